@@ -1733,6 +1733,7 @@ fn drop(&mut self) {
                 WorkItem::Optimize(m) => {
                     let _timer =
                         cgcx.prof.generic_activity_with_arg("codegen_module_optimize", &*m.name);
+                    let _cost_timer = cgcx.prof.codegen_unit_activity("llvm", || m.name.clone());
                     execute_optimize_work_item(&cgcx, m, module_config)
                 }
                 WorkItem::CopyPostLtoArtifacts(m) => {
@@ -1745,6 +1746,8 @@ fn drop(&mut self) {
                 WorkItem::LTO(m) => {
                     let _timer =
                         cgcx.prof.generic_activity_with_arg("codegen_module_perform_lto", m.name());
+                    let _cost_timer =
+                        cgcx.prof.codegen_unit_activity("llvm", || m.name().to_owned());
                     execute_lto_work_item(&cgcx, m, module_config)
                 }
             })
@@ -692,7 +692,9 @@ pub fn codegen_crate<B: ExtraBackendMethods>(
             let start_time = Instant::now();
 
             let pre_compiled_cgus = par_map(cgus, |(i, _)| {
-                let module = backend.compile_codegen_unit(tcx, codegen_units[i].name());
+                let cgu_name = codegen_units[i].name();
+                let _timer = tcx.prof.codegen_unit_activity("llvm", || cgu_name.to_string());
+                let module = backend.compile_codegen_unit(tcx, cgu_name);
                 (i, module)
             });
 
@@ -717,6 +719,7 @@ pub fn codegen_crate<B: ExtraBackendMethods>(
                     cgu
                 } else {
                     let start_time = Instant::now();
+                    let _timer = tcx.prof.codegen_unit_activity("llvm", || cgu.name().to_string());
                     let module = backend.compile_codegen_unit(tcx, cgu.name());
                     total_codegen_time += start_time.elapsed();
                     module
@@ -85,10 +85,13 @@
 use crate::fx::FxHashMap;
 
 use std::borrow::Borrow;
+use std::cell::Cell;
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt::Display;
 use std::fs;
+use std::hash::Hash;
 use std::intrinsics::unlikely;
 use std::path::Path;
 use std::process;
@@ -97,7 +100,7 @@
 
 pub use measureme::EventId;
 use measureme::{EventIdBuilder, Profiler, SerializableString, StringId};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use smallvec::SmallVec;
 
 bitflags::bitflags! {
@@ -170,19 +173,30 @@ pub struct SelfProfilerRef {
 
     // Print verbose generic activities to stderr.
     print_verbose_generic_activities: Option<TimePassesFormat>,
+
+    // Per-item costs for `--print=compile-cost`. This field is `None` unless
+    // that report was requested.
+    item_costs: Option<Arc<ItemCosts>>,
 }
 
 impl SelfProfilerRef {
     pub fn new(
         profiler: Option<Arc<SelfProfiler>>,
         print_verbose_generic_activities: Option<TimePassesFormat>,
+        record_item_costs: bool,
     ) -> SelfProfilerRef {
         // If there is no SelfProfiler then the filter mask is set to NONE,
         // ensuring that nothing ever tries to actually access it.
         let event_filter_mask =
             profiler.as_ref().map_or(EventFilter::empty(), |p| p.event_filter_mask);
+        let item_costs = record_item_costs.then(|| Arc::new(ItemCosts::default()));
 
-        SelfProfilerRef { profiler, event_filter_mask, print_verbose_generic_activities }
+        SelfProfilerRef {
+            profiler,
+            event_filter_mask,
+            print_verbose_generic_activities,
+            item_costs,
+        }
     }
 
     /// This shim makes sure that calls only get executed if the filter mask
@@ -242,6 +256,22 @@ pub fn verbose_generic_activity_with_arg<A>(
         )
     }
 
+    /// Start timing `category` (e.g. `llvm`) for a codegen unit, until the ItemTimingGuard
+    /// returned from this call is dropped. This is only recorded if per-item costs were
+    /// requested with `--print=compile-cost`, and `cgu_name` is only called in that case.
+    /// The time is split between the items of the codegen unit when the report is built.
+    #[inline(always)]
+    pub fn codegen_unit_activity(
+        &self,
+        category: &'static str,
+        cgu_name: impl FnOnce() -> String,
+    ) -> ItemTimingGuard<'_> {
+        match &self.item_costs {
+            Some(costs) => ItemTimingGuard::start(&costs.cgu_times, category, cgu_name()),
+            None => ItemTimingGuard::none(),
+        }
+    }
+
     /// Start profiling a generic activity. Profiling continues until the
     /// TimingGuard returned from this call is dropped.
     #[inline(always)]
@@ -493,6 +523,12 @@ pub fn enabled(&self) -> bool {
         self.profiler.is_some()
     }
 
+    /// Returns the per-item costs recorded so far, if `--print=compile-cost` was requested.
+    #[inline]
+    pub fn item_costs(&self) -> Option<&ItemCosts> {
+        self.item_costs.as_deref()
+    }
+
     #[inline]
     pub fn llvm_recording_enabled(&self) -> bool {
         self.event_filter_mask.contains(EventFilter::LLVM)
@@ -763,6 +799,205 @@ fn drop(&mut self) {
     }
 }
 
+type ItemTimes = Mutex<FxHashMap<(&'static str, String), Duration>>;
+
+/// The time spent on, and the number of instantiations of, each item of the crate.
+/// Collected for `--print=compile-cost`.
+///
+/// Items are keyed by their path, so that all instantiations of a generic item are
+/// attributed to the generic item itself.
+#[derive(Default)]
+pub struct ItemCosts {
+    item_times: ItemTimes,
+    cgu_times: ItemTimes,
+    instantiations: Mutex<FxHashMap<String, usize>>,
+    // The items of each codegen unit, with their size estimates.
+    cgu_items: Mutex<FxHashMap<String, Vec<(String, usize)>>>,
+}
+
+/// The recorded costs of a single item, see `ItemCosts::report`.
+pub struct ItemCost {
+    pub item: String,
+    pub total: Duration,
+    pub times: BTreeMap<&'static str, Duration>,
+    pub instantiations: usize,
+}
+
+impl ItemCosts {
+    pub fn record_item_time(&self, category: &'static str, item: String, time: Duration) {
+        *self.item_times.lock().entry((category, item)).or_default() += time;
+    }
+
+    pub fn record_instantiations(&self, item: String, count: usize) {
+        *self.instantiations.lock().entry(item).or_default() += count;
+    }
+
+    pub fn record_codegen_unit(&self, cgu_name: String, items: Vec<(String, usize)>) {
+        self.cgu_items.lock().insert(cgu_name, items);
+    }
+
+    /// Builds the list of item costs, most expensive first.
+    ///
+    /// The time spent on a codegen unit is split between its items in proportion to
+    /// their size estimates. Codegen units without known items (e.g. the allocator
+    /// shim, or the single module of fat LTO) are reported as items of their own.
+    pub fn report(&self) -> Vec<ItemCost> {
+        fn cost<'a>(costs: &'a mut FxHashMap<String, ItemCost>, item: &str) -> &'a mut ItemCost {
+            costs.entry(item.to_owned()).or_insert_with(|| ItemCost {
+                item: item.to_owned(),
+                total: Duration::ZERO,
+                times: BTreeMap::new(),
+                instantiations: 0,
+            })
+        }
+
+        let mut costs = FxHashMap::default();
+
+        for (&(category, ref item), &time) in self.item_times.lock().iter() {
+            *cost(&mut costs, item).times.entry(category).or_default() += time;
+        }
+
+        let cgu_items = self.cgu_items.lock();
+        for (&(category, ref cgu_name), &time) in self.cgu_times.lock().iter() {
+            let items = match cgu_items.get(cgu_name) {
+                Some(items) if !items.is_empty() => items,
+                _ => {
+                    *cost(&mut costs, cgu_name).times.entry(category).or_default() += time;
+                    continue;
+                }
+            };
+            let total_size: usize = items.iter().map(|&(_, size)| size).sum();
+            for (item, size) in items {
+                let share = if total_size == 0 {
+                    time / items.len() as u32
+                } else {
+                    time.mul_f64(*size as f64 / total_size as f64)
+                };
+                *cost(&mut costs, item).times.entry(category).or_default() += share;
+            }
+        }
+
+        for (item, &count) in self.instantiations.lock().iter() {
+            cost(&mut costs, item).instantiations += count;
+        }
+
+        let mut costs: Vec<_> = costs
+            .into_values()
+            .map(|mut cost| {
+                cost.total = cost.times.values().sum();
+                cost
+            })
+            .collect();
+        costs.sort_by(|a, b| {
+            b.total
+                .cmp(&a.total)
+                .then_with(|| b.instantiations.cmp(&a.instantiations))
+                .then_with(|| a.item.cmp(&b.item))
+        });
+        costs
+    }
+}
+
+thread_local! {
+    // The time spent in the timed activities nested in the one being timed on this thread,
+    // which is excluded from the time of the latter.
+    static NESTED_TIME: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+/// Measures the self-time of an activity, excluding the time of the self-timed activities
+/// that run nested in it on the same thread.
+struct SelfTimer {
+    start: Instant,
+    outer_nested_time: Duration,
+}
+
+impl SelfTimer {
+    fn start() -> Self {
+        let outer_nested_time = NESTED_TIME.with(|nested| nested.replace(Duration::ZERO));
+        SelfTimer { start: Instant::now(), outer_nested_time }
+    }
+
+    fn finish(self) -> Duration {
+        let elapsed = self.start.elapsed();
+        let nested_time =
+            NESTED_TIME.with(|nested| nested.replace(self.outer_nested_time + elapsed));
+        elapsed.saturating_sub(nested_time)
+    }
+}
+
+#[must_use]
+pub struct ItemTimingGuard<'a> {
+    info: Option<(&'a ItemTimes, &'static str, String, SelfTimer)>,
+}
+
+impl<'a> ItemTimingGuard<'a> {
+    fn start(times: &'a ItemTimes, category: &'static str, item: String) -> Self {
+        ItemTimingGuard { info: Some((times, category, item, SelfTimer::start())) }
+    }
+
+    #[inline]
+    pub fn none() -> Self {
+        ItemTimingGuard { info: None }
+    }
+
+    #[inline(always)]
+    pub fn run<R>(self, f: impl FnOnce() -> R) -> R {
+        let _timer = self;
+        f()
+    }
+}
+
+impl Drop for ItemTimingGuard<'_> {
+    fn drop(&mut self) {
+        if let Some((times, category, item, timer)) = self.info.take() {
+            *times.lock().entry((category, item)).or_default() += timer.finish();
+        }
+    }
+}
+
+/// The self-time of query invocations for `--print=compile-cost`, keyed by the name of the
+/// query and by the item it was invoked for. The time spent in nested queries is excluded, so
+/// it is only attributed to the nested queries themselves.
+pub struct QueryCosts<K> {
+    times: Mutex<FxHashMap<(&'static str, K), Duration>>,
+}
+
+impl<K: Eq + Hash> QueryCosts<K> {
+    pub fn new() -> Self {
+        QueryCosts { times: Default::default() }
+    }
+
+    /// Start timing an invocation of `query`, until the QueryTimingGuard returned from this
+    /// call is dropped. Invocations for no item are timed, so that their time is excluded
+    /// from their caller's, but they aren't recorded.
+    #[inline]
+    pub fn start(&self, query: &'static str, item: Option<K>) -> QueryTimingGuard<'_, K> {
+        QueryTimingGuard { costs: self, query, item, timer: Some(SelfTimer::start()) }
+    }
+
+    /// Returns the times recorded so far, and starts over.
+    pub fn take(&self) -> FxHashMap<(&'static str, K), Duration> {
+        std::mem::take(&mut *self.times.lock())
+    }
+}
+
+#[must_use]
+pub struct QueryTimingGuard<'a, K: Eq + Hash> {
+    costs: &'a QueryCosts<K>,
+    query: &'static str,
+    item: Option<K>,
+    timer: Option<SelfTimer>,
+}
+
+impl<K: Eq + Hash> Drop for QueryTimingGuard<'_, K> {
+    fn drop(&mut self) {
+        let time = self.timer.take().unwrap().finish();
+        if let Some(item) = self.item.take() {
+            *self.costs.times.lock().entry((self.query, item)).or_default() += time;
+        }
+    }
+}
+
 struct JsonTimePassesEntry<'a> {
     pass: &'a str,
     time: f64,
@@ -1,4 +1,5 @@
-use super::JsonTimePassesEntry;
+use super::{JsonTimePassesEntry, QueryCosts};
+use std::time::{Duration, Instant};
 
 #[test]
 fn with_rss() {
@@ -17,3 +18,23 @@ fn no_rss() {
         r#"{"pass":"typeck","time":56.1,"rss_start":null,"rss_end":null}"#
     )
 }
+
+#[test]
+fn query_costs_exclude_nested_queries() {
+    let costs = QueryCosts::new();
+    let start = Instant::now();
+    {
+        let _outer = costs.start("outer", Some(1));
+        let _unrecorded = costs.start("unrecorded", None);
+        let _inner = costs.start("inner", Some(2));
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let elapsed = start.elapsed();
+
+    let times = costs.take();
+    assert_eq!(times.len(), 2);
+    let (outer, inner) = (times[&("outer", 1)], times[&("inner", 2)]);
+    assert!(inner >= Duration::from_millis(10));
+    assert!(outer + inner <= elapsed);
+    assert!(costs.take().is_empty());
+}
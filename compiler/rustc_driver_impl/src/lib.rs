@@ -21,6 +21,7 @@
 
 use rustc_ast as ast;
 use rustc_codegen_ssa::{traits::CodegenBackend, CodegenErrors, CodegenResults};
+use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::profiling::{
     get_resident_set_size, print_time_passes_entry, TimePassesFormat,
 };
@@ -34,14 +35,15 @@
 use rustc_interface::{interface, Queries};
 use rustc_lint::LintStore;
 use rustc_metadata::locator;
-use rustc_session::config::{nightly_options, CG_OPTIONS, Z_OPTIONS};
-use rustc_session::config::{ErrorOutputType, Input, OutFileName, OutputType, TrimmedDefPaths};
+use rustc_middle::ty::TyCtxt;
+use rustc_session::config::{nightly_options, TrimmedDefPaths, CG_OPTIONS, Z_OPTIONS};
+use rustc_session::config::{ErrorOutputType, Input, OutFileName, OutputType, PrintKind};
 use rustc_session::cstore::MetadataLoader;
 use rustc_session::getopts::{self, Matches};
 use rustc_session::lint::{Lint, LintId};
 use rustc_session::{config, EarlyErrorHandler, Session};
 use rustc_span::source_map::{FileLoader, FileName};
-use rustc_span::symbol::sym;
+use rustc_span::symbol::{sym, Symbol};
 use rustc_target::json::ToJson;
 use rustc_target::spec::{Target, TargetTriple};
 
@@ -323,6 +325,13 @@ fn run_compiler(
             0 => {
                 callbacks.config(&mut config);
 
+                // `--print=features` lists the library features of the standard library,
+                // which is loaded by an otherwise empty crate.
+                if config.opts.prints.iter().any(|print| print.kind == PrintKind::Features) {
+                    config.input =
+                        Input::Str { name: FileName::Custom(String::new()), input: String::new() };
+                }
+
                 early_error_handler.abort_if_errors();
 
                 interface::run_compiler(config, |compiler| {
@@ -342,12 +351,7 @@ fn run_compiler(
                         describe_lints(compiler.session(), &lint_store, registered_lints);
                         return;
                     }
-                    let should_stop = print_crate_info(
-                        &handler,
-                        &**compiler.codegen_backend(),
-                        compiler.session(),
-                        false,
-                    );
+                    let should_stop = print_crate_info(&handler, compiler, false);
 
                     if should_stop == Compilation::Stop {
                         return;
@@ -370,7 +374,7 @@ fn run_compiler(
         let sess = compiler.session();
         let handler = EarlyErrorHandler::new(sess.opts.error_format);
 
-        let should_stop = print_crate_info(&handler, &**compiler.codegen_backend(), sess, true)
+        let should_stop = print_crate_info(&handler, compiler, true)
             .and_then(|| {
                 list_metadata(&handler, sess, &*compiler.codegen_backend().metadata_loader())
             })
@@ -471,6 +475,8 @@ fn run_compiler(
             linker.link()?
         }
 
+        print_compile_cost(sess);
+
         if sess.opts.unstable_opts.perf_stats {
             sess.print_perf_stats();
         }
@@ -722,21 +728,24 @@ pub fn list_metadata(
 
 fn print_crate_info(
     handler: &EarlyErrorHandler,
-    codegen_backend: &dyn CodegenBackend,
-    sess: &Session,
+    compiler: &interface::Compiler,
     parse_attrs: bool,
 ) -> Compilation {
     use rustc_session::config::PrintKind::*;
 
+    let sess = compiler.session();
+    let codegen_backend = &**compiler.codegen_backend();
+
     // This import prevents the following code from using the printing macros
     // used by the rest of the module. Within this function, we only write to
     // the output specified by `sess.io.output_file`.
     #[allow(unused_imports)]
     use {do_not_use_safe_print as safe_print, do_not_use_safe_print as safe_println};
 
-    // NativeStaticLibs and LinkArgs are special - printed during linking
+    // NativeStaticLibs, LinkArgs and CompileCost are special - printed during linking
     // (empty iterator returns true)
-    if sess.opts.prints.iter().all(|p| p.kind == NativeStaticLibs || p.kind == LinkArgs) {
+    if sess.opts.prints.iter().all(|p| matches!(p.kind, NativeStaticLibs | LinkArgs | CompileCost))
+    {
         return Compilation::Continue;
     }
 
@@ -851,6 +860,7 @@ fn print_crate_info(
             // Any output here interferes with Cargo's parsing of other printed output
             NativeStaticLibs => {}
             LinkArgs => {}
+            CompileCost => {}
             SplitDebuginfo => {
                 use rustc_target::spec::SplitDebuginfo::{Off, Packed, Unpacked};
 
@@ -874,6 +884,21 @@ fn print_crate_info(
                         .early_error("only Apple targets currently support deployment version info")
                 }
             }
+            Lints => {
+                let mut lint_store = rustc_lint::new_lint_store(sess.enable_internal_lints());
+                if let Some(register_lints) = compiler.register_lints() {
+                    register_lints(sess, &mut lint_store);
+                }
+                let lints = lints_json(&lint_store);
+                println_info!("{}", serde_json::to_string_pretty(&lints).unwrap());
+            }
+            Features => {
+                let features = compiler.enter(|queries| {
+                    Ok::<_, ErrorGuaranteed>(queries.global_ctxt()?.enter(features_json))
+                });
+                let Ok(features) = features else { return Compilation::Stop };
+                println_info!("{}", serde_json::to_string_pretty(&features).unwrap());
+            }
         }
 
         req.out.overwrite(&crate_info, sess);
@@ -881,6 +906,178 @@ fn print_crate_info(
     Compilation::Stop
 }
 
+/// Describes every lint and lint group in `lint_store`, for `--print=lints`.
+fn lints_json(lint_store: &LintStore) -> serde_json::Value {
+    use rustc_session::lint::FutureIncompatibilityReason::*;
+
+    let mut lint_groups: Vec<_> = lint_store.get_lint_groups().collect();
+    lint_groups.sort_by_key(|&(name, ..)| name);
+
+    let mut groups_of_lint: FxHashMap<LintId, Vec<String>> = FxHashMap::default();
+    let groups: Vec<_> = lint_groups
+        .into_iter()
+        .map(|(name, lint_ids, is_plugin)| {
+            let name = name.to_lowercase();
+            for &lint_id in &lint_ids {
+                groups_of_lint.entry(lint_id).or_default().push(name.clone());
+            }
+            serde_json::json!({
+                "name": name,
+                "lints": lint_ids.iter().map(LintId::to_string).collect::<Vec<_>>(),
+                "is_plugin": is_plugin,
+            })
+        })
+        .collect();
+
+    let mut lints = lint_store.get_lints().to_vec();
+    lints.sort_by_cached_key(|lint| lint.name_lower());
+    let lints: Vec<_> = lints
+        .into_iter()
+        .map(|lint| {
+            let future_incompatible = lint.future_incompatible.map(|info| {
+                let reason = match info.reason {
+                    FutureReleaseError => "future-release-error",
+                    FutureReleaseErrorReportNow => "future-release-error-report-now",
+                    FutureReleaseSemanticsChange => "future-release-semantics-change",
+                    EditionError(_) => "edition-error",
+                    EditionSemanticsChange(_) => "edition-semantics-change",
+                    Custom(_) => "custom",
+                };
+                let explanation = match info.reason {
+                    Custom(explanation) => Some(explanation),
+                    _ => None,
+                };
+                serde_json::json!({
+                    "reason": reason,
+                    "edition": info.reason.edition().map(|edition| edition.to_string()),
+                    "explanation": explanation,
+                    "reference": info.reference,
+                })
+            });
+            serde_json::json!({
+                "name": lint.name_lower(),
+                "groups": groups_of_lint.remove(&LintId::of(lint)).unwrap_or_default(),
+                "default_level": lint.default_level.as_str(),
+                "edition_level": lint.edition_lint_opts.map(|(edition, level)| {
+                    serde_json::json!({ "edition": edition.to_string(), "level": level.as_str() })
+                }),
+                "future_incompatible": future_incompatible,
+                "feature_gate": lint.feature_gate.map(|feature| feature.to_string()),
+                "is_plugin": lint.is_plugin,
+                "description": lint.desc,
+            })
+        })
+        .collect();
+
+    serde_json::json!({ "lints": lints, "groups": groups })
+}
+
+/// Describes every language feature known to this compiler, and every library feature
+/// declared by the crates loaded by the current crate, for `--print=features`.
+///
+/// Library features are declared with `#[unstable]`/`#[stable]` attributes in the
+/// library crates themselves, so without an input crate those of the standard library
+/// are listed.
+fn features_json(tcx: TyCtxt<'_>) -> serde_json::Value {
+    use rustc_feature::{
+        Features, GateIssue, State, ACCEPTED_FEATURES, ACTIVE_FEATURES, REMOVED_FEATURES,
+        STABLE_REMOVED_FEATURES,
+    };
+
+    let mut features: Vec<_> = ACCEPTED_FEATURES
+        .iter()
+        .chain(ACTIVE_FEATURES)
+        .chain(REMOVED_FEATURES)
+        .chain(STABLE_REMOVED_FEATURES)
+        .collect();
+    features.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+
+    let active = Features::default();
+    let mut features: Vec<_> = features
+        .into_iter()
+        .map(|feature| {
+            let (state, reason) = match feature.state {
+                State::Accepted => ("accepted", None),
+                State::Active { .. } if active.incomplete(feature.name) => ("incomplete", None),
+                State::Active { .. } if active.internal(feature.name) => ("internal", None),
+                State::Active { .. } => ("active", None),
+                State::Removed { reason } => ("removed", reason),
+                State::Stabilized { reason } => ("stabilized", reason),
+            };
+            let issue = rustc_feature::find_feature_issue(feature.name, GateIssue::Language);
+            serde_json::json!({
+                "name": feature.name.as_str(),
+                "kind": "lang",
+                "state": state,
+                "since": feature.since,
+                "issue": issue.map(|issue| issue.get()),
+                "edition": feature.edition.map(|edition| edition.to_string()),
+                "reason": reason,
+            })
+        })
+        .collect();
+
+    // The same library feature is often declared by several crates, e.g. `core` and `alloc`.
+    let mut lib_features: BTreeMap<&str, (Option<Symbol>, Vec<Symbol>)> = BTreeMap::new();
+    for &cnum in tcx.crates(()) {
+        for &(name, since) in tcx.defined_lib_features(cnum) {
+            let (feature_since, crates) = lib_features.entry(name.as_str()).or_default();
+            *feature_since = feature_since.or(since);
+            crates.push(tcx.crate_name(cnum));
+        }
+    }
+    features.extend(lib_features.into_iter().map(|(name, (since, mut crates))| {
+        crates.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        serde_json::json!({
+            "name": name,
+            "kind": "lib",
+            "state": if since.is_some() { "stable" } else { "unstable" },
+            "since": since.map(|since| since.to_string()),
+            "crates": crates.iter().map(Symbol::as_str).collect::<Vec<_>>(),
+        })
+    }));
+    features.sort_by(|a, b| {
+        (a["name"].as_str(), a["kind"].as_str()).cmp(&(b["name"].as_str(), b["kind"].as_str()))
+    });
+
+    serde_json::json!({ "features": features })
+}
+
+/// Prints the time spent on each item of the crate for `--print=compile-cost`, most
+/// expensive first. Called once codegen has finished, so that LLVM time is included.
+///
+/// The time of the queries run for an item (e.g. `typeck` or `mir_borrowck`) is reported
+/// by query, excluding the time of the queries they run in turn.
+fn print_compile_cost(sess: &Session) {
+    let Some(item_costs) = sess.prof.item_costs() else { return };
+    let items: Vec<_> = item_costs
+        .report()
+        .into_iter()
+        .map(|mut cost| {
+            let llvm = cost.times.remove("llvm").unwrap_or_default();
+            let queries: serde_json::Map<_, _> = cost
+                .times
+                .into_iter()
+                .map(|(query, time)| (query.into(), time.as_secs_f64().into()))
+                .collect();
+            serde_json::json!({
+                "item": cost.item,
+                "total": cost.total.as_secs_f64(),
+                "queries": queries,
+                "llvm": llvm.as_secs_f64(),
+                "instantiations": cost.instantiations,
+            })
+        })
+        .collect();
+    let report = serde_json::to_string_pretty(&serde_json::json!({ "items": items })).unwrap();
+
+    for req in &sess.opts.prints {
+        if req.kind == PrintKind::CompileCost {
+            req.out.overwrite(&format!("{report}\n"), sess);
+        }
+    }
+}
+
 /// Prints version information
 ///
 /// NOTE: this is a macro to support drivers built at a different time than the main `rustc_driver` crate.
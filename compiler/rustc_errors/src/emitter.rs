@@ -208,6 +208,10 @@ fn emit_unused_externs(
     ) {
     }
 
+    /// Emit a remark describing what a MIR optimization pass did to a function.
+    /// Like artifact notifications, this is only supported for the JSON format.
+    fn emit_mir_remark(&mut self, _pass: &str, _function: &str, _message: &str, _span: Span) {}
+
     /// Checks if should show explanations about "rustc --explain"
     fn should_show_explain(&self) -> bool {
         true
@@ -213,6 +213,24 @@ fn emit_unused_externs(&mut self, lint_level: rustc_lint_defs::Level, unused_ext
         }
     }
 
+    fn emit_mir_remark(&mut self, pass: &str, function: &str, message: &str, span: Span) {
+        let spans = if span.is_dummy() {
+            vec![]
+        } else {
+            vec![DiagnosticSpan::from_span_etc(span, true, None, None, self)]
+        };
+        let data = MirRemark { mir_remark: message, pass, function, spans };
+        let result = if self.pretty {
+            writeln!(&mut self.dst, "{}", serde_json::to_string_pretty(&data).unwrap())
+        } else {
+            writeln!(&mut self.dst, "{}", serde_json::to_string(&data).unwrap())
+        }
+        .and_then(|_| self.dst.flush());
+        if let Err(e) = result {
+            panic!("failed to print MIR remark: {e:?}");
+        }
+    }
+
     fn source_map(&self) -> Option<&Lrc<SourceMap>> {
         Some(&self.sm)
     }
@@ -327,6 +345,18 @@ struct UnusedExterns<'a, 'b, 'c> {
     unused_extern_names: &'b [&'c str],
 }
 
+#[derive(Serialize)]
+struct MirRemark<'a> {
+    /// What the pass did, e.g. "inlined `foo` into `bar`".
+    mir_remark: &'a str,
+    /// The name of the MIR pass that made the change.
+    pass: &'a str,
+    /// The path of the function whose body was changed.
+    function: &'a str,
+    /// The location the remark refers to, if any.
+    spans: Vec<DiagnosticSpan>,
+}
+
 impl Diagnostic {
     fn from_errors_diagnostic(diag: &crate::Diagnostic, je: &JsonEmitter) -> Diagnostic {
         let args = to_fluent_args(diag.args());
@@ -67,6 +67,7 @@
 mod lock;
 pub mod markdown;
 pub mod registry;
+pub mod sarif;
 mod snippet;
 mod styled_buffer;
 #[cfg(test)]
@@ -1212,6 +1213,10 @@ pub fn emit_unused_externs(
         inner.emit_unused_externs(lint_level, unused_externs)
     }
 
+    pub fn emit_mir_remark(&self, pass: &str, function: &str, message: &str, span: Span) {
+        self.inner.borrow_mut().emitter.emit_mir_remark(pass, function, message, span)
+    }
+
     pub fn update_unstable_expectation_id(
         &self,
         unstable_to_stable: &FxHashMap<LintExpectationId, LintExpectationId>,
@@ -0,0 +1,442 @@
+//! A SARIF emitter for errors.
+//!
+//! Where the JSON emitter prints one blob per diagnostic as soon as it is
+//! emitted, SARIF describes a whole analysis run. This emitter therefore
+//! collects every diagnostic of the session and writes a single SARIF 2.1.0 log
+//! once it is dropped, i.e. when the compilation session ends.
+//!
+//! The log is written to a file (`-Z sarif-output`, `rustc.sarif` by default)
+//! rather than to stderr, because diagnostics emitted before the session exists
+//! (e.g. about command-line options) are printed there in the human-readable
+//! format: early error handlers are created and replaced several times, and
+//! each one would otherwise write its own log.
+//!
+//! See <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html> for
+//! the format. Only the subset that code-scanning tools commonly consume is
+//! produced: rules, results, locations, related locations and fixes.
+
+use rustc_span::source_map::SourceMap;
+
+use crate::emitter::Emitter;
+use crate::registry::Registry;
+use crate::translation::{to_fluent_args, Translate};
+use crate::{CodeSuggestion, DiagnosticId, FluentBundle, LazyFallbackBundle, Level, SpanLabel};
+use rustc_lint_defs::Applicability;
+
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_data_structures::sync::Lrc;
+use rustc_error_messages::FluentArgs;
+use rustc_span::{FileName, Span};
+use std::error::Report;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use serde::Serialize;
+
+#[cfg(test)]
+mod tests;
+
+pub struct SarifEmitter {
+    dst: Box<dyn Write + Send>,
+    registry: Option<Registry>,
+    sm: Lrc<SourceMap>,
+    fluent_bundle: Option<Lrc<FluentBundle>>,
+    fallback_bundle: LazyFallbackBundle,
+    /// Rules referenced by `results`, in order of first use.
+    rules: FxIndexMap<String, ReportingDescriptor>,
+    results: Vec<SarifResult>,
+}
+
+impl SarifEmitter {
+    /// Creates an emitter writing its log to the file at `path`.
+    pub fn create(
+        path: &Path,
+        registry: Option<Registry>,
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> io::Result<SarifEmitter> {
+        let file = File::create(path)?;
+        Ok(SarifEmitter::new(
+            Box::new(io::BufWriter::new(file)),
+            registry,
+            source_map,
+            fluent_bundle,
+            fallback_bundle,
+        ))
+    }
+
+    pub fn new(
+        dst: Box<dyn Write + Send>,
+        registry: Option<Registry>,
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> SarifEmitter {
+        SarifEmitter {
+            dst,
+            registry,
+            sm: source_map,
+            fluent_bundle,
+            fallback_bundle,
+            rules: Default::default(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Returns the id and index of the rule for `code`, registering it on first use.
+    fn rule(&mut self, code: DiagnosticId) -> (String, usize) {
+        let (id, help_uri, full_description) = match code {
+            DiagnosticId::Error(code) => {
+                let explanation = self
+                    .registry
+                    .as_ref()
+                    .and_then(|registry| registry.try_find_description(&code).ok());
+                let help_uri = format!("https://doc.rust-lang.org/error_codes/{code}.html");
+                (code, Some(help_uri), explanation.map(|text| Message { text: text.to_owned() }))
+            }
+            DiagnosticId::Lint { name, .. } => (name, None, None),
+        };
+        if let Some(index) = self.rules.get_index_of(&id) {
+            return (id, index);
+        }
+        let (index, _) = self.rules.insert_full(
+            id.clone(),
+            ReportingDescriptor { id: id.clone(), help_uri, full_description },
+        );
+        (id, index)
+    }
+
+    fn location(&self, span: Span, message: Option<String>) -> Option<Location> {
+        if span.is_dummy() {
+            return None;
+        }
+        let start = self.sm.lookup_char_pos(span.lo());
+        let end = self.sm.lookup_char_pos(span.hi());
+        let byte_start = start.file.original_relative_byte_pos(span.lo()).0;
+        let byte_end = start.file.original_relative_byte_pos(span.hi()).0;
+        Some(Location {
+            physical_location: PhysicalLocation {
+                artifact_location: self.artifact_location(&start.file.name),
+                region: Region {
+                    start_line: start.line,
+                    start_column: start.col.0 + 1,
+                    end_line: end.line,
+                    end_column: end.col.0 + 1,
+                    byte_offset: byte_start,
+                    byte_length: byte_end - byte_start,
+                },
+            },
+            message: message.map(|text| Message { text }),
+        })
+    }
+
+    fn artifact_location(&self, name: &FileName) -> ArtifactLocation {
+        // SARIF wants URI references, which always use forward slashes.
+        let uri = self.sm.filename_for_diagnostics(name).to_string().replace('\\', "/");
+        ArtifactLocation { uri }
+    }
+
+    fn span_label_location(
+        &self,
+        span_label: SpanLabel,
+        fallback_message: Option<&str>,
+        args: &FluentArgs<'_>,
+    ) -> Option<Location> {
+        let label = span_label
+            .label
+            .as_ref()
+            .map(|label| self.translate_message(label, args).map_err(Report::new).unwrap())
+            .map(|label| label.to_string())
+            .or_else(|| fallback_message.map(str::to_owned));
+        self.location(span_label.span, label)
+    }
+
+    fn fixes(&self, suggestion: &CodeSuggestion, args: &FluentArgs<'_>) -> Vec<Fix> {
+        let description =
+            self.translate_message(&suggestion.msg, args).map_err(Report::new).unwrap();
+        suggestion
+            .substitutions
+            .iter()
+            .filter_map(|substitution| {
+                // Group the parts of this substitution by the file they touch.
+                let mut replacements: FxIndexMap<String, Vec<Replacement>> = Default::default();
+                for part in &substitution.parts {
+                    let Some(location) = self.location(part.span, None) else { continue };
+                    let PhysicalLocation { artifact_location, region } = location.physical_location;
+                    replacements.entry(artifact_location.uri).or_default().push(Replacement {
+                        deleted_region: region,
+                        inserted_content: ArtifactContent { text: part.snippet.clone() },
+                    });
+                }
+                let artifact_changes: Vec<_> = replacements
+                    .into_iter()
+                    .map(|(uri, replacements)| ArtifactChange {
+                        artifact_location: ArtifactLocation { uri },
+                        replacements,
+                    })
+                    .collect();
+                (!artifact_changes.is_empty()).then(|| Fix {
+                    description: Message { text: description.to_string() },
+                    artifact_changes,
+                    properties: FixProperties { applicability: suggestion.applicability },
+                })
+            })
+            .collect()
+    }
+}
+
+impl Translate for SarifEmitter {
+    fn fluent_bundle(&self) -> Option<&Lrc<FluentBundle>> {
+        self.fluent_bundle.as_ref()
+    }
+
+    fn fallback_fluent_bundle(&self) -> &FluentBundle {
+        &self.fallback_bundle
+    }
+}
+
+impl Emitter for SarifEmitter {
+    fn emit_diagnostic(&mut self, diag: &crate::Diagnostic) {
+        let Some(level) = result_level(diag.level) else { return };
+        let args = to_fluent_args(diag.args());
+        let mut message = self.translate_messages(&diag.message, &args).to_string();
+
+        let mut locations = vec![];
+        let mut related_locations = vec![];
+        for span_label in diag.span.span_labels() {
+            let is_primary = span_label.is_primary;
+            let Some(location) = self.span_label_location(span_label, None, &args) else {
+                continue;
+            };
+            if is_primary {
+                locations.push(location);
+            } else {
+                related_locations.push(location);
+            }
+        }
+
+        // Notes and helps attached to a span become related locations; the
+        // others are folded into the result message, as the human output does.
+        for child in &diag.children {
+            let child_message = self.translate_messages(&child.message, &args).to_string();
+            let span = child.render_span.as_ref().unwrap_or(&child.span);
+            let child_locations: Vec<_> = span
+                .span_labels()
+                .into_iter()
+                .filter_map(|span_label| {
+                    self.span_label_location(span_label, Some(&child_message), &args)
+                })
+                .collect();
+            if child_locations.is_empty() {
+                message.push_str(&format!("\n{}: {child_message}", child.level.to_str()));
+            } else {
+                related_locations.extend(child_locations);
+            }
+        }
+
+        let fixes = diag
+            .suggestions
+            .iter()
+            .flatten()
+            .flat_map(|suggestion| self.fixes(suggestion, &args))
+            .collect();
+
+        let rule = diag.code.clone().map(|code| self.rule(code));
+        self.results.push(SarifResult {
+            rule_id: rule.as_ref().map(|(id, _)| id.clone()),
+            rule_index: rule.map(|(_, index)| index),
+            level,
+            message: Message { text: message },
+            locations,
+            related_locations,
+            fixes,
+        });
+    }
+
+    fn source_map(&self) -> Option<&Lrc<SourceMap>> {
+        Some(&self.sm)
+    }
+
+    fn should_show_explain(&self) -> bool {
+        false
+    }
+}
+
+impl Drop for SarifEmitter {
+    fn drop(&mut self) {
+        let log = SarifLog {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: [Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: "rustc",
+                        version: option_env!("CFG_RELEASE"),
+                        information_uri: "https://www.rust-lang.org/",
+                        rules: self.rules.values().collect(),
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results: &self.results,
+            }],
+        };
+        let result = serde_json::to_writer(&mut self.dst, &log)
+            .map_err(io::Error::from)
+            .and_then(|_| writeln!(&mut self.dst))
+            .and_then(|_| self.dst.flush());
+        // Panicking while already unwinding from a fatal error would abort.
+        if let Err(e) = result && !std::thread::panicking() {
+            panic!("failed to print SARIF log: {e:?}");
+        }
+    }
+}
+
+/// Maps a diagnostic level to a SARIF result level, or `None` for diagnostics
+/// that describe the compilation itself (e.g. "aborting due to 2 previous errors").
+fn result_level(level: Level) -> Option<&'static str> {
+    match level {
+        Level::Bug | Level::DelayedBug | Level::Fatal | Level::Error { .. } => Some("error"),
+        Level::Warning(_) => Some("warning"),
+        Level::Note | Level::OnceNote | Level::Help => Some("note"),
+        Level::FailureNote | Level::Allow | Level::Expect(_) => None,
+    }
+}
+
+// The following data types are provided just for serialisation. Field names
+// follow the SARIF 2.1.0 schema.
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run<'a> {
+    tool: Tool<'a>,
+    /// rustc counts columns in `char`s, not in the UTF-16 code units SARIF defaults to.
+    column_kind: &'static str,
+    results: &'a [SarifResult],
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: ToolComponent<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent<'a> {
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'static str>,
+    information_uri: &'static str,
+    rules: Vec<&'a ReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    /// The error code (e.g. `E0308`) or lint name (e.g. `unused_variables`).
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    /// The long-form explanation of an error code, if it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    full_description: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    /// Index into the driver's `rules`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    /// "error", "warning" or "note".
+    level: &'static str,
+    message: Message,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+    /// The span label, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    /// 1-based.
+    start_line: usize,
+    /// 1-based, character offset.
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+    byte_offset: u32,
+    byte_length: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+    properties: FixProperties,
+}
+
+#[derive(Serialize)]
+struct FixProperties {
+    applicability: Applicability,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: ArtifactContent,
+}
+
+#[derive(Serialize)]
+struct ArtifactContent {
+    text: String,
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+use crate::Handler;
+use rustc_span::source_map::FilePathMapping;
+use rustc_span::BytePos;
+
+use std::path::Path;
+use std::str;
+use std::sync::{Arc, Mutex};
+
+struct Shared<T> {
+    data: Arc<Mutex<T>>,
+}
+
+impl<T: Write> Write for Shared<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.data.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.data.lock().unwrap().flush()
+    }
+}
+
+#[test]
+fn single_log_per_session() {
+    rustc_span::create_default_session_globals_then(|| {
+        let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        sm.new_source_file(
+            Path::new("test.rs").to_owned().into(),
+            "fn main() {\n    let x = 1;\n    let y: u8 = \"\";\n}\n".to_owned(),
+        );
+        let fallback_bundle =
+            crate::fallback_fluent_bundle(vec![crate::DEFAULT_LOCALE_RESOURCE], false);
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let emitter = SarifEmitter::new(
+            Box::new(Shared { data: output.clone() }),
+            None,
+            sm,
+            None,
+            fallback_bundle,
+        );
+        let handler = Handler::with_emitter(Box::new(emitter));
+
+        let x = Span::with_root_ctxt(BytePos(20), BytePos(21));
+        handler
+            .struct_span_warn(x, "unused variable: `x`")
+            .code(DiagnosticId::Lint {
+                name: "unused_variables".to_owned(),
+                has_future_breakage: false,
+                is_force_warn: false,
+            })
+            .span_suggestion(
+                x,
+                "if this is intentional, prefix it with an underscore",
+                "_x",
+                Applicability::MachineApplicable,
+            )
+            .emit();
+        let ty = Span::with_root_ctxt(BytePos(38), BytePos(40));
+        let expr = Span::with_root_ctxt(BytePos(43), BytePos(45));
+        handler
+            .struct_span_err_with_code(
+                expr,
+                "mismatched types",
+                DiagnosticId::Error("E0308".into()),
+            )
+            .span_label(ty, "expected due to this")
+            .emit();
+
+        // Nothing is written until the session ends.
+        assert!(output.lock().unwrap().is_empty());
+        drop(handler);
+
+        let bytes = output.lock().unwrap();
+        let log: serde_json::Value = serde_json::from_str(str::from_utf8(&bytes).unwrap()).unwrap();
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "unused_variables");
+        assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "E0308");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][1]["helpUri"],
+            "https://doc.rust-lang.org/error_codes/E0308.html"
+        );
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+
+        let unused = &results[0];
+        assert_eq!(unused["ruleId"], "unused_variables");
+        assert_eq!(unused["ruleIndex"], 0);
+        assert_eq!(unused["level"], "warning");
+        let region = &unused["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 2);
+        assert_eq!(region["startColumn"], 9);
+        assert_eq!(region["byteOffset"], 20);
+        assert_eq!(region["byteLength"], 1);
+        let change = &unused["fixes"][0]["artifactChanges"][0];
+        assert_eq!(change["artifactLocation"]["uri"], "test.rs");
+        assert_eq!(change["replacements"][0]["insertedContent"]["text"], "_x");
+        assert_eq!(unused["fixes"][0]["properties"]["applicability"], "MachineApplicable");
+
+        let mismatch = &results[1];
+        assert_eq!(mismatch["ruleIndex"], 1);
+        assert_eq!(mismatch["level"], "error");
+        assert_eq!(mismatch["message"]["text"], "mismatched types");
+        let related = &mismatch["relatedLocations"][0];
+        assert_eq!(related["message"]["text"], "expected due to this");
+        assert_eq!(related["physicalLocation"]["region"]["startColumn"], 12);
+    })
+}
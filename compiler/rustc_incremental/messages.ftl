@@ -46,6 +46,8 @@ incremental_delete_partial = failed to delete partly initialized session dir `{$
 
 incremental_delete_workproduct = file-system error deleting outdated file `{$path}`: {$err}
 
+incremental_dump_incr_state = failed to dump incremental state to `{$path}`: {$err}
+
 incremental_field_associated_value_expected = associated value expected for `{$name}`
 
 incremental_finalize = error finalizing incremental compilation session directory `{$path}`: {$err}
@@ -362,3 +362,10 @@ pub struct DeleteWorkProduct<'a> {
     pub path: &'a Path,
     pub err: std::io::Error,
 }
+
+#[derive(Diagnostic)]
+#[diag(incremental_dump_incr_state)]
+pub struct DumpIncrState<'a> {
+    pub path: &'a Path,
+    pub err: std::io::Error,
+}
@@ -0,0 +1,319 @@
+//! Writes a description of the incremental compilation state for `-Z dump-incr-state`.
+//!
+//! The dump is written after the dep-graph and the query result cache have been saved.
+//! It covers the query results in the new cache, the work products of the previous
+//! session, and the reason each node of the previous dep-graph was recomputed.
+
+use crate::errors;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_middle::dep_graph::{make_compile_codegen_unit, DepContext, DepKind, DepNodeColor};
+use rustc_middle::dep_graph::{DepGraphData, SerializedDepGraph, SerializedDepNodeIndex};
+use rustc_middle::query::on_disk_cache::OnDiskCache;
+use rustc_middle::ty::TyCtxt;
+use rustc_serialize::opaque::MemDecoder;
+use rustc_serialize::Decodable;
+use rustc_session::config::DumpIncrStateFormat;
+use rustc_span::Symbol;
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use super::file_format;
+use super::fs::*;
+
+#[derive(Serialize)]
+struct IncrState {
+    session_directory: String,
+    /// Per-kind counts of the nodes of the previous dep-graph.
+    dep_kinds: Vec<DepKindSummary>,
+    invalidated: Vec<InvalidatedNode>,
+    work_products: Vec<WorkProductState>,
+    query_results: Vec<QueryResult>,
+}
+
+#[derive(Serialize)]
+struct DepKindSummary {
+    kind: String,
+    previous: usize,
+    green: usize,
+    red: usize,
+    /// Nodes that were never evaluated, and are thus dropped from the new dep-graph.
+    unused: usize,
+}
+
+#[derive(Serialize)]
+struct InvalidatedNode {
+    node: String,
+    previous_fingerprint: String,
+    reason: String,
+    /// The node at the end of the chain of changed dependencies.
+    root_cause: String,
+}
+
+#[derive(Serialize)]
+struct WorkProductState {
+    cgu_name: String,
+    /// `reused`, `recompiled` or `unused`.
+    status: &'static str,
+    reason: Option<String>,
+    files: Vec<SavedFile>,
+}
+
+#[derive(Serialize)]
+struct SavedFile {
+    kind: String,
+    path: String,
+    size: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct QueryResult {
+    node: String,
+    fingerprint: String,
+    size: usize,
+    /// `new`, `reused` or `recomputed`.
+    status: &'static str,
+}
+
+pub(crate) fn dump_incr_state(tcx: TyCtxt<'_>, output_directory: Option<&Path>) {
+    let format = tcx.sess.opts.unstable_opts.dump_incr_state_format;
+    let file_name = format!("{}.incr_state.{}", tcx.crate_name(LOCAL_CRATE), format.extension());
+    let path = output_directory.unwrap_or(Path::new(".")).join(file_name);
+
+    if let Err(err) = write_incr_state(tcx, output_directory, &path, format) {
+        tcx.sess.emit_err(errors::DumpIncrState { path: &path, err });
+    }
+}
+
+fn write_incr_state(
+    tcx: TyCtxt<'_>,
+    output_directory: Option<&Path>,
+    path: &Path,
+    format: DumpIncrStateFormat,
+) -> io::Result<()> {
+    let Some(data) = tcx.dep_graph.data() else { return Ok(()) };
+    let state = IncrState {
+        session_directory: tcx.sess.incr_comp_session_dir().display().to_string(),
+        dep_kinds: dep_kind_summaries(data),
+        invalidated: invalidated_nodes(tcx, data),
+        work_products: work_products(tcx, data),
+        query_results: query_results(tcx, data)?,
+    };
+
+    if let Some(output_directory) = output_directory {
+        fs::create_dir_all(output_directory)?;
+    }
+    let mut file = BufWriter::new(File::create(path)?);
+    match format {
+        DumpIncrStateFormat::Json => serde_json::to_writer_pretty(&mut file, &state)?,
+        DumpIncrStateFormat::Human => write_human(&mut file, &state)?,
+    }
+    file.flush()
+}
+
+fn dep_kind_summaries(data: &DepGraphData) -> Vec<DepKindSummary> {
+    let previous = data.previous();
+    let mut summaries: FxHashMap<DepKind, DepKindSummary> = Default::default();
+    for prev_index in previous.indices() {
+        let kind = previous.index_to_node(prev_index).kind;
+        let summary = summaries.entry(kind).or_insert_with(|| DepKindSummary {
+            kind: format!("{kind:?}"),
+            previous: 0,
+            green: 0,
+            red: 0,
+            unused: 0,
+        });
+        summary.previous += 1;
+        match data.prev_node_color(prev_index) {
+            Some(DepNodeColor::Green(_)) => summary.green += 1,
+            Some(DepNodeColor::Red) => summary.red += 1,
+            None => summary.unused += 1,
+        }
+    }
+
+    let mut summaries: Vec<_> = summaries.into_values().collect();
+    summaries.sort_by(|a, b| b.previous.cmp(&a.previous).then_with(|| a.kind.cmp(&b.kind)));
+    summaries
+}
+
+fn invalidated_nodes(tcx: TyCtxt<'_>, data: &DepGraphData) -> Vec<InvalidatedNode> {
+    let previous = data.previous();
+    previous
+        .indices()
+        .filter(|&prev_index| data.prev_node_color(prev_index) == Some(DepNodeColor::Red))
+        .map(|prev_index| {
+            let (reason, root_cause) = invalidation_reason(tcx, data, prev_index);
+            InvalidatedNode {
+                node: format!("{:?}", previous.index_to_node(prev_index)),
+                previous_fingerprint: previous.fingerprint_by_index(prev_index).to_string(),
+                reason,
+                root_cause: format!("{:?}", previous.index_to_node(root_cause)),
+            }
+        })
+        .collect()
+}
+
+/// Explains why the previous node `prev_index` had to be recomputed, and follows the
+/// chain of changed dependencies back to the node that started it.
+///
+/// Marking a node green visits its dependencies in order and stops at the first one
+/// that cannot be marked green, so that dependency is the one reported.
+fn invalidation_reason(
+    tcx: TyCtxt<'_>,
+    data: &DepGraphData,
+    prev_index: SerializedDepNodeIndex,
+) -> (String, SerializedDepNodeIndex) {
+    let previous = data.previous();
+    let first_changed_dep = |index| {
+        previous
+            .edge_targets_from(index)
+            .iter()
+            .copied()
+            .find(|&dep| !matches!(data.prev_node_color(dep), Some(DepNodeColor::Green(_))))
+    };
+
+    let mut root_cause = prev_index;
+    while let Some(dep) = first_changed_dep(root_cause) {
+        root_cause = dep;
+        if data.prev_node_color(dep) != Some(DepNodeColor::Red) {
+            // The dependency could not be re-validated, e.g. because it no longer exists.
+            break;
+        }
+    }
+
+    let reason = if tcx.is_eval_always(previous.index_to_node(prev_index).kind) {
+        "always re-executed, and its result changed".to_string()
+    } else if let Some(dep) = first_changed_dep(prev_index) {
+        let dep_node = previous.index_to_node(dep);
+        match data.prev_node_color(dep) {
+            Some(DepNodeColor::Red) => format!("dependency `{dep_node:?}` changed"),
+            _ => format!("dependency `{dep_node:?}` could not be re-validated"),
+        }
+    } else {
+        "re-executed, and its result changed".to_string()
+    };
+    (reason, root_cause)
+}
+
+fn work_products(tcx: TyCtxt<'_>, data: &DepGraphData) -> Vec<WorkProductState> {
+    let previous = data.previous();
+    tcx.dep_graph
+        .previous_work_products()
+        .values()
+        .map(|work_product| {
+            let dep_node = make_compile_codegen_unit(tcx, Symbol::intern(&work_product.cgu_name));
+            let prev_index = previous.node_to_index_opt(&dep_node);
+            let (status, reason) = match prev_index.map(|i| (i, data.prev_node_color(i))) {
+                Some((_, Some(DepNodeColor::Green(_)))) => ("reused", None),
+                Some((prev_index, Some(DepNodeColor::Red))) => {
+                    ("recompiled", Some(invalidation_reason(tcx, data, prev_index).0))
+                }
+                _ => ("unused", Some("the codegen unit no longer exists".to_string())),
+            };
+            let files = work_product
+                .saved_files
+                .items()
+                .into_sorted_stable_ord()
+                .into_iter()
+                .map(|(kind, path)| SavedFile {
+                    kind: kind.clone(),
+                    path: path.clone(),
+                    size: fs::metadata(in_incr_comp_dir_sess(tcx.sess, path)).ok().map(|m| m.len()),
+                })
+                .collect();
+            WorkProductState { cgu_name: work_product.cgu_name.clone(), status, reason, files }
+        })
+        .collect()
+}
+
+/// Reads back the dep-graph and the query result cache that were just saved, and
+/// describes every cached query result, largest first.
+fn query_results(tcx: TyCtxt<'_>, data: &DepGraphData) -> io::Result<Vec<QueryResult>> {
+    let sess = tcx.sess;
+    let read_file = |path: PathBuf| {
+        file_format::read_file(&path, false, sess.is_nightly_build(), sess.cfg_version)
+    };
+
+    let Some((graph_bytes, graph_start_pos)) = read_file(dep_graph_path(sess))? else {
+        return Ok(vec![]);
+    };
+    let mut decoder = MemDecoder::new(&graph_bytes, graph_start_pos);
+    let _commandline_args_hash = u64::decode(&mut decoder);
+    let new_graph = SerializedDepGraph::decode(&mut decoder);
+
+    let Some((cache_bytes, cache_start_pos)) = read_file(query_cache_path(sess))? else {
+        return Ok(vec![]);
+    };
+    let cache = OnDiskCache::new(sess, cache_bytes, cache_start_pos);
+
+    let mut results: Vec<_> = cache
+        .query_result_sizes()
+        .into_iter()
+        .map(|(index, size)| {
+            let node = new_graph.index_to_node(index);
+            let status = match data.previous().node_to_index_opt(&node) {
+                Some(prev_index) => match data.prev_node_color(prev_index) {
+                    Some(DepNodeColor::Green(_)) => "reused",
+                    _ => "recomputed",
+                },
+                None => "new",
+            };
+            QueryResult {
+                node: format!("{node:?}"),
+                fingerprint: new_graph.fingerprint_by_index(index).to_string(),
+                size,
+                status,
+            }
+        })
+        .collect();
+    results.sort_by(|a, b| b.size.cmp(&a.size));
+    Ok(results)
+}
+
+fn write_human(out: &mut impl Write, state: &IncrState) -> io::Result<()> {
+    writeln!(out, "incremental state in `{}`", state.session_directory)?;
+
+    writeln!(out)?;
+    writeln!(out, "nodes of the previous dep-graph:")?;
+    writeln!(
+        out,
+        "  {:<36} {:>9} {:>9} {:>9} {:>9}",
+        "kind", "previous", "green", "red", "unused"
+    )?;
+    for DepKindSummary { kind, previous, green, red, unused } in &state.dep_kinds {
+        writeln!(out, "  {kind:<36} {previous:>9} {green:>9} {red:>9} {unused:>9}")?;
+    }
+
+    writeln!(out)?;
+    writeln!(out, "invalidated nodes ({}):", state.invalidated.len())?;
+    for InvalidatedNode { node, previous_fingerprint, reason, root_cause } in &state.invalidated {
+        writeln!(out, "  {node} [{previous_fingerprint}]")?;
+        writeln!(out, "      {reason}")?;
+        writeln!(out, "      root cause: `{root_cause}`")?;
+    }
+
+    writeln!(out)?;
+    writeln!(out, "work products of the previous session ({}):", state.work_products.len())?;
+    for WorkProductState { cgu_name, status, reason, files } in &state.work_products {
+        match reason {
+            Some(reason) => writeln!(out, "  {cgu_name}: {status}, {reason}")?,
+            None => writeln!(out, "  {cgu_name}: {status}")?,
+        }
+        for SavedFile { kind, path, size } in files {
+            match size {
+                Some(size) => writeln!(out, "      {kind}: {path} ({size} bytes)")?,
+                None => writeln!(out, "      {kind}: {path} (missing)")?,
+            }
+        }
+    }
+
+    let total: usize = state.query_results.iter().map(|result| result.size).sum();
+    writeln!(out)?;
+    writeln!(out, "cached query results ({}, {total} bytes):", state.query_results.len())?;
+    for QueryResult { node, fingerprint, size, status } in &state.query_results {
+        writeln!(out, "  {size:>10} {status:<10} {node} [{fingerprint}]")?;
+    }
+    Ok(())
+}
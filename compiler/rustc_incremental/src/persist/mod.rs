@@ -4,6 +4,7 @@
 
 mod data;
 mod dirty_clean;
+mod dump;
 mod file_format;
 mod fs;
 mod load;
@@ -5,11 +5,13 @@
 use rustc_middle::ty::TyCtxt;
 use rustc_serialize::opaque::{FileEncodeResult, FileEncoder};
 use rustc_serialize::Encodable as RustcEncodable;
+use rustc_session::config::SwitchWithOptPath;
 use rustc_session::Session;
 use std::fs;
 
 use super::data::*;
 use super::dirty_clean;
+use super::dump;
 use super::file_format;
 use super::fs::*;
 use super::work_product;
@@ -72,6 +74,10 @@ pub fn save_dep_graph(tcx: TyCtxt<'_>) {
                 });
             },
         );
+
+        if let SwitchWithOptPath::Enabled(ref path) = sess.opts.unstable_opts.dump_incr_state {
+            sess.time("incr_comp_dump_state", || dump::dump_incr_state(tcx, path.as_deref()));
+        }
     })
 }
 
@@ -734,6 +734,7 @@ pub fn create_global_ctxt<'tcx>(
                     extern_providers,
                     query_result_on_disk_cache,
                     incremental,
+                    sess.prof.item_costs().is_some(),
                 ),
             )
         })
@@ -971,6 +972,9 @@ pub fn start_codegen<'tcx>(
         codegen_backend.codegen_crate(tcx, metadata, need_metadata_module)
     });
 
+    // No queries run after codegen, so the query costs are complete.
+    tcx.record_query_costs();
+
     // Don't run these test assertions when not doing codegen. Compiletest tries to build
     // build-fail tests in check mode first and expects it to not give an error in that case.
     if tcx.sess.opts.output_types.should_codegen() {
@@ -16,7 +16,7 @@
     ProcMacroExecutionStrategy, SymbolManglingVersion, WasiExecModel,
 };
 use rustc_session::config::{CFGuard, ExternEntry, LinkerPluginLto, LtoCli, SwitchWithOptPath};
-use rustc_session::config::{DumpMonoStatsFormat, MirSpanview};
+use rustc_session::config::{DumpIncrStateFormat, DumpMonoStatsFormat, MirSpanview};
 use rustc_session::config::{ErrorOutputType, ExternLocation, LocationDetail, Options, Strip};
 use rustc_session::config::{InstrumentCoverage, Passes};
 use rustc_session::lint::Level;
@@ -684,6 +684,8 @@ macro_rules! untracked {
     untracked!(dont_buffer_diagnostics, true);
     untracked!(dump_dep_graph, true);
     untracked!(dump_drop_tracking_cfg, Some("cfg.dot".to_string()));
+    untracked!(dump_incr_state, SwitchWithOptPath::Enabled(Some("incr-state-dir/".into())));
+    untracked!(dump_incr_state_format, DumpIncrStateFormat::Json);
     untracked!(dump_mir, Some(String::from("abc")));
     untracked!(dump_mir_dataflow, true);
     untracked!(dump_mir_dir, String::from("abc"));
@@ -723,6 +725,7 @@ macro_rules! untracked {
     untracked!(proc_macro_execution_strategy, ProcMacroExecutionStrategy::CrossThread);
     untracked!(profile_closures, true);
     untracked!(query_dep_graph, true);
+    untracked!(sarif_output, Some(PathBuf::from("log.sarif")));
     untracked!(self_profile, SwitchWithOptPath::Enabled(None));
     untracked!(self_profile_events, Some(vec![String::new()]));
     untracked!(span_debug, true);
@@ -801,6 +804,7 @@ macro_rules! tracked {
     tracked!(mir_enable_passes, vec![("DestProp".to_string(), false)]);
     tracked!(mir_keep_place_mention, true);
     tracked!(mir_opt_level, Some(4));
+    tracked!(mir_remarks, Passes::Some(vec![String::from("Inline")]));
     tracked!(move_size_limit, Some(4096));
     tracked!(mutable_noalias, false);
     tracked!(no_generate_arange_section, true);
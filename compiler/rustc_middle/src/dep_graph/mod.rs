@@ -12,9 +12,11 @@
 };
 
 pub use dep_node::{label_strs, DepKind, DepNode, DepNodeExt};
-pub(crate) use dep_node::{make_compile_codegen_unit, make_compile_mono_item};
+pub use dep_node::make_compile_codegen_unit;
+pub(crate) use dep_node::make_compile_mono_item;
 
 pub type DepGraph = rustc_query_system::dep_graph::DepGraph<DepKind>;
+pub type DepGraphData = rustc_query_system::dep_graph::DepGraphData<DepKind>;
 
 pub type TaskDeps = rustc_query_system::dep_graph::TaskDeps<DepKind>;
 pub type TaskDepsRef<'a> = rustc_query_system::dep_graph::TaskDepsRef<'a, DepKind>;
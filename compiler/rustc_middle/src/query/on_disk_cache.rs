@@ -26,6 +26,7 @@
 use rustc_span::{CachingSourceMapView, Symbol};
 use std::collections::hash_map::Entry;
 use std::io;
+use std::iter;
 use std::mem;
 
 const TAG_FILE_FOOTER: u128 = 0xC0FFEE_C0FFEE_C0FFEE_C0FFEE_C0FFEE;
@@ -66,6 +67,9 @@ pub struct OnDiskCache<'sess> {
     // `serialized_data`.
     query_result_index: FxHashMap<SerializedDepNodeIndex, AbsoluteBytePos>,
 
+    // The position right after the last cached query result in `serialized_data`.
+    query_result_end: AbsoluteBytePos,
+
     // A map from dep-node to the position of any associated `QuerySideEffects` in
     // `serialized_data`.
     prev_side_effects_index: FxHashMap<SerializedDepNodeIndex, AbsoluteBytePos>,
@@ -102,6 +106,7 @@ pub struct OnDiskCache<'sess> {
 struct Footer {
     file_index_to_stable_id: FxHashMap<SourceFileIndex, EncodedSourceFileId>,
     query_result_index: EncodedDepNodeIndex,
+    query_result_end: AbsoluteBytePos,
     side_effects_index: EncodedDepNodeIndex,
     // The location of all allocations.
     // Most uses only need values up to u32::MAX, but benchmarking indicates that we can use a u64
@@ -188,6 +193,7 @@ pub fn new(sess: &'sess Session, data: Mmap, start_pos: usize) -> Self {
             source_map: sess.source_map(),
             current_side_effects: Default::default(),
             query_result_index: footer.query_result_index.into_iter().collect(),
+            query_result_end: footer.query_result_end,
             prev_side_effects_index: footer.side_effects_index.into_iter().collect(),
             alloc_decoding_state: AllocDecodingState::new(footer.interpret_alloc_index),
             syntax_contexts: footer.syntax_contexts,
@@ -205,6 +211,7 @@ pub fn new_empty(source_map: &'sess SourceMap) -> Self {
             source_map,
             current_side_effects: Default::default(),
             query_result_index: Default::default(),
+            query_result_end: AbsoluteBytePos::new(0),
             prev_side_effects_index: Default::default(),
             alloc_decoding_state: AllocDecodingState::new(Vec::new()),
             syntax_contexts: FxHashMap::default(),
@@ -275,6 +282,7 @@ pub fn serialize(&self, tcx: TyCtxt<'_>, encoder: FileEncoder) -> FileEncodeResu
                 let qri = &mut query_result_index;
                 (tcx.query_system.fns.encode_query_results)(tcx, enc, qri);
             });
+            let query_result_end = AbsoluteBytePos::new(encoder.position());
 
             // Encode side effects.
             let side_effects_index: EncodedDepNodeIndex = self
@@ -344,6 +352,7 @@ pub fn serialize(&self, tcx: TyCtxt<'_>, encoder: FileEncoder) -> FileEncodeResu
                 &Footer {
                     file_index_to_stable_id,
                     query_result_index,
+                    query_result_end,
                     side_effects_index,
                     interpret_alloc_index,
                     syntax_contexts,
@@ -363,6 +372,17 @@ pub fn serialize(&self, tcx: TyCtxt<'_>, encoder: FileEncoder) -> FileEncodeResu
         })
     }
 
+    /// Returns the index of every cached query result together with the number of bytes
+    /// it takes up in the serialized data, in the order the results were encoded.
+    pub fn query_result_sizes(&self) -> Vec<(SerializedDepNodeIndex, usize)> {
+        let mut positions: Vec<_> =
+            self.query_result_index.iter().map(|(&index, pos)| (pos.to_usize(), index)).collect();
+        positions.sort_unstable();
+        let ends =
+            positions.iter().skip(1).map(|&(pos, _)| pos).chain([self.query_result_end.to_usize()]);
+        iter::zip(&positions, ends).map(|(&(start, index), end)| (index, end - start)).collect()
+    }
+
     /// Loads a `QuerySideEffects` created during the previous compilation session.
     pub fn load_side_effects(
         &self,
@@ -10,6 +10,7 @@
 use field_offset::FieldOffset;
 use measureme::StringId;
 use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::profiling::QueryCosts;
 use rustc_data_structures::sync::AtomicU64;
 use rustc_hir::def::DefKind;
 use rustc_hir::def_id::{DefId, LocalDefId};
@@ -83,6 +84,10 @@ pub struct QuerySystem<'tcx> {
     pub fns: QuerySystemFns<'tcx>,
 
     pub jobs: AtomicU64,
+
+    /// The self-time of the queries run for each item, for `--print=compile-cost`.
+    /// This is `None` unless that report was requested.
+    pub costs: Option<QueryCosts<DefId>>,
 }
 
 #[derive(Copy, Clone)]
@@ -3,6 +3,7 @@
 use crate::middle::codegen_fn_attrs::CodegenFnAttrFlags;
 use crate::query::Providers;
 use crate::ty::layout::IntegerExt;
+use crate::ty::print::with_no_trimmed_paths;
 use crate::ty::{
     self, FallibleTypeFolder, ToPredicate, Ty, TyCtxt, TypeFoldable, TypeFolder, TypeSuperFoldable,
     TypeVisitableExt,
@@ -595,6 +596,27 @@ pub fn typeck_root_def_id(self, def_id: DefId) -> DefId {
         def_id
     }
 
+    /// The name under which `--print=compile-cost` reports the costs of `def_id`.
+    /// Closures and inline consts are reported as part of their typeck root.
+    pub fn item_cost_name(self, def_id: DefId) -> String {
+        with_no_trimmed_paths!(self.def_path_str(self.typeck_root_def_id(def_id)))
+    }
+
+    /// Moves the self-time of the queries run so far into the item costs reported by
+    /// `--print=compile-cost`, under the names of the items they were run for.
+    pub fn record_query_costs(self) {
+        let (Some(item_costs), Some(query_costs)) =
+            (self.sess.prof.item_costs(), &self.query_system.costs)
+        else {
+            return;
+        };
+        let mut names = FxHashMap::default();
+        for ((query, def_id), time) in query_costs.take() {
+            let name = names.entry(def_id).or_insert_with(|| self.item_cost_name(def_id));
+            item_costs.record_item_time(query, name.clone(), time);
+        }
+    }
+
     /// Given the `DefId` and args a closure, creates the type of
     /// `self` argument that the closure expects. For example, for a
     /// `Fn` closure, this would return a reference type `&T` where
@@ -14,6 +14,7 @@
 use rustc_target::abi::FieldIdx;
 use rustc_target::spec::abi::Abi;
 
+use crate::pass_manager::emit_mir_remark;
 use crate::simplify::{remove_dead_blocks, CfgSimplifier};
 use crate::util;
 use crate::MirPass;
@@ -29,6 +30,9 @@
 
 const TOP_DOWN_DEPTH_LIMIT: usize = 5;
 
+/// The reason for not inlining a callee that is too costly.
+const COST_ABOVE_THRESHOLD: &str = "cost above threshold";
+
 pub struct Inline;
 
 #[derive(Copy, Clone, Debug)]
@@ -141,10 +145,27 @@ fn process_blocks(&mut self, caller_body: &mut Body<'tcx>, blocks: Range<BasicBl
             match self.try_inlining(caller_body, &callsite) {
                 Err(reason) => {
                     debug!("not-inlined {} [{}]", callsite.callee, reason);
+                    // `check_mir_body` already emitted a remark with the cost of the callee.
+                    if reason != COST_ABOVE_THRESHOLD {
+                        emit_mir_remark(
+                            self.tcx,
+                            Inline.name(),
+                            caller_body,
+                            callsite.source_info.span,
+                            || format!("did not inline `{}`: {reason}", callsite.callee),
+                        );
+                    }
                     continue;
                 }
                 Ok(new_blocks) => {
                     debug!("inlined {}", callsite.callee);
+                    emit_mir_remark(
+                        self.tcx,
+                        Inline.name(),
+                        caller_body,
+                        callsite.source_info.span,
+                        || format!("inlined `{}`", callsite.callee),
+                    );
                     self.changed = true;
 
                     self.history.push(callsite.callee.def_id());
@@ -185,7 +206,7 @@ fn try_inlining(
 
         self.check_mir_is_available(caller_body, &callsite.callee)?;
         let callee_body = try_instance_mir(self.tcx, callsite.callee.def)?;
-        self.check_mir_body(callsite, callee_body, callee_attrs)?;
+        self.check_mir_body(caller_body, callsite, callee_body, callee_attrs)?;
 
         if !self.tcx.consider_optimizing(|| {
             format!("Inline {:?} into {:?}", callsite.callee, caller_body.source)
@@ -430,9 +451,10 @@ fn check_codegen_attributes(
 
     /// Returns inlining decision that is based on the examination of callee MIR body.
     /// Assumes that codegen attributes have been checked for compatibility already.
-    #[instrument(level = "debug", skip(self, callee_body))]
+    #[instrument(level = "debug", skip(self, caller_body, callee_body))]
     fn check_mir_body(
         &self,
+        caller_body: &Body<'tcx>,
         callsite: &CallSite<'tcx>,
         callee_body: &Body<'tcx>,
         callee_attrs: &CodegenFnAttrs,
@@ -509,7 +531,15 @@ fn check_mir_body(
             Ok(())
         } else {
             debug!("NOT inlining {:?} [cost={} > threshold={}]", callsite, cost, threshold);
-            Err("cost above threshold")
+            let callee = callsite.callee;
+            emit_mir_remark(
+                self.tcx,
+                Inline.name(),
+                caller_body,
+                callsite.source_info.span,
+                || format!("inline cost of `{callee}` is {cost}, above threshold {threshold}"),
+            );
+            Err(COST_ABOVE_THRESHOLD)
         }
     }
 
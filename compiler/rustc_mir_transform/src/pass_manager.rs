@@ -1,6 +1,11 @@
+use rustc_data_structures::fingerprint::Fingerprint;
+use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
 use rustc_middle::mir::{self, Body, MirPhase, RuntimePhase};
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::TyCtxt;
+use rustc_session::config::Passes;
 use rustc_session::Session;
+use rustc_span::Span;
 
 use crate::{validate, MirPass};
 
@@ -121,8 +126,19 @@ fn run_passes_inner<'tcx>(
                 validate_body(tcx, body, format!("before pass {name}"));
             }
 
+            // Only fingerprint the body when remarks were requested for this pass, as
+            // hashing every body twice per pass is far too expensive otherwise.
+            let fingerprint_before =
+                mir_remarks_enabled(&tcx.sess, name).then(|| body_fingerprint(tcx, body));
+
             tcx.sess.time(name, || pass.run_pass(tcx, body));
 
+            if let Some(before) = fingerprint_before
+                && before != body_fingerprint(tcx, body)
+            {
+                emit_mir_remark(tcx, name, body, body.span, || format!("{name} changed the body"));
+            }
+
             if dump_enabled {
                 dump_mir_for_pass(tcx, body, &name, true);
             }
@@ -151,6 +167,41 @@ fn run_passes_inner<'tcx>(
     }
 }
 
+/// Returns whether `-Zmir-remarks` requested remarks for the pass named `pass_name`.
+pub(crate) fn mir_remarks_enabled(sess: &Session, pass_name: &str) -> bool {
+    match &sess.opts.unstable_opts.mir_remarks {
+        Passes::All => true,
+        Passes::Some(passes) => passes.iter().any(|p| p == pass_name),
+    }
+}
+
+/// Emits a remark on behalf of the pass named `pass_name` describing what it did to `body`.
+///
+/// The message is only built when remarks were requested for that pass.
+pub(crate) fn emit_mir_remark<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    pass_name: &str,
+    body: &Body<'tcx>,
+    span: Span,
+    message: impl FnOnce() -> String,
+) {
+    if mir_remarks_enabled(&tcx.sess, pass_name) {
+        // Remarks are not counted as diagnostics, so trimmed paths would report a
+        // `delayed_good_path_bugs` ICE in crates without warnings.
+        let (function, message) =
+            with_no_trimmed_paths!((tcx.def_path_str(body.source.def_id()), message()));
+        tcx.sess.diagnostic().emit_mir_remark(pass_name, &function, &message, span);
+    }
+}
+
+fn body_fingerprint<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> Fingerprint {
+    tcx.with_stable_hashing_context(|mut hcx| {
+        let mut hasher = StableHasher::new();
+        body.hash_stable(&mut hcx, &mut hasher);
+        hasher.finish()
+    })
+}
+
 pub fn validate_body<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>, when: String) {
     validate::Validator { when, mir_phase: body.phase }.run_pass(tcx, body);
 }
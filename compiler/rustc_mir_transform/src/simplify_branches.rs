@@ -1,3 +1,4 @@
+use crate::pass_manager::emit_mir_remark;
 use crate::MirPass;
 use rustc_middle::mir::*;
 use rustc_middle::ty::TyCtxt;
@@ -17,6 +18,7 @@ fn name(&self) -> &'static str {
 
     fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         let param_env = tcx.param_env_reveal_all_normalized(body.source.def_id());
+        let mut simplified = vec![];
         for block in body.basic_blocks_mut() {
             let terminator = block.terminator_mut();
             terminator.kind = match terminator.kind {
@@ -39,6 +41,12 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
                 },
                 _ => continue,
             };
+            simplified.push(terminator.source_info.span);
+        }
+        for span in simplified {
+            emit_mir_remark(tcx, self.name(), body, span, || {
+                "replaced a branch on a constant condition with a goto".to_string()
+            });
         }
     }
 }
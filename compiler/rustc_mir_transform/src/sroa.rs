@@ -1,3 +1,4 @@
+use crate::pass_manager::emit_mir_remark;
 use crate::MirPass;
 use rustc_index::bit_set::{BitSet, GrowableBitSet};
 use rustc_index::IndexVec;
@@ -32,6 +33,15 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
             debug!(?escaping);
             let replacements = compute_flattening(tcx, param_env, body, escaping);
             debug!(?replacements);
+            for (local, fragments) in replacements.fragments.iter_enumerated() {
+                if let Some(fragments) = fragments {
+                    let span = body.local_decls[local].source_info.span;
+                    emit_mir_remark(tcx, self.name(), body, span, || {
+                        let count = fragments.iter().flatten().count();
+                        format!("split `{local:?}` into {count} scalar locals")
+                    });
+                }
+            }
             let all_dead_locals = replace_flattened_locals(tcx, body, replacements);
             if !all_dead_locals.is_empty() {
                 excluded.union(&all_dead_locals);
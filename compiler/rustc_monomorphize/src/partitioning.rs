@@ -1129,6 +1129,27 @@ fn collect_and_partition_mono_items(tcx: TyCtxt<'_>, (): ()) -> (&DefIdSet, &[Co
         }
     }
 
+    if let Some(item_costs) = tcx.sess.prof.item_costs() {
+        // Record instantiation counts and the contents of each CGU for
+        // `--print=compile-cost`, which splits the time spent on a CGU between its items.
+        let mut names: FxHashMap<DefId, String> = Default::default();
+        let mut name = |mono_item: &MonoItem<'_>| {
+            let def_id = mono_item.def_id();
+            names.entry(def_id).or_insert_with(|| tcx.item_cost_name(def_id)).clone()
+        };
+        for mono_item in &items {
+            item_costs.record_instantiations(name(mono_item), 1);
+        }
+        for cgu in codegen_units {
+            let cgu_items = cgu
+                .items()
+                .iter()
+                .map(|(mono_item, data)| (name(mono_item), data.size_estimate))
+                .collect();
+            item_costs.record_codegen_unit(cgu.name().to_string(), cgu_items);
+        }
+    }
+
     let mono_items: DefIdSet = items
         .iter()
         .filter_map(|mono_item| match *mono_item {
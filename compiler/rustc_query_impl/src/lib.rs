@@ -18,6 +18,7 @@
 
 use crate::plumbing::{__rust_begin_short_backtrace, encode_all_query_results, try_mark_green};
 use field_offset::offset_of;
+use rustc_data_structures::profiling::QueryCosts;
 use rustc_data_structures::stable_hasher::HashStable;
 use rustc_data_structures::sync::AtomicU64;
 use rustc_middle::arena::Arena;
@@ -207,6 +208,7 @@ pub fn query_system<'tcx>(
     extern_providers: ExternProviders,
     on_disk_cache: Option<OnDiskCache<'tcx>>,
     incremental: bool,
+    record_costs: bool,
 ) -> QuerySystem<'tcx> {
     QuerySystem {
         states: Default::default(),
@@ -222,6 +224,7 @@ pub fn query_system<'tcx>(
             try_mark_green: try_mark_green,
         },
         jobs: AtomicU64::new(1),
+        costs: record_costs.then(QueryCosts::new),
     }
 }
 
@@ -575,6 +575,10 @@ pub fn dynamic_query<'tcx>() -> DynamicQuery<'tcx, queries::$name::Storage<'tcx>
                     compute: |tcx, key| {
                         #[cfg(debug_assertions)]
                         let _guard = tracing::span!(tracing::Level::TRACE, stringify!($name), ?key).entered();
+                        let _cost_timer = tcx.query_system.costs.as_ref().map(|costs| {
+                            let item = ::rustc_middle::query::Key::key_as_def_id(&key);
+                            costs.start(stringify!($name), item)
+                        });
                         __rust_begin_short_backtrace(||
                             queries::$name::provided_to_erased(
                                 tcx,
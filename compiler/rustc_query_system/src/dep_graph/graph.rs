@@ -669,6 +669,19 @@ pub fn prev_node_of(&self, prev_index: SerializedDepNodeIndex) -> DepNode<K> {
         self.previous.index_to_node(prev_index)
     }
 
+    /// Returns the dep-graph loaded from the previous compilation session.
+    #[inline]
+    pub fn previous(&self) -> &SerializedDepGraph<K> {
+        &self.previous
+    }
+
+    /// Returns the color the given node of the previous dep-graph has been marked with
+    /// during the current session, or `None` if it has not been evaluated.
+    #[inline]
+    pub fn prev_node_color(&self, prev_index: SerializedDepNodeIndex) -> Option<DepNodeColor> {
+        self.colors.get(prev_index)
+    }
+
     pub fn mark_debug_loaded_from_disk(&self, dep_node: DepNode<K>) {
         self.debug_loaded_from_disk.lock().insert(dep_node);
     }
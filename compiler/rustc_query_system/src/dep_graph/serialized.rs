@@ -87,6 +87,11 @@ pub fn fingerprint_by_index(&self, dep_node_index: SerializedDepNodeIndex) -> Fi
     pub fn node_count(&self) -> usize {
         self.index.len()
     }
+
+    #[inline]
+    pub fn indices(&self) -> impl Iterator<Item = SerializedDepNodeIndex> + Clone {
+        self.nodes.indices()
+    }
 }
 
 impl<'a, K: DepKind + Decodable<MemDecoder<'a>>> Decodable<MemDecoder<'a>>
@@ -530,6 +530,9 @@ pub enum ErrorOutputType {
         /// human output.
         json_rendered: HumanReadableErrorType,
     },
+    /// A single SARIF 2.1.0 log for the whole session, for code-scanning tools. It is
+    /// written to the file given by `-Z sarif-output`.
+    Sarif,
 }
 
 impl Default for ErrorOutputType {
@@ -739,6 +742,9 @@ pub enum PrintKind {
     LinkArgs,
     SplitDebuginfo,
     DeploymentTarget,
+    Lints,
+    Features,
+    CompileCost,
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
@@ -1699,7 +1705,8 @@ pub fn rustc_short_optgroups() -> Vec<RustcOptGroup> {
             "[crate-name|file-names|sysroot|target-libdir|cfg|calling-conventions|\
              target-list|target-cpus|target-features|relocation-models|code-models|\
              tls-models|target-spec-json|all-target-specs-json|native-static-libs|\
-             stack-protector-strategies|link-args|deployment-target]",
+             stack-protector-strategies|link-args|deployment-target|lints|features|\
+             compile-cost]",
         ),
         opt::flagmulti_s("g", "", "Equivalent to -C debuginfo=2"),
         opt::flagmulti_s("O", "", "Equivalent to -C opt-level=2"),
@@ -1931,6 +1938,7 @@ pub fn parse_error_format(
             Some("json") => ErrorOutputType::Json { pretty: false, json_rendered },
             Some("pretty-json") => ErrorOutputType::Json { pretty: true, json_rendered },
             Some("short") => ErrorOutputType::HumanReadable(HumanReadableErrorType::Short(color)),
+            Some("sarif") => ErrorOutputType::Sarif,
 
             Some(arg) => {
                 handler.abort_if_error_and_set_error_format(ErrorOutputType::HumanReadable(
@@ -2011,6 +2019,13 @@ fn check_error_format_stability(
             });
             handler.early_error("`--error-format=human-annotate-rs` is unstable");
         }
+        if let ErrorOutputType::Sarif = error_format {
+            handler.abort_if_error_and_set_error_format(ErrorOutputType::Json {
+                pretty: false,
+                json_rendered,
+            });
+            handler.early_error("`--error-format=sarif` is unstable");
+        }
     }
 }
 
@@ -2139,6 +2154,9 @@ fn collect_print_requests(
         ("link-args", PrintKind::LinkArgs),
         ("split-debuginfo", PrintKind::SplitDebuginfo),
         ("deployment-target", PrintKind::DeploymentTarget),
+        ("lints", PrintKind::Lints),
+        ("features", PrintKind::Features),
+        ("compile-cost", PrintKind::CompileCost),
     ];
 
     // We disallow reusing the same path in multiple prints, such as `--print
@@ -2157,24 +2175,21 @@ fn collect_print_requests(
             );
         }
         let kind = match PRINT_KINDS.iter().find(|&&(name, _)| name == req) {
-            Some((_, PrintKind::TargetSpec)) => {
+            Some(&(
+                name,
+                print_kind @ (PrintKind::TargetSpec
+                | PrintKind::AllTargetSpecs
+                | PrintKind::Lints
+                | PrintKind::Features
+                | PrintKind::CompileCost),
+            )) => {
                 if unstable_opts.unstable_options {
-                    PrintKind::TargetSpec
+                    print_kind
                 } else {
-                    handler.early_error(
-                        "the `-Z unstable-options` flag must also be passed to \
-                         enable the target-spec-json print option",
-                    );
-                }
-            }
-            Some((_, PrintKind::AllTargetSpecs)) => {
-                if unstable_opts.unstable_options {
-                    PrintKind::AllTargetSpecs
-                } else {
-                    handler.early_error(
+                    handler.early_error(format!(
                         "the `-Z unstable-options` flag must also be passed to \
-                         enable the all-target-specs-json print option",
-                    );
+                         enable the {name} print option",
+                    ));
                 }
             }
             Some(&(_, print_kind)) => print_kind,
@@ -3355,3 +3370,21 @@ pub fn extension(self) -> &'static str {
         }
     }
 }
+
+/// Which format to use for `-Z dump-incr-state`
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum DumpIncrStateFormat {
+    /// Plain text meant to be read by humans
+    Human,
+    /// Emit structured JSON
+    Json,
+}
+
+impl DumpIncrStateFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Human => "txt",
+            Self::Json => "json",
+        }
+    }
+}
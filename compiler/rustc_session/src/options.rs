@@ -381,6 +381,7 @@ mod desc {
     pub const parse_optimization_fuel: &str = "crate=integer";
     pub const parse_mir_spanview: &str = "`statement` (default), `terminator`, or `block`";
     pub const parse_dump_mono_stats: &str = "`markdown` (default) or `json`";
+    pub const parse_dump_incr_state: &str = "`human` (default) or `json`";
     pub const parse_instrument_coverage: &str =
         "`all` (default), `except-unused-generics`, `except-unused-functions`, or `off`";
     pub const parse_instrument_xray: &str = "either a boolean (`yes`, `no`, `on`, `off`, etc), or a comma separated list of settings: `always` or `never` (mutually exclusive), `ignore-loops`, `instruction-threshold=N`, `skip-entry`, `skip-exit`";
@@ -861,6 +862,21 @@ pub(crate) fn parse_time_passes_format(slot: &mut TimePassesFormat, v: Option<&s
         }
     }
 
+    pub(crate) fn parse_dump_incr_state(slot: &mut DumpIncrStateFormat, v: Option<&str>) -> bool {
+        match v {
+            None => true,
+            Some("json") => {
+                *slot = DumpIncrStateFormat::Json;
+                true
+            }
+            Some("human") => {
+                *slot = DumpIncrStateFormat::Human;
+                true
+            }
+            Some(_) => false,
+        }
+    }
+
     pub(crate) fn parse_dump_mono_stats(slot: &mut DumpMonoStatsFormat, v: Option<&str>) -> bool {
         match v {
             None => true,
@@ -1446,6 +1462,12 @@ pub(crate) fn parse_dump_solver_proof_tree(
         (default: no)"),
     dump_drop_tracking_cfg: Option<String> = (None, parse_opt_string, [UNTRACKED],
         "dump drop-tracking control-flow graph as a `.dot` file (default: no)"),
+    dump_incr_state: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
+        parse_switch_with_opt_path, [UNTRACKED],
+        "output the incremental compilation state written by this session: cached query \
+        results, work products and why previous entries were invalidated"),
+    dump_incr_state_format: DumpIncrStateFormat = (DumpIncrStateFormat::Human, parse_dump_incr_state, [UNTRACKED],
+        "the format to use for -Z dump-incr-state (`human` (default) or `json`)"),
     dump_mir: Option<String> = (None, parse_opt_string, [UNTRACKED],
         "dump MIR state to file.
         `val` is used to select which passes and functions to dump. For example:
@@ -1607,6 +1629,10 @@ pub(crate) fn parse_dump_solver_proof_tree(
     #[rustc_lint_opt_deny_field_access("use `Session::mir_opt_level` instead of this field")]
     mir_opt_level: Option<usize> = (None, parse_opt_number, [TRACKED],
         "MIR optimization level (0-4; default: 1 in non optimized builds and 2 in optimized builds)"),
+    mir_remarks: Passes = (Passes::Some(Vec::new()), parse_passes, [TRACKED],
+        "emit remarks on the JSON diagnostic stream for these MIR passes \
+        (space separated, or \"all\"; requires `--error-format=json`; incremental builds \
+        only emit them for the functions they optimize again)"),
     move_size_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
         "the size at which the `large_assignments` lint starts to be emitted"),
     mutable_noalias: bool = (true, parse_bool, [TRACKED],
@@ -1721,6 +1747,8 @@ pub(crate) fn parse_dump_solver_proof_tree(
         "enable origins tracking in MemorySanitizer"),
     sanitizer_recover: SanitizerSet = (SanitizerSet::empty(), parse_sanitizers, [TRACKED],
         "enable recovery for selected sanitizers"),
+    sarif_output: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "file into which to write the log of `--error-format=sarif` (default: `rustc.sarif`)"),
     saturating_float_casts: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "make float->int casts UB-free: numbers outside the integer type's range are clipped to \
         the max/min integer respectively, and NaN is mapped to 0 (default: yes)"),
@@ -2,7 +2,8 @@
 use crate::code_stats::CodeStats;
 pub use crate::code_stats::{DataTypeKind, FieldInfo, FieldKind, SizeKind, VariantInfo};
 use crate::config::{
-    self, CrateType, InstrumentCoverage, OptLevel, OutFileName, OutputType, SwitchWithOptPath,
+    self, CrateType, InstrumentCoverage, OptLevel, OutFileName, OutputType, PrintKind,
+    SwitchWithOptPath,
 };
 use crate::config::{ErrorOutputType, Input};
 use crate::errors;
@@ -23,10 +24,11 @@
 use rustc_errors::emitter::{Emitter, EmitterWriter, HumanReadableErrorType};
 use rustc_errors::json::JsonEmitter;
 use rustc_errors::registry::Registry;
+use rustc_errors::sarif::SarifEmitter;
 use rustc_errors::{
-    error_code, fallback_fluent_bundle, DiagnosticBuilder, DiagnosticId, DiagnosticMessage,
-    ErrorGuaranteed, FluentBundle, Handler, IntoDiagnostic, LazyFallbackBundle, MultiSpan, Noted,
-    TerminalUrl,
+    error_code, fallback_fluent_bundle, ColorConfig, DiagnosticBuilder, DiagnosticId,
+    DiagnosticMessage, ErrorGuaranteed, FluentBundle, Handler, IntoDiagnostic, LazyFallbackBundle,
+    MultiSpan, Noted, TerminalUrl,
 };
 use rustc_macros::HashStable_Generic;
 pub use rustc_span::def_id::StableCrateId;
@@ -1315,6 +1317,7 @@ pub fn link_dead_code(&self) -> bool {
 // JUSTIFICATION: part of session construction
 #[allow(rustc::bad_opt_access)]
 fn default_emitter(
+    handler: &EarlyErrorHandler,
     sopts: &config::Options,
     registry: rustc_errors::registry::Registry,
     source_map: Lrc<SourceMap>,
@@ -1377,6 +1380,15 @@ fn default_emitter(
             )
             .ui_testing(sopts.unstable_opts.ui_testing),
         ),
+        config::ErrorOutputType::Sarif => {
+            let path =
+                sopts.unstable_opts.sarif_output.as_deref().unwrap_or(Path::new("rustc.sarif"));
+            match SarifEmitter::create(path, Some(registry), source_map, bundle, fallback_bundle) {
+                Ok(emitter) => Box::new(emitter),
+                Err(e) => handler
+                    .early_error(format!("failed to create SARIF log `{}`: {e}", path.display())),
+            }
+        }
     }
 }
 
@@ -1437,7 +1449,8 @@ pub fn build_session(
         fluent_resources,
         sopts.unstable_opts.translate_directionality_markers,
     );
-    let emitter = default_emitter(&sopts, registry, source_map.clone(), bundle, fallback_bundle);
+    let emitter =
+        default_emitter(handler, &sopts, registry, source_map.clone(), bundle, fallback_bundle);
 
     let mut span_diagnostic = rustc_errors::Handler::with_emitter(emitter)
         .with_flags(sopts.unstable_opts.diagnostic_handler_flags(can_emit_warnings));
@@ -1496,6 +1509,7 @@ pub fn build_session(
     let prof = SelfProfilerRef::new(
         self_profiler,
         sopts.unstable_opts.time_passes.then(|| sopts.unstable_opts.time_passes_format),
+        sopts.prints.iter().any(|print| print.kind == PrintKind::CompileCost),
     );
 
     let ctfe_backtrace = Lock::new(match env::var("RUSTC_CTFE_BACKTRACE") {
@@ -1803,6 +1817,10 @@ fn mk_emitter(output: ErrorOutputType) -> Box<dyn Emitter + sync::Send + 'static
             false,
             TerminalUrl::No,
         )),
+        // Only the session's emitter writes a SARIF log, see `rustc_errors::sarif`.
+        config::ErrorOutputType::Sarif => {
+            Box::new(EmitterWriter::stderr(ColorConfig::Auto, fallback_bundle))
+        }
     };
     emitter
 }
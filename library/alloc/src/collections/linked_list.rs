@@ -163,6 +163,104 @@ fn into_element<A: Allocator>(self: Box<Self, A>) -> T {
     }
 }
 
+/// The first and last nodes of a non-empty run of nodes, linked together in both directions and
+/// with no node after the last one.
+type Run<T> = (NonNull<Node<T>>, NonNull<Node<T>>);
+
+/// Links runs of nodes together, in order, into a single run.
+///
+/// # Safety
+/// The runs must consist of valid nodes, and must not share any node.
+unsafe fn concat_runs<T>(runs: impl IntoIterator<Item = Option<Run<T>>>) -> Option<Run<T>> {
+    let mut result: Option<Run<T>> = None;
+    for (head, tail) in runs.into_iter().flatten() {
+        // This method takes care not to create mutable references to whole nodes,
+        // to maintain validity of aliasing pointers into `element`.
+        unsafe {
+            (*tail.as_ptr()).next = None;
+            match result {
+                Some((first, last)) => {
+                    (*last.as_ptr()).next = Some(head);
+                    (*head.as_ptr()).prev = Some(last);
+                    result = Some((first, tail));
+                }
+                None => {
+                    (*head.as_ptr()).prev = None;
+                    result = Some((head, tail));
+                }
+            }
+        }
+    }
+    result
+}
+
+/// A merge of two sorted runs of nodes. Each node is in one of its three runs at any time, so
+/// the nodes can still be linked back together if the comparator panics partway through.
+struct Merge<T> {
+    merged: Option<Run<T>>,
+    a: Option<Run<T>>,
+    b: Option<Run<T>>,
+}
+
+impl<T> Merge<T> {
+    fn new() -> Self {
+        Merge { merged: None, a: None, b: None }
+    }
+
+    /// Merges two runs of nodes which are each sorted according to `compare`, and leaves the
+    /// merged run in `self.merged`, which must be empty. Among equal elements, those of `a` come
+    /// first.
+    ///
+    /// # Safety
+    /// The runs must consist of valid nodes, and must not share any node.
+    unsafe fn merge_runs<F>(&mut self, a: Option<Run<T>>, b: Option<Run<T>>, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        debug_assert!(self.merged.is_none());
+        self.a = a;
+        self.b = b;
+
+        // This method takes care not to create mutable references to whole nodes,
+        // to maintain validity of aliasing pointers into `element`.
+        unsafe {
+            while let (Some((x, x_tail)), Some((y, y_tail))) = (self.a, self.b) {
+                let node = if compare(&(*y.as_ptr()).element, &(*x.as_ptr()).element).is_lt() {
+                    self.b = (*y.as_ptr()).next.map(|next| (next, y_tail));
+                    y
+                } else {
+                    self.a = (*x.as_ptr()).next.map(|next| (next, x_tail));
+                    x
+                };
+                match self.merged {
+                    Some((head, tail)) => {
+                        (*tail.as_ptr()).next = Some(node);
+                        (*node.as_ptr()).prev = Some(tail);
+                        self.merged = Some((head, node));
+                    }
+                    None => {
+                        (*node.as_ptr()).prev = None;
+                        self.merged = Some((node, node));
+                    }
+                }
+            }
+
+            // One of the runs is exhausted, the rest of the other one comes after all the
+            // merged nodes.
+            self.merged = self.take();
+        }
+    }
+
+    /// Links the merged nodes and the rest of both runs into a single run, leaving the merge
+    /// empty.
+    ///
+    /// # Safety
+    /// The runs of the merge must consist of valid nodes.
+    unsafe fn take(&mut self) -> Option<Run<T>> {
+        unsafe { concat_runs([self.merged.take(), self.a.take(), self.b.take()]) }
+    }
+}
+
 // private methods
 impl<T, A: Allocator> LinkedList<T, A> {
     /// Adds the given node to the front of the list.
@@ -495,6 +593,89 @@ pub fn append(&mut self, other: &mut Self) {
             }
         }
     }
+
+    /// Merges `other` into the list, both of which must be sorted, so that the list stays sorted.
+    ///
+    /// This reuses all the nodes from `other` and moves them into `self`. After this operation,
+    /// `other` becomes empty. The merge is stable: elements of `self` come before equal elements
+    /// of `other`.
+    ///
+    /// This operation should compute in *O*(*n* + *m*) time and *O*(1) memory, where *n* and *m*
+    /// are the lengths of the two lists. If either list isn't sorted, the elements end up in an
+    /// unspecified order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_sort)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list1 = LinkedList::from([1, 4, 6]);
+    /// let mut list2 = LinkedList::from([2, 3, 5, 7]);
+    ///
+    /// list1.merge(&mut list2);
+    /// assert!(list1.into_iter().eq([1, 2, 3, 4, 5, 6, 7]));
+    /// assert!(list2.is_empty());
+    /// ```
+    #[unstable(feature = "linked_list_sort", issue = "none")]
+    pub fn merge(&mut self, other: &mut Self)
+    where
+        T: Ord,
+    {
+        self.merge_by(other, T::cmp)
+    }
+
+    /// Merges `other` into the list, both of which must be sorted with the given comparator
+    /// function, so that the list stays sorted.
+    ///
+    /// See [`merge`](LinkedList::merge) for details. If `compare` panics, all the elements are
+    /// left in `self` in an unspecified order, and `other` is left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_sort)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list1 = LinkedList::from([(3, 'a'), (1, 'b')]);
+    /// let mut list2 = LinkedList::from([(3, 'c'), (2, 'd')]);
+    ///
+    /// list1.merge_by(&mut list2, |a, b| b.0.cmp(&a.0));
+    /// assert!(list1.into_iter().eq([(3, 'a'), (3, 'c'), (2, 'd'), (1, 'b')]));
+    /// ```
+    #[unstable(feature = "linked_list_sort", issue = "none")]
+    pub fn merge_by<F>(&mut self, other: &mut Self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // Links the merged nodes back into the list, including when `compare` panics.
+        struct DropGuard<'a, T, A: Allocator> {
+            list: &'a mut LinkedList<T, A>,
+            len: usize,
+            merge: Merge<T>,
+        }
+
+        impl<'a, T, A: Allocator> Drop for DropGuard<'a, T, A> {
+            fn drop(&mut self) {
+                // SAFETY: The merge is made of all the nodes of both lists.
+                if let Some((head, tail)) = unsafe { self.merge.take() } {
+                    self.list.head = Some(head);
+                    self.list.tail = Some(tail);
+                    self.list.len = self.len;
+                }
+            }
+        }
+
+        // Both lists are left empty while the nodes are relinked, so that they stay consistent
+        // until the guard links the nodes back into `self`.
+        let len = mem::replace(&mut self.len, 0) + mem::replace(&mut other.len, 0);
+        let a = self.head.take().zip(self.tail.take());
+        let b = other.head.take().zip(other.tail.take());
+
+        let mut guard = DropGuard { list: self, len, merge: Merge::new() };
+        // SAFETY: Both runs are made of all the nodes of one of the lists.
+        unsafe { guard.merge.merge_runs(a, b, &mut compare) };
+    }
 }
 
 impl<T, A: Allocator> LinkedList<T, A> {
@@ -1065,6 +1246,106 @@ pub fn extract_if<F>(&mut self, filter: F) -> ExtractIf<'_, T, F, A>
 
         ExtractIf { list: self, it, pred: filter, idx: 0, old_len }
     }
+
+    /// Sorts the list with a comparator function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements). It relinks the existing
+    /// nodes instead of moving the elements, and doesn't allocate.
+    ///
+    /// This operation should compute in *O*(*n* \* log(*n*)) time and *O*(1) memory, as a
+    /// bottom-up merge sort. If `compare` panics, or if the comparator function does not
+    /// implement a total order, the list keeps all its elements in an unspecified order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_sort)]
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list = LinkedList::from([5, 4, 1, 3, 2]);
+    ///
+    /// list.sort_by(|a, b| a.cmp(b));
+    /// assert!(list.iter().eq(&[1, 2, 3, 4, 5]));
+    ///
+    /// // reverse sorting
+    /// list.sort_by(|a, b| b.cmp(a));
+    /// assert!(list.iter().eq(&[5, 4, 3, 2, 1]));
+    /// ```
+    #[unstable(feature = "linked_list_sort", issue = "none")]
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // Links all the nodes back into the list, including when `compare` panics.
+        struct DropGuard<'a, T, A: Allocator> {
+            list: &'a mut LinkedList<T, A>,
+            len: usize,
+            // The nodes that haven't been added to a run yet, in their original order.
+            rest: Option<Run<T>>,
+            runs: [Option<Run<T>>; usize::BITS as usize],
+            carry: Option<Run<T>>,
+            merge: Merge<T>,
+        }
+
+        impl<'a, T, A: Allocator> Drop for DropGuard<'a, T, A> {
+            fn drop(&mut self) {
+                // SAFETY: Each node of the list is in exactly one of the runs.
+                let nodes = unsafe {
+                    let (merged, carry, rest) =
+                        (self.merge.take(), self.carry.take(), self.rest.take());
+                    let runs = self.runs.iter_mut().rev().map(Option::take);
+                    concat_runs([merged, carry].into_iter().chain(runs).chain([rest]))
+                };
+                if let Some((head, tail)) = nodes {
+                    self.list.head = Some(head);
+                    self.list.tail = Some(tail);
+                    self.list.len = self.len;
+                }
+            }
+        }
+
+        // The list is left empty while the nodes are relinked, so that it stays consistent until
+        // the guard links the nodes back into it.
+        let len = mem::replace(&mut self.len, 0);
+        let rest = self.head.take().zip(self.tail.take());
+
+        // `runs[i]` is either empty or holds a sorted run of 2^i nodes, which come before the
+        // nodes of all the lower runs in the original order. Nodes are added one at a time like
+        // a binary counter is incremented, merging the full runs they carry over.
+        let mut guard = DropGuard {
+            list: self,
+            len,
+            rest,
+            runs: [None; usize::BITS as usize],
+            carry: None,
+            merge: Merge::new(),
+        };
+        // SAFETY: Each node is unlinked from `rest` before being merged into a run, so runs
+        // never share a node.
+        unsafe {
+            while let Some((node, tail)) = guard.rest {
+                guard.rest = (*node.as_ptr()).next.map(|next| (next, tail));
+                (*node.as_ptr()).next = None;
+                (*node.as_ptr()).prev = None;
+
+                guard.carry = Some((node, node));
+                let mut i = 0;
+                while let Some(run) = guard.runs[i].take() {
+                    let carry = guard.carry.take();
+                    guard.merge.merge_runs(Some(run), carry, &mut compare);
+                    guard.carry = guard.merge.merged.take();
+                    i += 1;
+                }
+                guard.runs[i] = guard.carry.take();
+            }
+
+            for i in 0..guard.runs.len() {
+                let (run, carry) = (guard.runs[i].take(), guard.carry.take());
+                guard.merge.merge_runs(run, carry, &mut compare);
+                guard.carry = guard.merge.merged.take();
+            }
+        }
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1164,3 +1164,102 @@ fn drop(&mut self) {
 
     assert_eq!(unsafe { DROPS }, 8);
 }
+
+#[test]
+fn test_sort_by() {
+    let mut rng = crate::test_helpers::test_rng();
+    for len in [0, 1, 2, 3, 10, 100, 257] {
+        // Few distinct keys, to check that the sort is stable.
+        let v: Vec<(u32, usize)> = (0..len).map(|i| (rng.next_u32() % 8, i)).collect();
+        let mut list: LinkedList<_> = v.iter().copied().collect();
+
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+        check_links(&list);
+        let mut sorted = v.clone();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+        assert!(list.iter().eq(&sorted));
+
+        list.sort_by(|a, b| b.cmp(a));
+        check_links(&list);
+        sorted.sort_by(|a, b| b.cmp(a));
+        assert!(list.iter().eq(&sorted));
+    }
+}
+
+#[test]
+#[cfg_attr(not(panic = "unwind"), ignore = "test requires unwinding support")]
+fn test_sort_by_panic_keeps_elements() {
+    // Panic at each comparison in turn, until the sort makes it through.
+    for panic_at in 1.. {
+        let mut list: LinkedList<_> = (0..10).rev().collect();
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                calls += 1;
+                if calls == panic_at {
+                    panic!("panic in `compare`");
+                }
+                a.cmp(b)
+            })
+        }));
+        check_links(&list);
+        assert_eq!(list.len(), 10);
+        let mut elements: Vec<_> = list.into_iter().collect();
+        if result.is_ok() {
+            assert_eq!(elements, (0..10).collect::<Vec<_>>());
+            break;
+        }
+        elements.sort();
+        assert_eq!(elements, (0..10).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+#[cfg_attr(not(panic = "unwind"), ignore = "test requires unwinding support")]
+fn test_merge_by_panic_keeps_elements() {
+    let mut a = LinkedList::from([1, 3, 5, 7]);
+    let mut b = LinkedList::from([2, 4, 6]);
+    let mut calls = 0;
+    catch_unwind(AssertUnwindSafe(|| {
+        a.merge_by(&mut b, |x, y| {
+            calls += 1;
+            if calls == 3 {
+                panic!("panic in `compare`");
+            }
+            x.cmp(y)
+        })
+    }))
+    .unwrap_err();
+    check_links(&a);
+    check_links(&b);
+    assert!(b.is_empty());
+    let mut elements: Vec<_> = a.into_iter().collect();
+    elements.sort();
+    assert_eq!(elements, [1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_merge() {
+    let mut a = LinkedList::from([1, 3, 3, 8]);
+    let mut b = LinkedList::from([0, 3, 9, 10]);
+    a.merge(&mut b);
+    check_links(&a);
+    check_links(&b);
+    assert!(a.iter().eq(&[0, 1, 3, 3, 3, 8, 9, 10]));
+    assert!(b.is_empty());
+
+    a.merge(&mut b);
+    check_links(&a);
+    assert_eq!(a.len(), 8);
+    b.merge(&mut a);
+    check_links(&b);
+    assert!(b.iter().eq(&[0, 1, 3, 3, 3, 8, 9, 10]));
+    assert!(a.is_empty());
+
+    // Elements of `self` come first among equal ones.
+    let mut a = LinkedList::from([(1, 'a'), (2, 'a')]);
+    let mut b = LinkedList::from([(1, 'b'), (2, 'b'), (2, 'c')]);
+    a.merge_by(&mut b, |x, y| x.0.cmp(&y.0));
+    check_links(&a);
+    assert!(a.iter().eq(&[(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b'), (2, 'c')]));
+}
@@ -1663,6 +1663,42 @@ pub fn push_back(&mut self, value: T) {
         self.len += 1;
     }
 
+    /// Tries to append an element to the back of the deque.
+    ///
+    /// This is the fallible counterpart of [`push_back`]: if the deque has
+    /// to grow and the allocation fails, an error is returned instead of
+    /// panicking or aborting. On error, `value` is dropped and the deque is
+    /// unchanged.
+    ///
+    /// [`push_back`]: VecDeque::push_back
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collection_ops)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf = VecDeque::new();
+    /// buf.try_push_back(1).expect("why is the test harness OOMing on 4 bytes?");
+    /// buf.try_push_back(3).expect("why is the test harness OOMing on 8 bytes?");
+    /// assert_eq!(3, *buf.back().unwrap());
+    /// ```
+    #[unstable(feature = "fallible_collection_ops", issue = "none")]
+    pub fn try_push_back(&mut self, value: T) -> Result<(), TryReserveError> {
+        if self.is_full() {
+            self.try_reserve(1)?;
+        }
+
+        unsafe { self.buffer_write(self.to_physical_idx(self.len), value) }
+        self.len += 1;
+        Ok(())
+    }
+
     #[inline]
     fn is_contiguous(&self) -> bool {
         // Do the calculation like this to avoid overflowing if len + head > usize::MAX
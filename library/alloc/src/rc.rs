@@ -693,6 +693,68 @@ pub fn new_in(value: T, alloc: A) -> Rc<T, A> {
         }
     }
 
+    /// Constructs a new `Rc<T, A>` in the provided allocator while giving you
+    /// a `Weak<T, A>` to the allocation, to allow you to construct a `T` which
+    /// holds a weak pointer to itself.
+    ///
+    /// See [`Rc::new_cyclic`] for details; this behaves the same, except that
+    /// the allocation is made with `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    ///
+    /// use std::alloc::System;
+    /// use std::rc::{Rc, Weak};
+    ///
+    /// struct Gadget {
+    ///     me: Weak<Gadget, System>,
+    /// }
+    ///
+    /// let gadget = Rc::new_cyclic_in(|me| Gadget { me: me.clone() }, System);
+    /// assert!(Rc::ptr_eq(&gadget, &gadget.me.upgrade().unwrap()));
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub fn new_cyclic_in<F>(data_fn: F, alloc: A) -> Rc<T, A>
+    where
+        F: FnOnce(&Weak<T, A>) -> T,
+    {
+        // Construct the inner in the "uninitialized" state with a single
+        // weak reference.
+        let (uninit_ptr, alloc) = Box::into_unique(Box::new_in(
+            RcBox {
+                strong: Cell::new(0),
+                weak: Cell::new(1),
+                value: mem::MaybeUninit::<T>::uninit(),
+            },
+            alloc,
+        ));
+        let init_ptr: NonNull<RcBox<T>> = NonNull::from(uninit_ptr).cast();
+
+        let weak = Weak { ptr: init_ptr, alloc };
+
+        // As in `new_cyclic`, we keep ownership of the weak pointer while
+        // `data_fn` runs so that the allocation stays alive.
+        let data = data_fn(&weak);
+
+        // Strong references should collectively own a shared weak reference,
+        // so don't run the destructor for our old weak reference.
+        let weak = mem::ManuallyDrop::new(weak);
+
+        unsafe {
+            let inner = init_ptr.as_ptr();
+            ptr::write(ptr::addr_of_mut!((*inner).value), data);
+
+            let prev_value = (*inner).strong.get();
+            debug_assert_eq!(prev_value, 0, "No prior strong references should exist");
+            (*inner).strong.set(1);
+
+            Rc::from_inner_in(init_ptr, ptr::read(&weak.alloc))
+        }
+    }
+
     /// Constructs a new `Rc` with uninitialized contents in the provided allocator.
     ///
     /// # Examples
@@ -2816,6 +2878,13 @@ pub unsafe fn from_raw(ptr: *const T) -> Self {
 }
 
 impl<T: ?Sized, A: Allocator> Weak<T, A> {
+    /// Returns a reference to the underlying allocator.
+    #[inline]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
     /// Returns a raw pointer to the object `T` pointed to by this `Weak<T>`.
     ///
     /// The pointer is valid only if there are some strong references. The pointer may be dangling,
@@ -2893,11 +2962,12 @@ pub fn into_raw(self) -> *const T {
         result
     }
 
-    /// Consumes the `Weak<T>` and turns it into a raw pointer.
+    /// Consumes the `Weak<T>` and turns it into a raw pointer, returning the
+    /// allocator alongside it.
     ///
     /// This converts the weak pointer into a raw pointer, while still preserving the ownership of
     /// one weak reference (the weak count is not modified by this operation). It can be turned
-    /// back into the `Weak<T>` with [`from_raw`].
+    /// back into the `Weak<T, A>` with [`from_raw_in`].
     ///
     /// The same restrictions of accessing the target of the pointer as with
     /// [`as_ptr`] apply.
@@ -2905,29 +2975,29 @@ pub fn into_raw(self) -> *const T {
     /// # Examples
     ///
     /// ```
+    /// #![feature(allocator_api)]
+    /// use std::alloc::System;
     /// use std::rc::{Rc, Weak};
     ///
-    /// let strong = Rc::new("hello".to_owned());
+    /// let strong = Rc::new_in("hello".to_owned(), System);
     /// let weak = Rc::downgrade(&strong);
-    /// let raw = weak.into_raw();
+    /// let (raw, alloc) = weak.into_raw_and_alloc();
     ///
     /// assert_eq!(1, Rc::weak_count(&strong));
     /// assert_eq!("hello", unsafe { &*raw });
     ///
-    /// drop(unsafe { Weak::from_raw(raw) });
+    /// drop(unsafe { Weak::from_raw_in(raw, alloc) });
     /// assert_eq!(0, Rc::weak_count(&strong));
     /// ```
     ///
-    /// [`from_raw`]: Weak::from_raw
+    /// [`from_raw_in`]: Weak::from_raw_in
     /// [`as_ptr`]: Weak::as_ptr
     #[inline]
     #[unstable(feature = "allocator_api", issue = "32838")]
-    pub fn into_raw_and_alloc(self) -> (*const T, A)
-    where
-        A: Clone,
-    {
+    pub fn into_raw_and_alloc(self) -> (*const T, A) {
         let result = self.as_ptr();
-        let alloc = self.alloc.clone();
+        // SAFETY: `self` is forgotten right after, so the allocator is moved out exactly once.
+        let alloc = unsafe { ptr::read(&self.alloc) };
         mem::forget(self);
         (result, alloc)
     }
@@ -575,6 +575,34 @@ struct TwoRefs {
     assert_eq!(Rc::weak_count(&two_refs), 2);
 }
 
+#[test]
+fn test_rc_cyclic_in() {
+    struct OneRef {
+        inner: Weak<OneRef, std::alloc::System>,
+    }
+
+    let one_ref = Rc::new_cyclic_in(
+        |inner| {
+            assert_eq!(inner.strong_count(), 0);
+            assert!(inner.upgrade().is_none());
+            let _: &std::alloc::System = inner.allocator();
+            OneRef { inner: inner.clone() }
+        },
+        std::alloc::System,
+    );
+
+    assert_eq!(Rc::strong_count(&one_ref), 1);
+    assert_eq!(Rc::weak_count(&one_ref), 1);
+
+    let one_ref2 = Weak::upgrade(&one_ref.inner).unwrap();
+    assert!(Rc::ptr_eq(&one_ref, &one_ref2));
+
+    let weak = one_ref.inner.clone();
+    let (ptr, alloc) = weak.into_raw_and_alloc();
+    let weak = unsafe { Weak::from_raw_in(ptr, alloc) };
+    assert!(Rc::ptr_eq(&one_ref, &weak.upgrade().unwrap()));
+}
+
 #[test]
 fn test_unique_rc_weak() {
     let rc = UniqueRc::new(42);
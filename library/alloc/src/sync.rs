@@ -717,6 +717,69 @@ pub fn new_in(data: T, alloc: A) -> Arc<T, A> {
         unsafe { Self::from_inner_in(ptr.into(), alloc) }
     }
 
+    /// Constructs a new `Arc<T, A>` in the provided allocator while giving you
+    /// a `Weak<T, A>` to the allocation, to allow you to construct a `T` which
+    /// holds a weak pointer to itself.
+    ///
+    /// See [`Arc::new_cyclic`] for details; this behaves the same, except that
+    /// the allocation is made with `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    ///
+    /// use std::alloc::System;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// struct Gadget {
+    ///     me: Weak<Gadget, System>,
+    /// }
+    ///
+    /// let gadget = Arc::new_cyclic_in(|me| Gadget { me: me.clone() }, System);
+    /// assert!(Arc::ptr_eq(&gadget, &gadget.me.upgrade().unwrap()));
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[inline]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub fn new_cyclic_in<F>(data_fn: F, alloc: A) -> Arc<T, A>
+    where
+        F: FnOnce(&Weak<T, A>) -> T,
+    {
+        // Construct the inner in the "uninitialized" state with a single
+        // weak reference.
+        let (uninit_ptr, alloc) = Box::into_unique(Box::new_in(
+            ArcInner {
+                strong: atomic::AtomicUsize::new(0),
+                weak: atomic::AtomicUsize::new(1),
+                data: mem::MaybeUninit::<T>::uninit(),
+            },
+            alloc,
+        ));
+        let init_ptr: NonNull<ArcInner<T>> = NonNull::from(uninit_ptr).cast();
+
+        let weak = Weak { ptr: init_ptr, alloc };
+
+        // As in `new_cyclic`, we keep ownership of the weak pointer while
+        // `data_fn` runs so that the allocation stays alive.
+        let data = data_fn(&weak);
+
+        // Strong references should collectively own a shared weak reference,
+        // so don't run the destructor for our old weak reference.
+        let weak = mem::ManuallyDrop::new(weak);
+
+        unsafe {
+            let inner = init_ptr.as_ptr();
+            ptr::write(ptr::addr_of_mut!((*inner).data), data);
+
+            // See `new_cyclic` for why `Release` is sufficient here.
+            let prev_value = (*inner).strong.fetch_add(1, Release);
+            debug_assert_eq!(prev_value, 0, "No prior strong references should exist");
+
+            Arc::from_inner_in(init_ptr, ptr::read(&weak.alloc))
+        }
+    }
+
     /// Constructs a new `Arc` with uninitialized contents in the provided allocator.
     ///
     /// # Examples
@@ -2598,6 +2661,13 @@ pub unsafe fn from_raw(ptr: *const T) -> Self {
 }
 
 impl<T: ?Sized, A: Allocator> Weak<T, A> {
+    /// Returns a reference to the underlying allocator.
+    #[inline]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
     /// Returns a raw pointer to the object `T` pointed to by this `Weak<T>`.
     ///
     /// The pointer is valid only if there are some strong references. The pointer may be dangling,
@@ -2675,6 +2745,46 @@ pub fn into_raw(self) -> *const T {
         result
     }
 
+    /// Consumes the `Weak<T>` and turns it into a raw pointer, returning the
+    /// allocator alongside it.
+    ///
+    /// This converts the weak pointer into a raw pointer, while still preserving the ownership of
+    /// one weak reference (the weak count is not modified by this operation). It can be turned
+    /// back into the `Weak<T, A>` with [`from_raw_in`].
+    ///
+    /// The same restrictions of accessing the target of the pointer as with
+    /// [`as_ptr`] apply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use std::alloc::System;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let strong = Arc::new_in("hello".to_owned(), System);
+    /// let weak = Arc::downgrade(&strong);
+    /// let (raw, alloc) = weak.into_raw_and_alloc();
+    ///
+    /// assert_eq!(1, Arc::weak_count(&strong));
+    /// assert_eq!("hello", unsafe { &*raw });
+    ///
+    /// drop(unsafe { Weak::from_raw_in(raw, alloc) });
+    /// assert_eq!(0, Arc::weak_count(&strong));
+    /// ```
+    ///
+    /// [`from_raw_in`]: Weak::from_raw_in
+    /// [`as_ptr`]: Weak::as_ptr
+    #[inline]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub fn into_raw_and_alloc(self) -> (*const T, A) {
+        let result = self.as_ptr();
+        // SAFETY: `self` is forgotten right after, so the allocator is moved out exactly once.
+        let alloc = unsafe { ptr::read(&self.alloc) };
+        mem::forget(self);
+        (result, alloc)
+    }
+
     /// Converts a raw pointer previously created by [`into_raw`] back into `Weak<T>` in the provided
     /// allocator.
     ///
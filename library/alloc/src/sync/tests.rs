@@ -651,6 +651,30 @@ struct TwoRefs {
     assert_eq!(Arc::weak_count(&two_refs), 2);
 }
 
+#[test]
+fn test_arc_cyclic_in() {
+    struct OneRef {
+        inner: Weak<OneRef, std::alloc::System>,
+    }
+
+    let one_ref = Arc::new_cyclic_in(
+        |inner| {
+            assert_eq!(inner.strong_count(), 0);
+            assert!(inner.upgrade().is_none());
+            OneRef { inner: inner.clone() }
+        },
+        std::alloc::System,
+    );
+
+    assert_eq!(Arc::strong_count(&one_ref), 1);
+    assert_eq!(Arc::weak_count(&one_ref), 1);
+
+    let weak = one_ref.inner.clone();
+    let (ptr, alloc) = weak.into_raw_and_alloc();
+    let weak = unsafe { Weak::from_raw_in(ptr, alloc) };
+    assert!(Arc::ptr_eq(&one_ref, &weak.upgrade().unwrap()));
+}
+
 /// Test for Arc::drop bug (https://github.com/rust-lang/rust/issues/55005)
 #[test]
 #[cfg(miri)] // relies on Stacked Borrows in Miri
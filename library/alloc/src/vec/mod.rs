@@ -114,10 +114,8 @@
 #[cfg(not(no_global_oom_handling))]
 mod spec_from_elem;
 
-#[cfg(not(no_global_oom_handling))]
 use self::set_len_on_drop::SetLenOnDrop;
 
-#[cfg(not(no_global_oom_handling))]
 mod set_len_on_drop;
 
 #[cfg(not(no_global_oom_handling))]
@@ -1469,6 +1467,63 @@ fn assert_failed(index: usize, len: usize) -> ! {
         }
     }
 
+    /// Tries to insert an element at position `index` within the vector,
+    /// shifting all elements after it to the right.
+    ///
+    /// This is the fallible counterpart of [`insert`]: if the vector has to
+    /// grow and the allocation fails, an error is returned instead of
+    /// panicking or aborting. On error, `element` is dropped and the vector
+    /// is unchanged.
+    ///
+    /// [`insert`]: Vec::insert
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collection_ops)]
+    /// let mut vec = vec![1, 2, 3];
+    /// vec.try_insert(1, 4).expect("why is the test harness OOMing on 16 bytes?");
+    /// assert_eq!(vec, [1, 4, 2, 3]);
+    /// ```
+    #[unstable(feature = "fallible_collection_ops", issue = "none")]
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), TryReserveError> {
+        #[cold]
+        #[inline(never)]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+
+        let len = self.len();
+        if index > len {
+            assert_failed(index, len);
+        }
+
+        // space for the new element
+        if len == self.buf.capacity() {
+            self.try_reserve(1)?;
+        }
+
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            if index < len {
+                // Shift everything over to make space.
+                ptr::copy(p, p.add(1), len - index);
+            }
+            ptr::write(p, element);
+            self.set_len(len + 1);
+        }
+        Ok(())
+    }
+
     /// Removes and returns the element at position `index` within the vector,
     /// shifting all elements after it to the left.
     ///
@@ -1835,6 +1890,49 @@ pub fn push(&mut self, value: T) {
         }
     }
 
+    /// Tries to append an element to the back of a collection.
+    ///
+    /// This is the fallible counterpart of [`push`]: if the vector has to
+    /// grow and the allocation fails, or the new capacity would exceed
+    /// `isize::MAX` bytes, an error is returned instead of panicking or
+    /// aborting. On error, `value` is dropped and the vector is unchanged.
+    ///
+    /// [`push`]: Vec::push
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collection_ops)]
+    /// use std::collections::TryReserveError;
+    ///
+    /// fn collect_doubled(data: &[u32]) -> Result<Vec<u32>, TryReserveError> {
+    ///     let mut output = Vec::new();
+    ///     for &val in data {
+    ///         output.try_push(val * 2)?;
+    ///     }
+    ///     Ok(output)
+    /// }
+    /// assert_eq!(collect_doubled(&[1, 2, 3]), Ok(vec![2, 4, 6]));
+    /// ```
+    #[inline]
+    #[unstable(feature = "fallible_collection_ops", issue = "none")]
+    pub fn try_push(&mut self, value: T) -> Result<(), TryReserveError> {
+        if self.len == self.buf.capacity() {
+            self.buf.try_reserve(self.len, 1)?;
+        }
+        unsafe {
+            let end = self.as_mut_ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+        Ok(())
+    }
+
     /// Appends an element if there is sufficient spare capacity, otherwise an error is returned
     /// with the element.
     ///
@@ -2387,6 +2485,45 @@ pub fn extend_from_slice(&mut self, other: &[T]) {
         self.spec_extend(other.iter())
     }
 
+    /// Tries to clone and append all elements in a slice to the `Vec`.
+    ///
+    /// This is the fallible counterpart of [`extend_from_slice`]: the space
+    /// for all of `other` is reserved up front, and if that allocation fails
+    /// an error is returned and the vector is left unchanged.
+    ///
+    /// [`extend_from_slice`]: Vec::extend_from_slice
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collection_ops)]
+    /// let mut vec = vec![1];
+    /// vec.try_extend_from_slice(&[2, 3, 4]).expect("why is the test harness OOMing on 16 bytes?");
+    /// assert_eq!(vec, [1, 2, 3, 4]);
+    /// ```
+    #[unstable(feature = "fallible_collection_ops", issue = "none")]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), TryReserveError> {
+        self.try_reserve(other.len())?;
+
+        unsafe {
+            let mut ptr = self.as_mut_ptr().add(self.len());
+            let mut local_len = SetLenOnDrop::new(&mut self.len);
+            for element in other {
+                ptr::write(ptr, element.clone());
+                ptr = ptr.add(1);
+                // Increment the length in every step in case clone() panics
+                local_len.increment_len(1);
+            }
+            // len set by scope guard
+        }
+        Ok(())
+    }
+
     /// Copies elements from `src` range to the end of the vector.
     ///
     /// # Panics
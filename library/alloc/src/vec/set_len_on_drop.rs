@@ -19,6 +19,7 @@ pub(super) fn increment_len(&mut self, increment: usize) {
         self.local_len += increment;
     }
 
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     pub(super) fn current_len(&self) -> usize {
         self.local_len
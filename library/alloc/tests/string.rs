@@ -792,6 +792,17 @@ fn test_try_reserve() {
     }
 }
 
+#[test]
+fn test_try_push_str() {
+    let mut s = String::new();
+    s.try_push_str("").unwrap();
+    assert_eq!(&s[0..], "");
+    s.try_push_str("abc").unwrap();
+    assert_eq!(&s[0..], "abc");
+    s.try_push_str("ประเทศไทย中华Việt Nam").unwrap();
+    assert_eq!(&s[0..], "abcประเทศไทย中华Việt Nam");
+}
+
 #[test]
 #[cfg_attr(miri, ignore)] // Miri does not support signalling OOM
 #[cfg_attr(target_os = "android", ignore)] // Android used in CI has a broken dlmalloc
@@ -869,3 +880,27 @@ fn test_str_concat() {
     let s: String = format!("{a}{b}");
     assert_eq!(s.as_bytes()[9], 'd' as u8);
 }
+
+#[test]
+fn test_string_in_allocator() {
+    use std::alloc::System;
+
+    let mut s = String::new_in(System);
+    s.push_str("hello");
+    s.push(' ');
+    s += "world";
+    assert_eq!(s, "hello world");
+    assert_eq!(s.len(), 11);
+
+    let world = s.split_off(6);
+    assert_eq!(s, "hello ");
+    assert_eq!(*"world", world);
+    assert_eq!(Cow::Borrowed("world"), world);
+
+    let drained: std::string::String = s.drain(..5).collect();
+    assert_eq!(drained, "hello");
+    assert_eq!(" ", s);
+
+    let bytes: Vec<u8, System> = world.into_bytes();
+    assert_eq!(bytes, b"world");
+}
@@ -1731,6 +1731,41 @@ fn test_try_reserve() {
     }
 }
 
+#[test]
+fn test_try_push_insert_extend() {
+    // Refuses any allocation larger than 16 bytes.
+    struct CappedAllocator;
+
+    unsafe impl Allocator for CappedAllocator {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, core::alloc::AllocError> {
+            if layout.size() > 16 { Err(core::alloc::AllocError) } else { System.allocate(layout) }
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            unsafe { System.deallocate(ptr, layout) }
+        }
+    }
+
+    let mut v = Vec::new_in(CappedAllocator);
+    v.try_push(1u32).unwrap();
+    v.try_insert(0, 0).unwrap();
+    v.try_extend_from_slice(&[2, 3]).unwrap();
+    assert_eq!(v, [0, 1, 2, 3]);
+    assert_eq!(v.capacity(), 4);
+
+    assert_matches!(v.try_push(4).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_matches!(v.try_insert(1, 4).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_matches!(v.try_extend_from_slice(&[4]).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_eq!(v, [0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+fn test_try_insert_out_of_bounds() {
+    let mut v = vec![1];
+    let _ = v.try_insert(2, 2);
+}
+
 #[test]
 #[cfg_attr(miri, ignore)] // Miri does not support signalling OOM
 #[cfg_attr(target_os = "android", ignore)] // Android used in CI has a broken dlmalloc
@@ -1278,6 +1278,17 @@ fn test_try_reserve() {
     }
 }
 
+#[test]
+fn test_try_push_back() {
+    let mut d = VecDeque::with_capacity(4);
+    d.push_front(2);
+    d.push_front(1);
+    for i in 3..=10 {
+        d.try_push_back(i).unwrap();
+    }
+    assert_eq!(d, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+}
+
 #[test]
 #[cfg_attr(miri, ignore)] // Miri does not support signalling OOM
 #[cfg_attr(target_os = "android", ignore)] // Android used in CI has a broken dlmalloc
@@ -851,6 +851,34 @@ fn test_try_reserve() {
     }
 }
 
+#[test]
+fn test_try_insert_alloc() {
+    let mut m = HashMap::new();
+    for i in 0..100 {
+        assert_eq!(m.try_insert_alloc(i, i * 2), Ok(None));
+    }
+    assert_eq!(m.len(), 100);
+    assert_eq!(m.try_insert_alloc(7, 0), Ok(Some(14)));
+    assert_eq!(m.len(), 100);
+    assert_eq!(m[&7], 0);
+    assert_eq!(m[&99], 198);
+}
+
+#[test]
+fn test_new_in() {
+    let mut m = HashMap::new_in(crate::alloc::System);
+    for i in 0..100 {
+        assert_eq!(m.insert(i, i * 2), None);
+    }
+    assert_eq!(m.len(), 100);
+    assert_eq!(m[&7], 14);
+    assert_eq!(m.remove(&7), Some(14));
+    assert!(m.capacity() >= 99);
+
+    let values: Vec<_> = m.into_values().collect();
+    assert_eq!(values.len(), 99);
+}
+
 #[test]
 fn test_raw_entry() {
     use super::RawEntryMut::{Occupied, Vacant};
@@ -524,3 +524,18 @@ fn test_insert_does_not_overwrite_the_value() {
             must be the same as first value pointer we inserted"
     );
 }
+
+#[test]
+fn test_new_in() {
+    let mut a = HashSet::new_in(crate::alloc::System);
+    let mut b = HashSet::with_capacity_in(8, crate::alloc::System);
+    for i in 0..10 {
+        assert!(a.insert(i));
+        assert!(b.insert(i * 2));
+    }
+    let mut common: Vec<_> = a.intersection(&b).copied().collect();
+    common.sort();
+    assert_eq!(common, [0, 2, 4, 6, 8]);
+    assert!(a.remove(&3));
+    assert_eq!(a.len(), 9);
+}
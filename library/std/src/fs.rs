@@ -10,6 +10,7 @@
 
 #[cfg(all(test, not(any(target_os = "emscripten", target_env = "sgx"))))]
 mod tests;
+mod walk;
 
 use crate::ffi::OsString;
 use crate::fmt;
@@ -21,6 +22,9 @@
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 use crate::time::SystemTime;
 
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub use self::walk::{walk_dir, WalkDir, WalkDirEntry};
+
 /// An object providing access to an open file on the filesystem.
 ///
 /// An instance of a `File` can be read and/or written depending on what options
@@ -702,6 +706,248 @@ pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
     pub fn set_modified(&self, time: SystemTime) -> io::Result<()> {
         self.set_times(FileTimes::new().set_modified(time))
     }
+
+    /// Copies up to `len` bytes starting at `offset` in this file to `dest_offset` in `dest`.
+    ///
+    /// Returns the number of bytes copied, which is less than `len` if the end of this file is
+    /// reached first. Unlike [`io::copy`], this copies a range of bytes without using or
+    /// changing the cursors of the two files, and `dest` is extended as needed.
+    ///
+    /// On Linux and Android, this uses `copy_file_range(2)`, which lets the kernel copy the data
+    /// without bringing it into user space, and lets filesystems which support it share the
+    /// underlying storage between both files, such as reflinks on Btrfs or XFS. If it is not
+    /// available, or the files are on different filesystems, the data is copied through a buffer
+    /// with positional reads and writes, which is what other platforms always do.
+    ///
+    /// If `self` and `dest` refer to the same file and the two ranges overlap, the contents of
+    /// the destination range are unspecified.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Windows, the cursors of both files may be moved, as with
+    /// [`FileExt::seek_read`](crate::os::windows::fs::FileExt::seek_read).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if this file isn't opened for reading, or `dest` isn't
+    /// opened for writing. `dest` must also not be opened in append mode on Linux and Android.
+    /// If an error is returned, an unknown amount of data may already have been copied.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_copy_range)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let segment = File::open("segment.log")?;
+    ///     let compacted = File::options().write(true).open("compacted.log")?;
+    ///     // Move the live records at the end of the segment to the start of the new file.
+    ///     let copied = segment.copy_range_to(4096, 1 << 20, &compacted, 0)?;
+    ///     println!("copied {copied} bytes");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_copy_range", issue = "none")]
+    #[doc(alias = "copy_file_range")]
+    pub fn copy_range_to(
+        &self,
+        offset: u64,
+        len: u64,
+        dest: &File,
+        dest_offset: u64,
+    ) -> io::Result<u64> {
+        self.inner.copy_range_to(offset, len, &dest.inner, dest_offset)
+    }
+
+    /// Acquires an exclusive lock on the file, blocking until it can be acquired.
+    ///
+    /// While a file is locked exclusively, no other handle can acquire a shared or an exclusive
+    /// lock on it. The lock is released by [`unlock`], or when the file and all its clones are
+    /// closed.
+    ///
+    /// Locks are advisory: they only prevent other locks from being acquired, not reads or
+    /// writes through handles which don't take them. They are associated with the open file
+    /// description on Unix and with the file handle on Windows, so two handles opened
+    /// separately for the same file exclude each other even within a single process, while
+    /// clones obtained from [`try_clone`] share the lock. If this handle, or a clone of it,
+    /// already holds a lock, the behavior is platform-specific and may deadlock.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_EX`
+    /// flag, and the `LockFileEx` function on Windows with the `LOCKFILE_EXCLUSIVE_LOCK` flag.
+    /// Note that, this [may change in the future][changes].
+    ///
+    /// On Unix platforms without `flock`, this function returns an error of kind
+    /// [`io::ErrorKind::Unsupported`].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`try_clone`]: File::try_clone
+    /// [`unlock`]: File::unlock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("cache.lock")?;
+    ///     f.lock()?;
+    ///     // Only this process touches the cache until the lock is released.
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "LockFileEx")]
+    pub fn lock(&self) -> io::Result<()> {
+        self.inner.lock()
+    }
+
+    /// Acquires a shared lock on the file, blocking until it can be acquired.
+    ///
+    /// Any number of handles can hold a shared lock on the same file, but no handle can hold an
+    /// exclusive lock on it at the same time. See [`lock`] for the semantics of file locks.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_SH`
+    /// flag, and the `LockFileEx` function on Windows. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("cache.lock")?;
+    ///     f.lock_shared()?;
+    ///     // Other readers may hold the lock too, but no writer.
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "LockFileEx")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Tries to acquire an exclusive lock on the file, without blocking.
+    ///
+    /// Returns `Ok(false)` if another handle holds a lock on the file, and `Ok(true)` once the
+    /// lock is acquired. See [`lock`] for the semantics of file locks.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_EX`
+    /// and `LOCK_NB` flags, and the `LockFileEx` function on Windows with the
+    /// `LOCKFILE_EXCLUSIVE_LOCK` and `LOCKFILE_FAIL_IMMEDIATELY` flags. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("build.lock")?;
+    ///     if !f.try_lock()? {
+    ///         eprintln!("Blocking waiting for file lock on build directory");
+    ///         f.lock()?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "LockFileEx")]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.inner.try_lock()
+    }
+
+    /// Tries to acquire a shared lock on the file, without blocking.
+    ///
+    /// Returns `Ok(false)` if another handle holds an exclusive lock on the file, and `Ok(true)`
+    /// once the lock is acquired. See [`lock`] for the semantics of file locks.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_SH`
+    /// and `LOCK_NB` flags, and the `LockFileEx` function on Windows with the
+    /// `LOCKFILE_FAIL_IMMEDIATELY` flag. Note that, this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("cache.lock")?;
+    ///     if f.try_lock_shared()? {
+    ///         // read from the cache
+    ///         f.unlock()?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "LockFileEx")]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Releases the lock held on the file.
+    ///
+    /// Closing the file and all its clones also releases the lock.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix with the `LOCK_UN`
+    /// flag, and the `UnlockFile` function on Windows. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// On Windows, this returns an error if the file isn't locked.
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("cache.lock")?;
+    ///     f.lock()?;
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    #[doc(alias = "flock")]
+    #[doc(alias = "UnlockFile")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
 }
 
 // In addition to the `impl`s here, `File` also has `impl`s for
@@ -637,6 +637,83 @@ fn recursive_rmdir_of_file_fails() {
     assert!(canary.exists());
 }
 
+#[test]
+fn walk_dir_sorted() {
+    let tmpdir = tmpdir();
+    let root = tmpdir.join("walk");
+    check!(fs::create_dir_all(root.join("a").join("b")));
+    check!(fs::create_dir_all(root.join("c")));
+    check!(File::create(root.join("a").join("b").join("f")));
+    check!(File::create(root.join("a").join("e")));
+    check!(File::create(root.join("d")));
+
+    let walk = |walk_dir: fs::WalkDir| -> Vec<(usize, String)> {
+        walk_dir
+            .map(|entry| {
+                let entry = check!(entry);
+                let path = entry.path().strip_prefix(&root).unwrap();
+                (entry.depth(), path.to_str().unwrap().replace('\\', "/"))
+            })
+            .collect()
+    };
+    let entries = |e: &[(usize, &str)]| -> Vec<(usize, String)> {
+        e.iter().map(|&(depth, path)| (depth, path.to_owned())).collect()
+    };
+
+    assert_eq!(
+        walk(fs::walk_dir(&root).sort_by_file_name()),
+        entries(&[(0, ""), (1, "a"), (2, "a/b"), (3, "a/b/f"), (2, "a/e"), (1, "c"), (1, "d")])
+    );
+    assert_eq!(
+        walk(fs::walk_dir(&root).sort_by_file_name().min_depth(1).max_depth(2)),
+        entries(&[(1, "a"), (2, "a/b"), (2, "a/e"), (1, "c"), (1, "d")])
+    );
+    assert_eq!(walk(fs::walk_dir(&root).min_depth(3)), entries(&[(3, "a/b/f")]));
+
+    let mut walk_dir = fs::walk_dir(tmpdir.join("does_not_exist"));
+    assert_eq!(walk_dir.next().unwrap().unwrap_err().kind(), ErrorKind::NotFound);
+    assert!(walk_dir.next().is_none());
+}
+
+#[test]
+fn walk_dir_symlinks() {
+    let tmpdir = tmpdir();
+    if !got_symlink_permission(&tmpdir) {
+        return;
+    };
+    let root = tmpdir.join("walk");
+    let dir = root.join("dir");
+    check!(fs::create_dir_all(&dir));
+    check!(File::create(dir.join("f")));
+    check!(symlink_dir(&dir, root.join("link")));
+    check!(symlink_dir(&root, dir.join("loop")));
+
+    // Without following links, the links are returned but not walked into.
+    let entries: Vec<_> = fs::walk_dir(&root).min_depth(1).sort_by_file_name().collect();
+    assert_eq!(entries.len(), 4);
+    let link = check!(entries[3].as_ref());
+    assert_eq!(link.file_name(), "link");
+    assert!(link.path_is_symlink());
+    assert!(link.file_type().is_symlink());
+
+    // When following them, the loop leading back to the root is detected, but
+    // the walk goes on.
+    let entries: Vec<_> =
+        fs::walk_dir(&root).min_depth(1).sort_by_file_name().follow_links(true).collect();
+    let loops = entries
+        .iter()
+        .filter(|e| e.as_ref().is_err_and(|e| e.kind() == ErrorKind::FilesystemLoop))
+        .count();
+    assert_eq!(loops, 2);
+    let files: Vec<_> = entries
+        .iter()
+        .filter_map(|e| e.as_ref().ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.path().to_owned())
+        .collect();
+    assert_eq!(files, [dir.join("f"), root.join("link").join("f")]);
+}
+
 #[test]
 // only Windows makes a distinction between file and directory symlinks.
 #[cfg(windows)]
@@ -862,6 +939,90 @@ fn copy_file_returns_metadata_len() {
     assert_eq!(check!(out_path.metadata()).len(), copied_len);
 }
 
+#[test]
+fn copy_range_to() {
+    let tmpdir = tmpdir();
+    let input = tmpdir.join("in.txt");
+    let out = tmpdir.join("out.txt");
+
+    let mut src = check!(OpenOptions::new().read(true).write(true).create(true).open(&input));
+    check!(src.write(b"0123456789"));
+    let dst = check!(OpenOptions::new().read(true).write(true).create(true).open(&out));
+
+    assert_eq!(check!(src.copy_range_to(2, 5, &dst, 3)), 5);
+    let mut v = Vec::new();
+    check!(check!(File::open(&out)).read_to_end(&mut v));
+    assert_eq!(v, b"\0\0\023456");
+
+    // The copy stops at the end of the source file, and extends the destination file.
+    assert_eq!(check!(src.copy_range_to(7, 100, &dst, 8)), 3);
+    let mut v = Vec::new();
+    check!(check!(File::open(&out)).read_to_end(&mut v));
+    assert_eq!(v, b"\0\0\023456789");
+    assert_eq!(check!(src.copy_range_to(10, 100, &dst, 0)), 0);
+
+    // The cursors of both files are left untouched.
+    #[cfg(not(windows))]
+    {
+        assert_eq!(check!(src.stream_position()), 10);
+        assert_eq!(check!((&dst).stream_position()), 0);
+    }
+}
+
+#[test]
+fn copy_range_to_needs_readable_source() {
+    let tmpdir = tmpdir();
+    let input = tmpdir.join("in.txt");
+    let out = tmpdir.join("out.txt");
+
+    let src = check!(File::create(&input));
+    check!((&src).write(b"hello"));
+    let dst = check!(File::create(&out));
+    assert!(src.copy_range_to(0, 5, &dst, 0).is_err());
+}
+
+#[test]
+#[cfg(any(windows, target_os = "freebsd", target_os = "linux", target_os = "macos"))]
+fn file_lock_multiple_handles() {
+    let tmpdir = tmpdir();
+    let filename = &tmpdir.join("file_lock_multiple_handles.txt");
+    let f1 = check!(File::create(filename));
+    let f2 = check!(OpenOptions::new().write(true).open(filename));
+
+    check!(f1.lock());
+    assert!(!check!(f2.try_lock()));
+    assert!(!check!(f2.try_lock_shared()));
+    check!(f1.unlock());
+    assert!(check!(f2.try_lock()));
+    check!(f2.unlock());
+
+    check!(f1.lock_shared());
+    assert!(check!(f2.try_lock_shared()));
+    let f3 = check!(OpenOptions::new().write(true).open(filename));
+    assert!(!check!(f3.try_lock()));
+    check!(f1.unlock());
+    check!(f2.unlock());
+    assert!(check!(f3.try_lock()));
+    check!(f3.unlock());
+}
+
+#[test]
+#[cfg(any(target_os = "freebsd", target_os = "linux", target_os = "macos"))]
+fn file_lock_released_on_close() {
+    let tmpdir = tmpdir();
+    let filename = &tmpdir.join("file_lock_released_on_close.txt");
+    let f1 = check!(File::create(filename));
+    let f2 = check!(OpenOptions::new().write(true).open(filename));
+
+    check!(f1.lock());
+    let clone = check!(f1.try_clone());
+    drop(f1);
+    // The clone still holds the lock.
+    assert!(!check!(f2.try_lock_shared()));
+    drop(clone);
+    assert!(check!(f2.try_lock()));
+}
+
 #[test]
 fn copy_file_follows_dst_symlink() {
     let tmp = tmpdir();
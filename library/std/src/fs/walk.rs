@@ -0,0 +1,368 @@
+use super::{canonicalize, metadata, read_dir, symlink_metadata};
+use super::{DirEntry, FileType, Metadata, ReadDir};
+use crate::ffi::OsStr;
+use crate::fmt;
+use crate::io;
+use crate::path::{Path, PathBuf};
+use crate::vec;
+
+/// Returns an iterator over the entries within a directory and all of its
+/// subdirectories, recursively.
+///
+/// The directory itself is returned first, at a depth of 0, followed by its
+/// entries at a depth of 1, each directory being immediately followed by its
+/// own entries. The iterator can be configured before iterating over it,
+/// e.g. to limit the depth of the walk or to follow symbolic links. See
+/// [`WalkDir`] for the available options.
+///
+/// By default, the order in which entries within a directory are returned is
+/// platform and filesystem dependent, as with [`read_dir`]. Use
+/// [`WalkDir::sort_by_file_name`] to get a deterministic order.
+///
+/// # Errors
+///
+/// Errors are returned by the iterator rather than by this function, and do
+/// not end the walk: if a directory cannot be read, an [`Err`] is returned in
+/// place of its entries, and the walk carries on with the next entry. This
+/// includes the directory given here, which is why it is read lazily on the
+/// first call to [`next`](Iterator::next).
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_walk_dir)]
+/// use std::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     for entry in fs::walk_dir("src").max_depth(2).sort_by_file_name() {
+///         let entry = entry?;
+///         if entry.file_type().is_file() {
+///             println!("{}", entry.path().display());
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub fn walk_dir<P: AsRef<Path>>(path: P) -> WalkDir {
+    WalkDir {
+        root: Some(path.as_ref().to_path_buf()),
+        pending: None,
+        stack: Vec::new(),
+        min_depth: 0,
+        max_depth: usize::MAX,
+        follow_links: false,
+        sort_by_file_name: false,
+    }
+}
+
+/// Iterator over the entries within a directory tree.
+///
+/// This struct is created by the [`walk_dir`] function. The methods on it
+/// configure the walk; they should be called before iterating over it.
+///
+/// # Errors
+///
+/// This [`io::Result`] will be an [`Err`] if a directory cannot be read, if
+/// an error occurs while iterating over the entries of a directory, or if a
+/// symbolic link loop is found while following symbolic links. The walk
+/// continues after an error.
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+#[derive(Debug)]
+pub struct WalkDir {
+    /// The directory to walk, until it has been returned.
+    root: Option<PathBuf>,
+    /// The directory visited last, if its entries are to be walked. Reading
+    /// it is deferred to the next call to `next`, so that an error doing so
+    /// is returned after the entry for the directory itself.
+    pending: Option<PathBuf>,
+    /// The directories being read, from the root down to the current one.
+    stack: Vec<Ancestor>,
+    min_depth: usize,
+    max_depth: usize,
+    follow_links: bool,
+    sort_by_file_name: bool,
+}
+
+#[derive(Debug)]
+struct Ancestor {
+    entries: Entries,
+    /// The canonical path of the directory, to detect symbolic link loops.
+    /// This is only needed, and only set, when following symbolic links.
+    canonical_path: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+enum Entries {
+    Unsorted(ReadDir),
+    Sorted(vec::IntoIter<io::Result<DirEntry>>),
+}
+
+impl Iterator for Entries {
+    type Item = io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<io::Result<DirEntry>> {
+        match self {
+            Entries::Unsorted(read_dir) => read_dir.next(),
+            Entries::Sorted(entries) => entries.next(),
+        }
+    }
+}
+
+impl WalkDir {
+    /// Sets the minimum depth of the entries to return.
+    ///
+    /// The directory given to [`walk_dir`] is at a depth of 0, and its
+    /// entries are at a depth of 1. Entries at a lower depth are still walked
+    /// through, but not returned. For example, `min_depth(1)` skips the
+    /// directory being walked. The default is 0.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn min_depth(mut self, depth: usize) -> WalkDir {
+        self.min_depth = depth;
+        self
+    }
+
+    /// Sets the maximum depth of the entries to return.
+    ///
+    /// Directories at this depth are returned, but their entries are not
+    /// read. For example, `max_depth(1)` only returns the directory being
+    /// walked and its entries, like [`read_dir`]. There is no limit by
+    /// default.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn max_depth(mut self, depth: usize) -> WalkDir {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Sets whether to follow symbolic links.
+    ///
+    /// When following symbolic links, a link to a directory is walked into,
+    /// and the entries returned for links describe their targets. A link
+    /// that would lead back to one of the directories currently being walked
+    /// is reported as an error of the kind [`FilesystemLoop`] rather than
+    /// walked into. Links whose target doesn't exist are returned as links.
+    ///
+    /// Symbolic links are not followed by default, except for the directory
+    /// given to [`walk_dir`] itself, which is always followed.
+    ///
+    /// [`FilesystemLoop`]: io::ErrorKind::FilesystemLoop
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn follow_links(mut self, follow_links: bool) -> WalkDir {
+        self.follow_links = follow_links;
+        self
+    }
+
+    /// Returns the entries of every directory sorted by their file name.
+    ///
+    /// This reads each directory completely before returning its first
+    /// entry, rather than streaming it as the walk goes.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn sort_by_file_name(mut self) -> WalkDir {
+        self.sort_by_file_name = true;
+        self
+    }
+
+    /// Starts reading the entries of the directory `dir`.
+    fn descend(&mut self, dir: PathBuf) -> io::Result<()> {
+        let canonical_path = if self.follow_links {
+            let canonical_path = canonicalize(&dir)?;
+            if self.stack.iter().any(|a| a.canonical_path.as_ref() == Some(&canonical_path)) {
+                return Err(io::Error::new(
+                    io::ErrorKind::FilesystemLoop,
+                    format!("symbolic link loop at {}", dir.display()),
+                ));
+            }
+            Some(canonical_path)
+        } else {
+            None
+        };
+
+        let read_dir = read_dir(&dir)?;
+        let entries = if self.sort_by_file_name {
+            let mut entries: Vec<_> = read_dir.collect();
+            // Errors don't have a file name, so they come first, in their
+            // original order.
+            entries.sort_by_cached_key(|entry| entry.as_ref().ok().map(DirEntry::file_name));
+            Entries::Sorted(entries.into_iter())
+        } else {
+            Entries::Unsorted(read_dir)
+        };
+        self.stack.push(Ancestor { entries, canonical_path });
+        Ok(())
+    }
+
+    fn entry_from_dir_entry(&self, dir_entry: DirEntry) -> io::Result<WalkDirEntry> {
+        let path = dir_entry.path();
+        let depth = self.stack.len();
+        let file_type = dir_entry.file_type()?;
+        if file_type.is_symlink() && self.follow_links {
+            // A broken link is returned as the link itself.
+            if let Ok(target) = metadata(&path) {
+                let file_type = target.file_type();
+                return Ok(WalkDirEntry {
+                    path,
+                    depth,
+                    file_type,
+                    is_symlink: true,
+                    followed: true,
+                });
+            }
+        }
+        Ok(WalkDirEntry {
+            path,
+            depth,
+            file_type,
+            is_symlink: file_type.is_symlink(),
+            followed: false,
+        })
+    }
+
+    fn root_entry(root: PathBuf) -> io::Result<WalkDirEntry> {
+        let file_type = metadata(&root)?.file_type();
+        let is_symlink = symlink_metadata(&root)?.file_type().is_symlink();
+        Ok(WalkDirEntry { path: root, depth: 0, file_type, is_symlink, followed: true })
+    }
+
+    /// Returns `entry` if it is deep enough to be returned, and arranges for
+    /// its entries to be walked if it is a directory.
+    fn visit(&mut self, entry: WalkDirEntry) -> Option<WalkDirEntry> {
+        if entry.file_type.is_dir() && entry.depth < self.max_depth {
+            self.pending = Some(entry.path.clone());
+        }
+        (entry.depth >= self.min_depth).then_some(entry)
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl Iterator for WalkDir {
+    type Item = io::Result<WalkDirEntry>;
+
+    fn next(&mut self) -> Option<io::Result<WalkDirEntry>> {
+        if let Some(root) = self.root.take() {
+            match WalkDir::root_entry(root) {
+                Ok(entry) => {
+                    if let Some(entry) = self.visit(entry) {
+                        return Some(Ok(entry));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        loop {
+            if let Some(dir) = self.pending.take() {
+                if let Err(e) = self.descend(dir) {
+                    return Some(Err(e));
+                }
+            }
+
+            let ancestor = self.stack.last_mut()?;
+            let dir_entry = match ancestor.entries.next() {
+                Some(Ok(dir_entry)) => dir_entry,
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            let entry = match self.entry_from_dir_entry(dir_entry) {
+                Ok(entry) => entry,
+                Err(e) => return Some(Err(e)),
+            };
+            if let Some(entry) = self.visit(entry) {
+                return Some(Ok(entry));
+            }
+        }
+    }
+}
+
+/// An entry returned by the [`WalkDir`] iterator.
+///
+/// Unlike a [`DirEntry`], a `WalkDirEntry` doesn't keep the directory it was
+/// read from open.
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub struct WalkDirEntry {
+    path: PathBuf,
+    depth: usize,
+    /// The type of the file, or of the target of the link when following it.
+    file_type: FileType,
+    is_symlink: bool,
+    /// Whether the entry is a link that was followed.
+    followed: bool,
+}
+
+impl WalkDirEntry {
+    /// Returns the full path to the file that this entry represents.
+    ///
+    /// The path is created by joining the path given to [`walk_dir`] with
+    /// the names of the directories leading to this entry, and its file name.
+    #[must_use]
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Converts this entry into its full path.
+    #[must_use]
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn into_path(self) -> PathBuf {
+        self.path
+    }
+
+    /// Returns the file name of this entry.
+    ///
+    /// For the directory given to [`walk_dir`], this is the whole path if it
+    /// doesn't end in a file name, e.g. for `.` or `/`.
+    #[must_use]
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn file_name(&self) -> &OsStr {
+        self.path.file_name().unwrap_or(self.path.as_os_str())
+    }
+
+    /// Returns the depth of this entry within the walked directory tree. The
+    /// directory given to [`walk_dir`] is at a depth of 0.
+    #[must_use]
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the file type of this entry.
+    ///
+    /// If this entry is a symbolic link that was followed, this is the type
+    /// of its target; see [`WalkDir::follow_links`].
+    #[must_use]
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn file_type(&self) -> FileType {
+        self.file_type
+    }
+
+    /// Returns `true` if this entry is a symbolic link, whether it was
+    /// followed or not.
+    #[must_use]
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn path_is_symlink(&self) -> bool {
+        self.is_symlink
+    }
+
+    /// Queries the metadata of the file that this entry represents.
+    ///
+    /// Like [`file_type`](WalkDirEntry::file_type), this describes the
+    /// target of a symbolic link that was followed, and the link itself
+    /// otherwise.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn metadata(&self) -> io::Result<Metadata> {
+        if self.followed {
+            metadata(&self.path)
+        } else {
+            symlink_metadata(&self.path)
+        }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl fmt::Debug for WalkDirEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WalkDirEntry").field(&self.path).finish()
+    }
+}
@@ -8,7 +8,7 @@
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
 use crate::iter::FusedIterator;
-use crate::net::{Shutdown, SocketAddr, ToSocketAddrs};
+use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 use crate::time::Duration;
@@ -110,6 +110,46 @@ pub struct IntoIncoming {
     listener: TcpListener,
 }
 
+/// A TCP socket that has not been turned into a listener or a stream yet.
+///
+/// A `TcpSocket` allows setting socket options which only take effect before
+/// the socket is bound or connected, such as [`set_reuseaddr`] or
+/// [`set_only_v6`], which can't be set through [`TcpListener::bind`] or
+/// [`TcpStream::connect`]. Once configured, the socket is turned into a
+/// [`TcpListener`] with [`listen`], or into a [`TcpStream`] with [`connect`].
+///
+/// The socket will be closed when the value is dropped.
+///
+/// [`connect`]: TcpSocket::connect
+/// [`listen`]: TcpSocket::listen
+/// [`set_only_v6`]: TcpSocket::set_only_v6
+/// [`set_reuseaddr`]: TcpSocket::set_reuseaddr
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(tcp_socket)]
+///
+/// use std::net::TcpSocket;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = TcpSocket::new_v6()?;
+///     socket.set_reuseaddr(true)?;
+///     // Accept IPv4 connections too, as IPv4-mapped IPv6 addresses.
+///     socket.set_only_v6(false)?;
+///     socket.bind(&"[::]:8080".parse().unwrap())?;
+///     let listener = socket.listen(1024)?;
+///
+///     for stream in listener.incoming() {
+///         let _stream = stream?;
+///         // ...
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "tcp_socket", issue = "none")]
+pub struct TcpSocket(net_imp::TcpSocket);
+
 impl TcpStream {
     /// Opens a TCP connection to a remote host.
     ///
@@ -503,6 +543,134 @@ pub fn nodelay(&self) -> io::Result<bool> {
         self.0.nodelay()
     }
 
+    /// Sets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// If set, the system periodically sends probes on an otherwise idle connection, and
+    /// closes the connection if the peer stops answering them. The time before the first
+    /// probe, the interval between probes and the number of unanswered probes are system
+    /// defaults, which on Linux can be changed with `std::os::linux::net::TcpStreamExt`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive)]
+    ///
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive(true).expect("set_keepalive call failed");
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    /// Gets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_keepalive`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive)]
+    ///
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive(true).expect("set_keepalive call failed");
+    /// assert_eq!(stream.keepalive().unwrap_or(false), true);
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.0.keepalive()
+    }
+
+    /// Sets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// This is the size of the buffer the system reserves for outgoing data waiting to be sent.
+    /// The system may round the size, and clamps it to a system-wide range. On Linux, the
+    /// reserved size is double the requested one, to leave room for bookkeeping overhead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_buffer_size)]
+    ///
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_send_buffer_size(64 * 1024).expect("set_send_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_send_buffer_size`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_buffer_size)]
+    ///
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// println!("send buffer size: {}", stream.send_buffer_size().unwrap());
+    /// ```
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// This is the size of the buffer the system reserves for incoming data waiting to be read.
+    /// The system may round the size, and clamps it to a system-wide range. On Linux, the
+    /// reserved size is double the requested one, to leave room for bookkeeping overhead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_buffer_size)]
+    ///
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// stream.set_recv_buffer_size(64 * 1024).expect("set_recv_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_recv_buffer_size`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_buffer_size)]
+    ///
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///                        .expect("Couldn't connect to the server...");
+    /// println!("recv buffer size: {}", stream.recv_buffer_size().unwrap());
+    /// ```
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
     /// Sets the value for the `IP_TTL` option on this socket.
     ///
     /// This value sets the time-to-live field that is used in every packet sent
@@ -1067,3 +1235,463 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+impl TcpSocket {
+    /// Creates a new IPv4 TCP socket.
+    ///
+    /// The socket is neither bound nor connected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn new_v4() -> io::Result<TcpSocket> {
+        net_imp::TcpSocket::new(&SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0)).map(TcpSocket)
+    }
+
+    /// Creates a new IPv6 TCP socket.
+    ///
+    /// The socket is neither bound nor connected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v6().expect("couldn't create a socket");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn new_v6() -> io::Result<TcpSocket> {
+        net_imp::TcpSocket::new(&SocketAddr::new(Ipv6Addr::UNSPECIFIED.into(), 0)).map(TcpSocket)
+    }
+
+    /// Binds this socket to the specified address.
+    ///
+    /// The address must be of the same family as the socket. Binding to port 0 requests the
+    /// system to assign a port, which can be queried with [`TcpSocket::local_addr`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.bind(&"127.0.0.1:0".parse().unwrap()).expect("couldn't bind to address");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn bind(&self, addr: &SocketAddr) -> io::Result<()> {
+        self.0.bind(addr)
+    }
+
+    /// Returns the socket address that this socket is bound to.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.bind(&"127.0.0.1:0".parse().unwrap()).expect("couldn't bind to address");
+    /// let addr = socket.local_addr().expect("couldn't get local address");
+    /// assert_eq!(addr.ip(), Ipv4Addr::LOCALHOST);
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.0.socket_addr()
+    }
+
+    /// Turns this socket into a [`TcpListener`], listening for incoming connections.
+    ///
+    /// The `backlog` is the maximum number of connections which may wait to be accepted. The
+    /// system may round it, and clamps it to a system-wide maximum. If the socket is not bound
+    /// yet, the system binds it to a random port.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.bind(&"127.0.0.1:8080".parse().unwrap()).expect("couldn't bind to address");
+    /// let listener = socket.listen(1024).expect("listen call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn listen(self, backlog: u32) -> io::Result<TcpListener> {
+        self.0.listen(backlog).map(TcpListener)
+    }
+
+    /// Turns this socket into a [`TcpStream`], by opening a connection to a remote host.
+    ///
+    /// The address must be of the same family as the socket. If the socket is not bound yet,
+    /// the system binds it to a random port.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// let stream = socket.connect(&"127.0.0.1:8080".parse().unwrap())
+    ///                    .expect("Couldn't connect to the server...");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn connect(self, addr: &SocketAddr) -> io::Result<TcpStream> {
+        self.0.connect(addr).map(TcpStream)
+    }
+
+    /// Sets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// On platforms with Berkeley-derived sockets, this allows binding to an address whose
+    /// previous socket is still in the `TIME_WAIT` state, which is what [`TcpListener::bind`]
+    /// does by default. On Windows, this allows binding to an address that is actively in use,
+    /// which allows other processes to hijack connections, so it should not be set there.
+    ///
+    /// This option must be set before the socket is bound.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_reuseaddr(true).expect("set_reuseaddr call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        self.0.set_reuseaddr(reuseaddr)
+    }
+
+    /// Gets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_reuseaddr`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_reuseaddr(true).expect("set_reuseaddr call failed");
+    /// assert_eq!(socket.reuseaddr().unwrap_or(false), true);
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        self.0.reuseaddr()
+    }
+
+    /// Sets the value of the `IPV6_V6ONLY` option on this socket.
+    ///
+    /// If this is set to `true`, the socket only communicates over IPv6. If it is set to
+    /// `false`, the socket can also communicate with IPv4 peers, as IPv4-mapped IPv6 addresses.
+    /// The default value depends on the system. This option must be set before the socket is
+    /// bound, and can only be set on IPv6 sockets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v6().expect("couldn't create a socket");
+    /// socket.set_only_v6(true).expect("set_only_v6 call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_only_v6(&self, only_v6: bool) -> io::Result<()> {
+        self.0.set_only_v6(only_v6)
+    }
+
+    /// Gets the value of the `IPV6_V6ONLY` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_only_v6`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v6().expect("couldn't create a socket");
+    /// socket.set_only_v6(true).expect("set_only_v6 call failed");
+    /// assert_eq!(socket.only_v6().unwrap_or(false), true);
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn only_v6(&self) -> io::Result<bool> {
+        self.0.only_v6()
+    }
+
+    /// Sets the value of the `SO_LINGER` option on this socket.
+    ///
+    /// See [`TcpStream::set_linger`] for more information about this option. It is kept by the
+    /// stream this socket turns into.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    /// use std::time::Duration;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_linger(Some(Duration::from_secs(0))).expect("set_linger call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_linger(&self, linger: Option<Duration>) -> io::Result<()> {
+        self.0.set_linger(linger)
+    }
+
+    /// Gets the value of the `SO_LINGER` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_linger`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    /// use std::time::Duration;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_linger(Some(Duration::from_secs(0))).expect("set_linger call failed");
+    /// assert_eq!(socket.linger().unwrap(), Some(Duration::from_secs(0)));
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        self.0.linger()
+    }
+
+    /// Sets the value of the `TCP_NODELAY` option on this socket.
+    ///
+    /// See [`TcpStream::set_nodelay`] for more information about this option. It is kept by
+    /// the stream this socket turns into, and on most systems by the streams accepted by the
+    /// listener this socket turns into.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_nodelay(true).expect("set_nodelay call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.0.set_nodelay(nodelay)
+    }
+
+    /// Gets the value of the `TCP_NODELAY` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_nodelay`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_nodelay(true).expect("set_nodelay call failed");
+    /// assert_eq!(socket.nodelay().unwrap_or(false), true);
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn nodelay(&self) -> io::Result<bool> {
+        self.0.nodelay()
+    }
+
+    /// Sets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// See [`TcpStream::set_keepalive`] for more information about this option. Like
+    /// [`set_nodelay`](TcpSocket::set_nodelay), it is kept by the streams this socket turns
+    /// into or accepts.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_keepalive(true).expect("set_keepalive call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    /// Gets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_keepalive`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_keepalive(true).expect("set_keepalive call failed");
+    /// assert_eq!(socket.keepalive().unwrap_or(false), true);
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.0.keepalive()
+    }
+
+    /// Sets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// This is the size of the buffer the system reserves for outgoing data waiting to be sent.
+    /// The system may round the size, and clamps it to a system-wide range. On Linux, the
+    /// reserved size is double the requested one, to leave room for bookkeeping overhead.
+    ///
+    /// Setting the receive buffer size before connecting or listening allows the TCP window
+    /// scale to be chosen accordingly.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_send_buffer_size(64 * 1024).expect("set_send_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_send_buffer_size`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// println!("send buffer size: {}", socket.send_buffer_size().unwrap());
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// This is the size of the buffer the system reserves for incoming data waiting to be read.
+    /// The system may round the size, and clamps it to a system-wide range. On Linux, the
+    /// reserved size is double the requested one, to leave room for bookkeeping overhead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_recv_buffer_size(64 * 1024).expect("set_recv_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_recv_buffer_size`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// println!("recv buffer size: {}", socket.recv_buffer_size().unwrap());
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Gets the value of the `SO_ERROR` option on this socket.
+    ///
+    /// This will retrieve the stored error in the underlying socket, clearing the field in the
+    /// process.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.take_error().expect("No error was expected...");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+}
+
+impl AsInner<net_imp::TcpSocket> for TcpSocket {
+    #[inline]
+    fn as_inner(&self) -> &net_imp::TcpSocket {
+        &self.0
+    }
+}
+
+impl FromInner<net_imp::TcpSocket> for TcpSocket {
+    fn from_inner(inner: net_imp::TcpSocket) -> TcpSocket {
+        TcpSocket(inner)
+    }
+}
+
+impl IntoInner<net_imp::TcpSocket> for TcpSocket {
+    fn into_inner(self) -> net_imp::TcpSocket {
+        self.0
+    }
+}
+
+#[unstable(feature = "tcp_socket", issue = "none")]
+impl fmt::Debug for TcpSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
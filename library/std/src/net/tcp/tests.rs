@@ -836,6 +836,36 @@ fn nodelay() {
     assert_eq!(false, t!(stream.nodelay()));
 }
 
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn keepalive() {
+    let addr = next_test_ip4();
+    let _listener = t!(TcpListener::bind(&addr));
+
+    let stream = t!(TcpStream::connect(&("localhost", addr.port())));
+
+    assert_eq!(false, t!(stream.keepalive()));
+    t!(stream.set_keepalive(true));
+    assert_eq!(true, t!(stream.keepalive()));
+    t!(stream.set_keepalive(false));
+    assert_eq!(false, t!(stream.keepalive()));
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn buffer_sizes() {
+    let addr = next_test_ip4();
+    let _listener = t!(TcpListener::bind(&addr));
+
+    let stream = t!(TcpStream::connect(&("localhost", addr.port())));
+
+    // The system may round the sizes, or double them to account for bookkeeping overhead.
+    t!(stream.set_send_buffer_size(64 * 1024));
+    assert!(t!(stream.send_buffer_size()) >= 64 * 1024);
+    t!(stream.set_recv_buffer_size(64 * 1024));
+    assert!(t!(stream.recv_buffer_size()) >= 64 * 1024);
+}
+
 #[test]
 #[cfg_attr(target_env = "sgx", ignore)]
 fn ttl() {
@@ -914,3 +944,45 @@ fn connect_timeout_valid() {
     let addr = listener.local_addr().unwrap();
     TcpStream::connect_timeout(&addr, Duration::from_secs(2)).unwrap();
 }
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn socket_listen_connect() {
+    each_ip(&mut |addr| {
+        let socket = t!(if addr.is_ipv4() { TcpSocket::new_v4() } else { TcpSocket::new_v6() });
+        t!(socket.set_reuseaddr(true));
+        assert_eq!(true, t!(socket.reuseaddr()));
+        t!(socket.set_nodelay(true));
+        t!(socket.bind(&addr));
+        assert_eq!(addr, t!(socket.local_addr()));
+        let listener = t!(socket.listen(128));
+
+        let t = thread::spawn(move || {
+            let socket = t!(if addr.is_ipv4() { TcpSocket::new_v4() } else { TcpSocket::new_v6() });
+            t!(socket.set_keepalive(true));
+            let mut stream = t!(socket.connect(&addr));
+            assert_eq!(true, t!(stream.keepalive()));
+            t!(stream.write(&[99]));
+        });
+
+        let mut stream = t!(listener.accept()).0;
+        let mut buf = [0];
+        t!(stream.read(&mut buf));
+        assert_eq!(buf[0], 99);
+        t.join().unwrap();
+    })
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn socket_only_v6() {
+    let socket = t!(TcpSocket::new_v6());
+    t!(socket.set_only_v6(true));
+    assert_eq!(true, t!(socket.only_v6()));
+    t!(socket.set_only_v6(false));
+    assert_eq!(false, t!(socket.only_v6()));
+
+    // `IPV6_V6ONLY` only applies to IPv6 sockets.
+    let socket = t!(TcpSocket::new_v4());
+    assert!(socket.set_only_v6(true).is_err());
+}
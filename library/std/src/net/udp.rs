@@ -416,6 +416,86 @@ pub fn broadcast(&self) -> io::Result<bool> {
         self.0.broadcast()
     }
 
+    /// Sets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// This is the size of the buffer the system reserves for outgoing data waiting to be sent.
+    /// The system may round the size, and clamps it to a system-wide range. On Linux, the
+    /// reserved size is double the requested one, to leave room for bookkeeping overhead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_buffer_size)]
+    ///
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.set_send_buffer_size(64 * 1024).expect("set_send_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// For more information about this option, see [`UdpSocket::set_send_buffer_size`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_buffer_size)]
+    ///
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// println!("send buffer size: {}", socket.send_buffer_size().unwrap());
+    /// ```
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// This is the size of the buffer the system reserves for incoming data waiting to be read.
+    /// The system may round the size, and clamps it to a system-wide range. On Linux, the
+    /// reserved size is double the requested one, to leave room for bookkeeping overhead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_buffer_size)]
+    ///
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// socket.set_recv_buffer_size(64 * 1024).expect("set_recv_buffer_size call failed");
+    /// ```
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// For more information about this option, see [`UdpSocket::set_recv_buffer_size`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_buffer_size)]
+    ///
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// println!("recv buffer size: {}", socket.recv_buffer_size().unwrap());
+    /// ```
+    #[unstable(feature = "socket_buffer_size", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
     /// Sets the value of the `IP_MULTICAST_LOOP` option for this socket.
     ///
     /// If enabled, multicast packets will be looped back to the local socket.
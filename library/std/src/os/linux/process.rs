@@ -4,13 +4,18 @@
 
 #![unstable(feature = "linux_pidfd", issue = "82971")]
 
-use crate::io::Result;
+use crate::io::{self, Result};
+use crate::mem;
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+use crate::os::unix::process::ExitStatusExt;
 use crate::process;
+use crate::ptr;
 use crate::sealed::Sealed;
 #[cfg(not(doc))]
 use crate::sys::fd::FileDesc;
+use crate::sys::{cvt, cvt_r};
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use crate::time::{Duration, Instant};
 
 #[cfg(doc)]
 struct FileDesc;
@@ -38,6 +43,15 @@
 ///
 /// // The file descriptor will be closed when `pidfd` is dropped.
 /// ```
+///
+/// A pidfd for an arbitrary process can also be obtained with [`PidFd::open`].
+///
+/// Unlike a PID, a pidfd keeps referring to the same process even after it has
+/// exited, so signals sent through it can never reach an unrelated process that
+/// happens to reuse the PID. A pidfd becomes readable once the process exits,
+/// which allows it to be registered with `poll`, `epoll` or an async runtime
+/// to get notified of the exit without blocking a thread.
+///
 /// Refer to the man page of [`pidfd_open(2)`] for further details.
 ///
 /// [`Command`]: process::Command
@@ -51,6 +65,198 @@ pub struct PidFd {
     inner: FileDesc,
 }
 
+impl PidFd {
+    /// Opens a pidfd referring to the existing process with the given PID.
+    ///
+    /// Since PIDs are reused once a process has exited and been waited for,
+    /// `pid` may already refer to a different process by the time this is
+    /// called. This cannot happen for a child of the current process that
+    /// has not been waited for yet, e.g. one obtained from [`Child::id`]
+    /// before calling [`Child::wait`] or [`Child::try_wait`].
+    ///
+    /// Requires Linux 5.3 or later.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(linux_pidfd)]
+    /// use std::os::linux::process::PidFd;
+    /// use std::process::Command;
+    ///
+    /// let mut child = Command::new("sleep").arg("10").spawn()?;
+    /// let pidfd = PidFd::open(child.id())?;
+    /// pidfd.kill()?;
+    /// let status = pidfd.wait()?;
+    /// assert_eq!(status.code(), None);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [`Child::id`]: process::Child::id
+    /// [`Child::wait`]: process::Child::wait
+    /// [`Child::try_wait`]: process::Child::try_wait
+    pub fn open(pid: u32) -> Result<PidFd> {
+        let pid = libc::pid_t::try_from(pid)
+            .map_err(|_| io::const_io_error!(io::ErrorKind::InvalidInput, "pid out of range"))?;
+        let fd = cvt(unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) })?;
+        // SAFETY: pidfd_open returned a new file descriptor that nothing else owns.
+        Ok(unsafe { PidFd::from_raw_fd(fd as RawFd) })
+    }
+
+    /// Sends the signal `signal` to the process.
+    ///
+    /// Unlike [`Child::kill`], this returns an error with the raw OS error
+    /// `ESRCH` if the process has already exited, even if it has not been
+    /// waited for yet.
+    ///
+    /// Requires Linux 5.1 or later.
+    ///
+    /// [`Child::kill`]: process::Child::kill
+    pub fn send_signal(&self, signal: i32) -> Result<()> {
+        cvt(unsafe {
+            libc::syscall(
+                libc::SYS_pidfd_send_signal,
+                self.as_raw_fd(),
+                signal,
+                ptr::null::<libc::siginfo_t>(),
+                0,
+            )
+        })
+        .map(drop)
+    }
+
+    /// Forces the process to exit by sending it `SIGKILL`.
+    ///
+    /// This is equivalent to `send_signal(libc::SIGKILL)`.
+    pub fn kill(&self) -> Result<()> {
+        self.send_signal(libc::SIGKILL)
+    }
+
+    /// Waits for the process to exit completely, returning the status that
+    /// it exited with.
+    ///
+    /// This reaps the process, so the process must be a child of the current
+    /// process, and must not have been waited for yet, e.g. through
+    /// [`Child::wait`]. Otherwise an error with the raw OS error `ECHILD` is
+    /// returned. Note that the [`Child`] does not learn about the exit status
+    /// collected here, so waiting on it afterwards fails as well.
+    ///
+    /// Requires Linux 5.4 or later.
+    ///
+    /// [`Child`]: process::Child
+    /// [`Child::wait`]: process::Child::wait
+    pub fn wait(&self) -> Result<process::ExitStatus> {
+        let status = self.waitid(libc::WEXITED)?;
+        Ok(status.expect("waitid without WNOHANG should return a status"))
+    }
+
+    /// Attempts to collect the exit status of the process if it has already
+    /// exited.
+    ///
+    /// This function will not block the calling thread and will only check
+    /// whether the process has exited or not, returning `Ok(None)` if it is
+    /// still running. Like [`wait`], this reaps the process, and has the same
+    /// requirements.
+    ///
+    /// [`wait`]: PidFd::wait
+    pub fn try_wait(&self) -> Result<Option<process::ExitStatus>> {
+        self.waitid(libc::WEXITED | libc::WNOHANG)
+    }
+
+    /// Waits for the process to exit for at most `timeout`, returning its exit
+    /// status, or `Ok(None)` if it is still running once the timeout has elapsed.
+    ///
+    /// Like [`wait`], this reaps the process, and has the same requirements.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(linux_pidfd)]
+    /// use std::os::linux::process::PidFd;
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let child = Command::new("sleep").arg("10").spawn()?;
+    /// let pidfd = PidFd::open(child.id())?;
+    /// if pidfd.wait_timeout(Duration::from_secs(1))?.is_none() {
+    ///     pidfd.kill()?;
+    ///     pidfd.wait()?;
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [`wait`]: PidFd::wait
+    pub fn wait_timeout(&self, timeout: Duration) -> Result<Option<process::ExitStatus>> {
+        if self.poll_exit(timeout)? { self.try_wait() } else { Ok(None) }
+    }
+
+    /// Returns whether the process has exited, without blocking and without
+    /// reaping it.
+    ///
+    /// Unlike [`try_wait`], this works for any process, not only for children
+    /// of the current process, and leaves the exit status to be collected by
+    /// whoever waits for the process.
+    ///
+    /// [`try_wait`]: PidFd::try_wait
+    pub fn has_exited(&self) -> Result<bool> {
+        self.poll_exit(Duration::ZERO)
+    }
+
+    /// Waits for at most `timeout` for the pidfd to become readable, which
+    /// happens when the process exits.
+    pub(crate) fn poll_exit(&self, timeout: Duration) -> Result<bool> {
+        // If the deadline can't be represented, we might as well wait forever.
+        let deadline = Instant::now().checked_add(timeout);
+        let mut pollfd = libc::pollfd { fd: self.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+        loop {
+            let remaining = match deadline {
+                Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+                None => timeout,
+            };
+            // Round up, so that poll doesn't return just before the deadline.
+            let timeout_ms = (remaining.as_nanos() + 999_999) / 1_000_000;
+            let timeout_ms = libc::c_int::try_from(timeout_ms).unwrap_or(libc::c_int::MAX);
+            match cvt(unsafe { libc::poll(&mut pollfd, 1, timeout_ms) }) {
+                // Poll timed out, but the deadline may be further away than it
+                // is able to wait for at once.
+                Ok(0) if deadline.map_or(true, |deadline| Instant::now() < deadline) => {}
+                Ok(0) => return Ok(false),
+                Ok(_) => return Ok(true),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn waitid(&self, options: libc::c_int) -> Result<Option<process::ExitStatus>> {
+        let mut siginfo: libc::siginfo_t = unsafe { mem::zeroed() };
+        cvt_r(|| unsafe {
+            libc::waitid(libc::P_PIDFD, self.as_raw_fd() as libc::id_t, &mut siginfo, options)
+        })?;
+        // With WNOHANG, waitid succeeds without filling in `siginfo` if the
+        // process hasn't exited yet, in which case `si_pid` stays zero.
+        if unsafe { siginfo.si_pid() } == 0 {
+            return Ok(None);
+        }
+        Ok(Some(exit_status_from_siginfo(&siginfo)))
+    }
+}
+
+/// Converts the `siginfo_t` filled in by `waitid` into the wait status that
+/// `waitpid` would have returned for the same state change.
+fn exit_status_from_siginfo(siginfo: &libc::siginfo_t) -> process::ExitStatus {
+    // SAFETY: waitid filled in `siginfo` for a child state change.
+    let status = unsafe { siginfo.si_status() };
+    let wait_status = match siginfo.si_code {
+        libc::CLD_EXITED => (status & 0xff) << 8,
+        libc::CLD_KILLED => status,
+        libc::CLD_DUMPED => status | 0x80,
+        libc::CLD_CONTINUED => 0xffff,
+        libc::CLD_STOPPED | libc::CLD_TRAPPED => (status << 8) | 0x7f,
+        _ => unreachable!("waitid() should only return the above codes"),
+    };
+    process::ExitStatus::from_raw(wait_status)
+}
+
 impl AsInner<FileDesc> for PidFd {
     #[inline]
     fn as_inner(&self) -> &FileDesc {
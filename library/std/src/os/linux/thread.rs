@@ -0,0 +1,116 @@
+//! Linux-specific extensions to primitives in the [`std::thread`] module.
+//!
+//! [`std::thread`]: crate::thread
+
+#![unstable(feature = "linux_thread_sched", issue = "none")]
+
+use crate::io;
+use crate::sealed::Sealed;
+use crate::thread;
+
+/// A scheduling policy, as set by `sched_setscheduler(2)`.
+///
+/// See the `sched(7)` man page for a detailed description of the policies.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SchedPolicy {
+    /// `SCHED_OTHER`, the default time-sharing policy, under which threads are given CPU time
+    /// according to their nice value.
+    Other,
+
+    /// `SCHED_BATCH`, for CPU-intensive threads which do not need to react quickly.
+    Batch,
+
+    /// `SCHED_IDLE`, for threads which should only run when the CPU has nothing else to do.
+    Idle,
+
+    /// `SCHED_FIFO` with the given static priority, from 1 (lowest) to 99 (highest).
+    ///
+    /// A thread under this real-time policy keeps running until it blocks, yields or is
+    /// preempted by a thread with a higher priority.
+    Fifo(i32),
+
+    /// `SCHED_RR` with the given static priority, from 1 (lowest) to 99 (highest).
+    ///
+    /// Like [`Fifo`](SchedPolicy::Fifo), except that threads of the same priority take turns
+    /// running for a limited time slice.
+    RoundRobin(i32),
+}
+
+/// Linux-specific extensions to [`thread::Builder`].
+///
+/// The options set through this trait are applied by the new thread to itself before running its
+/// closure. If any of them can't be applied, the thread doesn't run the closure, and spawning it
+/// returns the error. In particular, setting a real-time policy or lowering the nice value
+/// usually requires the `CAP_SYS_NICE` capability or a suitable `RLIMIT_RTPRIO` or
+/// `RLIMIT_NICE` resource limit, and fails with [`io::ErrorKind::PermissionDenied`] otherwise.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_thread_sched)]
+/// use std::os::linux::thread::{BuilderExt, SchedPolicy};
+/// use std::thread;
+///
+/// let handle = thread::Builder::new()
+///     .affinity(&[2, 3])
+///     .sched_policy(SchedPolicy::Fifo(10))
+///     .spawn(|| {
+///         // latency-sensitive work
+///     })
+///     .expect("failed to spawn a real-time thread");
+/// handle.join().unwrap();
+/// ```
+pub trait BuilderExt: Sealed {
+    /// Restricts the thread to run on the given CPUs, as with `sched_setaffinity(2)`.
+    ///
+    /// CPUs are identified by their index, as listed in `/proc/cpuinfo`. Spawning the thread
+    /// fails if none of them is available to it, or if an index is not lower than
+    /// `CPU_SETSIZE` (1024).
+    fn affinity(self, cpus: &[usize]) -> thread::Builder;
+
+    /// Sets the nice value of the thread, from -20 (highest priority) to 19 (lowest priority).
+    ///
+    /// Values out of this range are clamped to it. The nice value only affects threads under
+    /// the [`Other`](SchedPolicy::Other) and [`Batch`](SchedPolicy::Batch) policies.
+    fn nice(self, nice: i32) -> thread::Builder;
+
+    /// Sets the scheduling policy of the thread.
+    fn sched_policy(self, policy: SchedPolicy) -> thread::Builder;
+}
+
+impl BuilderExt for thread::Builder {
+    fn affinity(mut self, cpus: &[usize]) -> thread::Builder {
+        self.sched.affinity = Some(cpus.to_vec());
+        self
+    }
+
+    fn nice(mut self, nice: i32) -> thread::Builder {
+        self.sched.nice = Some(nice);
+        self
+    }
+
+    fn sched_policy(mut self, policy: SchedPolicy) -> thread::Builder {
+        self.sched.policy = Some(policy);
+        self
+    }
+}
+
+/// Returns the indices of the CPUs the current thread is allowed to run on.
+///
+/// This is the affinity mask queried by `sched_getaffinity(2)`, which is inherited from the
+/// thread that spawned the current one, unless changed with [`BuilderExt::affinity`] or by
+/// another process. Its length is what [`thread::available_parallelism`] bases its estimate on,
+/// before taking cgroup quotas into account.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(linux_thread_sched)]
+/// use std::os::linux::thread::current_affinity;
+///
+/// let cpus = current_affinity().unwrap();
+/// assert!(!cpus.is_empty());
+/// ```
+pub fn current_affinity() -> io::Result<Vec<usize>> {
+    crate::sys::thread::current_affinity()
+}
@@ -6,6 +6,7 @@
 use crate::net;
 use crate::sealed::Sealed;
 use crate::sys_common::AsInner;
+use crate::time::Duration;
 
 /// Os-specific extensions for [`TcpStream`]
 ///
@@ -53,6 +54,125 @@ pub trait TcpStreamExt: Sealed {
     /// ```
     #[unstable(feature = "tcp_quickack", issue = "96256")]
     fn quickack(&self) -> io::Result<bool>;
+
+    /// Sets the value of the `TCP_KEEPIDLE` option on this socket.
+    ///
+    /// This is the time the connection needs to remain idle before keepalive probes are sent,
+    /// if `SO_KEEPALIVE` is enabled. It is truncated to whole seconds, and Linux only accepts
+    /// values from 1 to 32767 seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive, tcp_quickack)]
+    /// use std::net::TcpStream;
+    /// use std::os::linux::net::TcpStreamExt;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///         .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive_idle(Duration::from_secs(60)).expect("set_keepalive_idle call failed");
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()>;
+
+    /// Gets the value of the `TCP_KEEPIDLE` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStreamExt::set_keepalive_idle`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive, tcp_quickack)]
+    /// use std::net::TcpStream;
+    /// use std::os::linux::net::TcpStreamExt;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///         .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive_idle(Duration::from_secs(60)).expect("set_keepalive_idle call failed");
+    /// assert_eq!(stream.keepalive_idle().unwrap(), Duration::from_secs(60));
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    fn keepalive_idle(&self) -> io::Result<Duration>;
+
+    /// Sets the value of the `TCP_KEEPINTVL` option on this socket.
+    ///
+    /// This is the time between two keepalive probes. It is truncated to whole seconds, and
+    /// Linux only accepts values from 1 to 32767 seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive, tcp_quickack)]
+    /// use std::net::TcpStream;
+    /// use std::os::linux::net::TcpStreamExt;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///         .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive_interval(Duration::from_secs(10)).expect("set_keepalive_interval call failed");
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()>;
+
+    /// Gets the value of the `TCP_KEEPINTVL` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStreamExt::set_keepalive_interval`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive, tcp_quickack)]
+    /// use std::net::TcpStream;
+    /// use std::os::linux::net::TcpStreamExt;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///         .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive_interval(Duration::from_secs(10)).expect("set_keepalive_interval call failed");
+    /// assert_eq!(stream.keepalive_interval().unwrap(), Duration::from_secs(10));
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    fn keepalive_interval(&self) -> io::Result<Duration>;
+
+    /// Sets the value of the `TCP_KEEPCNT` option on this socket.
+    ///
+    /// This is the number of unanswered keepalive probes after which the connection is dropped,
+    /// from 1 to 127.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive, tcp_quickack)]
+    /// use std::net::TcpStream;
+    /// use std::os::linux::net::TcpStreamExt;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///         .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive_count(5).expect("set_keepalive_count call failed");
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    fn set_keepalive_count(&self, count: u32) -> io::Result<()>;
+
+    /// Gets the value of the `TCP_KEEPCNT` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStreamExt::set_keepalive_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_keepalive, tcp_quickack)]
+    /// use std::net::TcpStream;
+    /// use std::os::linux::net::TcpStreamExt;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8080")
+    ///         .expect("Couldn't connect to the server...");
+    /// stream.set_keepalive_count(5).expect("set_keepalive_count call failed");
+    /// assert_eq!(stream.keepalive_count().unwrap(), 5);
+    /// ```
+    #[unstable(feature = "tcp_keepalive", issue = "none")]
+    fn keepalive_count(&self) -> io::Result<u32>;
 }
 
 #[unstable(feature = "tcp_quickack", issue = "96256")]
@@ -67,4 +187,271 @@ fn set_quickack(&self, quickack: bool) -> io::Result<()> {
     fn quickack(&self) -> io::Result<bool> {
         self.as_inner().as_inner().quickack()
     }
+
+    fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()> {
+        self.as_inner().as_inner().set_keepalive_idle(idle)
+    }
+
+    fn keepalive_idle(&self) -> io::Result<Duration> {
+        self.as_inner().as_inner().keepalive_idle()
+    }
+
+    fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        self.as_inner().as_inner().set_keepalive_interval(interval)
+    }
+
+    fn keepalive_interval(&self) -> io::Result<Duration> {
+        self.as_inner().as_inner().keepalive_interval()
+    }
+
+    fn set_keepalive_count(&self, count: u32) -> io::Result<()> {
+        self.as_inner().as_inner().set_keepalive_count(count)
+    }
+
+    fn keepalive_count(&self) -> io::Result<u32> {
+        self.as_inner().as_inner().keepalive_count()
+    }
+}
+
+/// Os-specific extensions for [`TcpSocket`]
+///
+/// [`TcpSocket`]: net::TcpSocket
+#[unstable(feature = "tcp_socket", issue = "none")]
+pub trait TcpSocketExt: Sealed {
+    /// Sets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// This allows several sockets to bind to the same address and port, as long as all of them
+    /// set this option before binding and belong to the same user. Incoming connections are then
+    /// distributed across the listening sockets. This option must be set before the socket is
+    /// bound.
+    ///
+    /// See [`man 7 socket`](https://man7.org/linux/man-pages/man7/socket.7.html) for more
+    /// information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    /// use std::net::TcpSocket;
+    /// use std::os::linux::net::TcpSocketExt;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_reuseport(true).expect("set_reuseport call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    fn set_reuseport(&self, reuseport: bool) -> io::Result<()>;
+
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocketExt::set_reuseport`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    /// use std::net::TcpSocket;
+    /// use std::os::linux::net::TcpSocketExt;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_reuseport(true).expect("set_reuseport call failed");
+    /// assert_eq!(socket.reuseport().unwrap_or(false), true);
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    fn reuseport(&self) -> io::Result<bool>;
+
+    /// Binds this socket to a network interface, with the `SO_BINDTODEVICE` option.
+    ///
+    /// Only packets received through the interface named `device`, such as `b"eth0"`, are processed
+    /// by the socket, and packets it sends are routed through that interface. Passing `None`
+    /// removes the binding. This usually requires the `CAP_NET_RAW` capability.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    /// use std::net::TcpSocket;
+    /// use std::os::linux::net::TcpSocketExt;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_bind_device(Some(b"eth0")).expect("set_bind_device call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    fn set_bind_device(&self, device: Option<&[u8]>) -> io::Result<()>;
+
+    /// Gets the name of the network interface this socket is bound to, if any.
+    ///
+    /// For more information about this option, see [`TcpSocketExt::set_bind_device`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    /// use std::net::TcpSocket;
+    /// use std::os::linux::net::TcpSocketExt;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_bind_device(Some(b"eth0")).expect("set_bind_device call failed");
+    /// assert_eq!(socket.bind_device().unwrap().as_deref(), Some(&b"eth0"[..]));
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    fn bind_device(&self) -> io::Result<Option<Vec<u8>>>;
+
+    /// Sets the value of the `TCP_KEEPIDLE` option on this socket.
+    ///
+    /// This is the time the connection needs to remain idle before keepalive probes are sent,
+    /// if `SO_KEEPALIVE` is enabled. It is truncated to whole seconds, and Linux only accepts
+    /// values from 1 to 32767 seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    /// use std::net::TcpSocket;
+    /// use std::os::linux::net::TcpSocketExt;
+    /// use std::time::Duration;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_keepalive_idle(Duration::from_secs(60)).expect("set_keepalive_idle call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()>;
+
+    /// Gets the value of the `TCP_KEEPIDLE` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocketExt::set_keepalive_idle`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    /// use std::net::TcpSocket;
+    /// use std::os::linux::net::TcpSocketExt;
+    /// use std::time::Duration;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_keepalive_idle(Duration::from_secs(60)).expect("set_keepalive_idle call failed");
+    /// assert_eq!(socket.keepalive_idle().unwrap(), Duration::from_secs(60));
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    fn keepalive_idle(&self) -> io::Result<Duration>;
+
+    /// Sets the value of the `TCP_KEEPINTVL` option on this socket.
+    ///
+    /// This is the time between two keepalive probes. It is truncated to whole seconds, and
+    /// Linux only accepts values from 1 to 32767 seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    /// use std::net::TcpSocket;
+    /// use std::os::linux::net::TcpSocketExt;
+    /// use std::time::Duration;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_keepalive_interval(Duration::from_secs(10)).expect("set_keepalive_interval call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()>;
+
+    /// Gets the value of the `TCP_KEEPINTVL` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocketExt::set_keepalive_interval`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    /// use std::net::TcpSocket;
+    /// use std::os::linux::net::TcpSocketExt;
+    /// use std::time::Duration;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_keepalive_interval(Duration::from_secs(10)).expect("set_keepalive_interval call failed");
+    /// assert_eq!(socket.keepalive_interval().unwrap(), Duration::from_secs(10));
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    fn keepalive_interval(&self) -> io::Result<Duration>;
+
+    /// Sets the value of the `TCP_KEEPCNT` option on this socket.
+    ///
+    /// This is the number of unanswered keepalive probes after which the connection is dropped,
+    /// from 1 to 127.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    /// use std::net::TcpSocket;
+    /// use std::os::linux::net::TcpSocketExt;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_keepalive_count(5).expect("set_keepalive_count call failed");
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    fn set_keepalive_count(&self, count: u32) -> io::Result<()>;
+
+    /// Gets the value of the `TCP_KEEPCNT` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocketExt::set_keepalive_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_socket)]
+    /// use std::net::TcpSocket;
+    /// use std::os::linux::net::TcpSocketExt;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create a socket");
+    /// socket.set_keepalive_count(5).expect("set_keepalive_count call failed");
+    /// assert_eq!(socket.keepalive_count().unwrap(), 5);
+    /// ```
+    #[unstable(feature = "tcp_socket", issue = "none")]
+    fn keepalive_count(&self) -> io::Result<u32>;
+}
+
+#[unstable(feature = "tcp_socket", issue = "none")]
+impl Sealed for net::TcpSocket {}
+
+#[unstable(feature = "tcp_socket", issue = "none")]
+impl TcpSocketExt for net::TcpSocket {
+    fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.as_inner().socket().set_reuseport(reuseport)
+    }
+
+    fn reuseport(&self) -> io::Result<bool> {
+        self.as_inner().socket().reuseport()
+    }
+
+    fn set_bind_device(&self, device: Option<&[u8]>) -> io::Result<()> {
+        self.as_inner().socket().set_bind_device(device)
+    }
+
+    fn bind_device(&self) -> io::Result<Option<Vec<u8>>> {
+        self.as_inner().socket().bind_device()
+    }
+
+    fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()> {
+        self.as_inner().socket().set_keepalive_idle(idle)
+    }
+
+    fn keepalive_idle(&self) -> io::Result<Duration> {
+        self.as_inner().socket().keepalive_idle()
+    }
+
+    fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        self.as_inner().socket().set_keepalive_interval(interval)
+    }
+
+    fn keepalive_interval(&self) -> io::Result<Duration> {
+        self.as_inner().socket().keepalive_interval()
+    }
+
+    fn set_keepalive_count(&self, count: u32) -> io::Result<()> {
+        self.as_inner().socket().set_keepalive_count(count)
+    }
+
+    fn keepalive_count(&self) -> io::Result<u32> {
+        self.as_inner().socket().keepalive_count()
+    }
 }
@@ -26,3 +26,62 @@ macro_rules! t {
     t!(stream.set_quickack(false));
     assert_eq!(false, t!(stream.quickack()));
 }
+
+#[test]
+fn keepalive_params() {
+    use crate::{
+        net::{test::next_test_ip4, TcpListener, TcpStream},
+        os::net::linux_ext::tcp::TcpStreamExt,
+        time::Duration,
+    };
+
+    macro_rules! t {
+        ($e:expr) => {
+            match $e {
+                Ok(t) => t,
+                Err(e) => panic!("received error for `{}`: {}", stringify!($e), e),
+            }
+        };
+    }
+
+    let addr = next_test_ip4();
+    let _listener = t!(TcpListener::bind(&addr));
+
+    let stream = t!(TcpStream::connect(&("localhost", addr.port())));
+
+    t!(stream.set_keepalive(true));
+    t!(stream.set_keepalive_idle(Duration::from_secs(30)));
+    assert_eq!(Duration::from_secs(30), t!(stream.keepalive_idle()));
+    t!(stream.set_keepalive_interval(Duration::from_secs(5)));
+    assert_eq!(Duration::from_secs(5), t!(stream.keepalive_interval()));
+    t!(stream.set_keepalive_count(3));
+    assert_eq!(3, t!(stream.keepalive_count()));
+}
+
+#[test]
+fn socket_reuseport() {
+    use crate::{
+        net::{test::next_test_ip4, TcpSocket},
+        os::net::linux_ext::tcp::TcpSocketExt,
+    };
+
+    macro_rules! t {
+        ($e:expr) => {
+            match $e {
+                Ok(t) => t,
+                Err(e) => panic!("received error for `{}`: {}", stringify!($e), e),
+            }
+        };
+    }
+
+    let addr = next_test_ip4();
+    let mut listeners = Vec::new();
+    for _ in 0..2 {
+        let socket = t!(TcpSocket::new_v4());
+        t!(socket.set_reuseport(true));
+        assert_eq!(true, t!(socket.reuseport()));
+        assert_eq!(None, t!(socket.bind_device()));
+        t!(socket.bind(&addr));
+        listeners.push(t!(socket.listen(128)));
+    }
+}
@@ -1052,6 +1052,45 @@ pub fn lchown<P: AsRef<Path>>(dir: P, uid: Option<u32>, gid: Option<u32>) -> io:
     sys::fs::lchown(dir.as_ref(), uid.unwrap_or(u32::MAX), gid.unwrap_or(u32::MAX))
 }
 
+/// Sends up to `len` bytes starting at `offset` in `file` to `dest`, which can be any file
+/// descriptor, such as a socket or a pipe.
+///
+/// Returns the number of bytes sent, which is less than `len` if the end of `file` is reached
+/// first. The cursor of `file` is neither used nor changed, while the data is written at the
+/// cursor of `dest`, as with [`Write::write`].
+///
+/// This uses `sendfile(2)`, which lets the kernel send the data without bringing it into user
+/// space. If it is not available, or does not support `dest`, the data is sent through a
+/// buffer instead. To copy a range of bytes between two files, see [`fs::File::copy_range_to`].
+///
+/// # Errors
+///
+/// This function returns an error if `file` isn't opened for reading, or `dest` isn't opened
+/// for writing. If an error is returned, an unknown amount of data may already have been sent.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(file_copy_range)]
+/// use std::fs::File;
+/// use std::net::TcpStream;
+/// use std::os::unix::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     let segment = File::open("segment.log")?;
+///     let replica = TcpStream::connect("127.0.0.1:7000")?;
+///     // Ship the first mebibyte of the segment to a replica.
+///     let sent = fs::sendfile(&segment, 0, 1 << 20, &replica)?;
+///     println!("sent {sent} bytes");
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "file_copy_range", issue = "none")]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn sendfile<F: AsFd>(file: &fs::File, offset: u64, len: u64, dest: F) -> io::Result<u64> {
+    sys::fs::sendfile(file.as_inner(), offset, len, dest.as_fd().as_raw_fd())
+}
+
 /// Change the root directory of the current process to the specified path.
 ///
 /// This typically requires privileges, such as root or a specific capability.
@@ -55,3 +55,28 @@ fn write_vectored_at() {
     let content = fs::read(&filename).unwrap();
     assert_eq!(&content, expected);
 }
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn sendfile_to_socket() {
+    use crate::io::Seek;
+    use crate::os::unix::net::UnixStream;
+
+    let dir = crate::sys_common::io::test::tmpdir();
+    let filename = dir.join("sendfile.txt");
+    fs::write(&filename, b"0123456789").unwrap();
+
+    let file = fs::File::open(&filename).unwrap();
+    let (tx, mut rx) = UnixStream::pair().unwrap();
+    assert_eq!(sendfile(&file, 2, 5, &tx).unwrap(), 5);
+    // The send stops at the end of the file.
+    assert_eq!(sendfile(&file, 8, 100, &tx).unwrap(), 2);
+    drop(tx);
+
+    let mut received = Vec::new();
+    rx.read_to_end(&mut received).unwrap();
+    assert_eq!(received, b"2345689");
+
+    // The cursor of the file is left untouched.
+    assert_eq!((&file).stream_position().unwrap(), 0);
+}
@@ -9,6 +9,9 @@
 use crate::ptr::{eq, read_unaligned};
 use crate::slice::from_raw_parts;
 use crate::sys::net::Socket;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::sys_common::FromInner;
+use crate::time::SystemTime;
 
 // FIXME(#43348): Make libc adapt #[doc(cfg(...))] so we don't need these fake definitions here?
 #[cfg(all(
@@ -29,6 +32,12 @@ mod libc {
     pub type uid_t = u32;
 }
 
+// The types of the control messages which have no constant in the libc crate yet.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SCM_SECURITY: libc::c_int = 0x03;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SCM_PIDFD: libc::c_int = 0x04;
+
 pub(super) fn recv_vectored_with_ancillary_from(
     socket: &Socket,
     bufs: &mut [IoSliceMut<'_>],
@@ -53,6 +62,7 @@ pub(super) fn recv_vectored_with_ancillary_from(
         ancillary.truncated = msg.msg_flags & libc::MSG_CTRUNC == libc::MSG_CTRUNC;
 
         let truncated = msg.msg_flags & libc::MSG_TRUNC == libc::MSG_TRUNC;
+        ancillary.data_truncated = truncated;
         let addr = SocketAddr::from_parts(msg_name, msg.msg_namelen);
 
         Ok((count, truncated, addr))
@@ -81,6 +91,7 @@ pub(super) fn send_vectored_with_ancillary_to(
         }
 
         ancillary.truncated = false;
+        ancillary.data_truncated = false;
 
         socket.send_msg(&mut msg)
     }
@@ -463,6 +474,31 @@ pub enum AncillaryData<'a> {
         target_os = "freebsd"
     ))]
     ScmCredentials(ScmCredentials<'a>),
+    /// A pidfd referring to the sending process, received if [`set_passpidfd`] was enabled.
+    ///
+    /// The level is equal to `SOL_SOCKET` and the type is equal to `SCM_PIDFD`. Like the file
+    /// descriptors in [`ScmRights`](AncillaryData::ScmRights), the pidfd is owned by the
+    /// receiver, which must close it.
+    ///
+    /// [`set_passpidfd`]: super::UnixDatagram::set_passpidfd
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    ScmPidfd(RawFd),
+    /// The security context of the sending socket, as defined by the security module in use
+    /// (e.g. SELinux), received if [`set_passsec`] was enabled.
+    ///
+    /// The level is equal to `SOL_SOCKET` and the type is equal to `SCM_SECURITY`.
+    ///
+    /// [`set_passsec`]: super::UnixDatagram::set_passsec
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    ScmSecurity(&'a [u8]),
+    /// The time at which the message was received, received if [`set_timestamp`] was enabled.
+    ///
+    /// The level is equal to `SOL_SOCKET` and the type is equal to `SCM_TIMESTAMPNS` or
+    /// `SCM_TIMESTAMP`.
+    ///
+    /// [`set_timestamp`]: super::UnixDatagram::set_timestamp
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    ScmTimestamp(SystemTime),
 }
 
 impl<'a> AncillaryData<'a> {
@@ -497,6 +533,42 @@ unsafe fn as_credentials(data: &'a [u8]) -> Self {
         AncillaryData::ScmCredentials(scm_credentials)
     }
 
+    /// Create an `AncillaryData::ScmPidfd` variant.
+    ///
+    /// # Safety
+    ///
+    /// `data` must contain a valid control message and the control message must be type of
+    /// `SOL_SOCKET` and level of `SCM_PIDFD`.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    unsafe fn as_pidfd(data: &'a [u8]) -> Self {
+        AncillaryData::ScmPidfd(read_unaligned(data.as_ptr().cast()))
+    }
+
+    /// Create an `AncillaryData::ScmTimestamp` variant from a `timespec`.
+    ///
+    /// # Safety
+    ///
+    /// `data` must contain a valid control message and the control message must be type of
+    /// `SOL_SOCKET` and level of `SCM_TIMESTAMPNS`.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    unsafe fn as_timestampns(data: &'a [u8]) -> Self {
+        let ts: libc::timespec = read_unaligned(data.as_ptr().cast());
+        AncillaryData::ScmTimestamp(SystemTime::from_inner(ts.into()))
+    }
+
+    /// Create an `AncillaryData::ScmTimestamp` variant from a `timeval`.
+    ///
+    /// # Safety
+    ///
+    /// `data` must contain a valid control message and the control message must be type of
+    /// `SOL_SOCKET` and level of `SCM_TIMESTAMP`.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    unsafe fn as_timestamp(data: &'a [u8]) -> Self {
+        let tv: libc::timeval = read_unaligned(data.as_ptr().cast());
+        let time = crate::sys::time::SystemTime::new(tv.tv_sec as i64, tv.tv_usec as i64 * 1000);
+        AncillaryData::ScmTimestamp(SystemTime::from_inner(time))
+    }
+
     fn try_from_cmsghdr(cmsg: &'a libc::cmsghdr) -> Result<Self, AncillaryError> {
         unsafe {
             let cmsg_len_zero = libc::CMSG_LEN(0) as usize;
@@ -509,6 +581,14 @@ fn try_from_cmsghdr(cmsg: &'a libc::cmsghdr) -> Result<Self, AncillaryError> {
                     libc::SCM_RIGHTS => Ok(AncillaryData::as_rights(data)),
                     #[cfg(any(target_os = "android", target_os = "linux",))]
                     libc::SCM_CREDENTIALS => Ok(AncillaryData::as_credentials(data)),
+                    #[cfg(any(target_os = "android", target_os = "linux",))]
+                    SCM_PIDFD => Ok(AncillaryData::as_pidfd(data)),
+                    #[cfg(any(target_os = "android", target_os = "linux",))]
+                    SCM_SECURITY => Ok(AncillaryData::ScmSecurity(data)),
+                    #[cfg(any(target_os = "android", target_os = "linux",))]
+                    libc::SCM_TIMESTAMPNS => Ok(AncillaryData::as_timestampns(data)),
+                    #[cfg(any(target_os = "android", target_os = "linux",))]
+                    libc::SCM_TIMESTAMP => Ok(AncillaryData::as_timestamp(data)),
                     #[cfg(target_os = "freebsd")]
                     libc::SCM_CREDS2 => Ok(AncillaryData::as_credentials(data)),
                     #[cfg(target_os = "netbsd")]
@@ -602,6 +682,7 @@ pub struct SocketAncillary<'a> {
     buffer: &'a mut [u8],
     length: usize,
     truncated: bool,
+    data_truncated: bool,
 }
 
 impl<'a> SocketAncillary<'a> {
@@ -618,7 +699,7 @@ impl<'a> SocketAncillary<'a> {
     /// ```
     #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
     pub fn new(buffer: &'a mut [u8]) -> Self {
-        SocketAncillary { buffer, length: 0, truncated: false }
+        SocketAncillary { buffer, length: 0, truncated: false, data_truncated: false }
     }
 
     /// Returns the capacity of the buffer.
@@ -677,6 +758,39 @@ pub fn truncated(&self) -> bool {
         self.truncated
     }
 
+    /// Is `true` if during the last recv operation the message itself was truncated, because
+    /// the buffers it was received into were too small (`MSG_TRUNC`).
+    ///
+    /// The rest of the message is discarded for datagram and seqpacket sockets. Stream sockets
+    /// never truncate data.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data, unix_socket_seqpacket)]
+    /// use std::os::unix::net::{UnixSeqpacket, SocketAncillary};
+    /// use std::io::IoSliceMut;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixSeqpacket::connect("/tmp/sock")?;
+    ///
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///
+    ///     let mut buf = [1; 8];
+    ///     let mut bufs = &mut [IoSliceMut::new(&mut buf[..])][..];
+    ///     sock.recv_vectored_with_ancillary(bufs, &mut ancillary)?;
+    ///
+    ///     println!("Is the message truncated: {}", ancillary.data_truncated());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn data_truncated(&self) -> bool {
+        self.data_truncated
+    }
+
     /// Add file descriptors to the ancillary data.
     ///
     /// The function returns `true` if there was enough space in the buffer.
@@ -708,6 +822,7 @@ pub fn truncated(&self) -> bool {
     #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
     pub fn add_fds(&mut self, fds: &[RawFd]) -> bool {
         self.truncated = false;
+        self.data_truncated = false;
         add_to_ancillary_data(
             &mut self.buffer,
             &mut self.length,
@@ -734,6 +849,7 @@ pub fn add_fds(&mut self, fds: &[RawFd]) -> bool {
     #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
     pub fn add_creds(&mut self, creds: &[SocketCred]) -> bool {
         self.truncated = false;
+        self.data_truncated = false;
         add_to_ancillary_data(
             &mut self.buffer,
             &mut self.length,
@@ -794,5 +910,6 @@ pub fn add_creds(&mut self, creds: &[SocketCred]) -> bool {
     pub fn clear(&mut self) {
         self.length = 0;
         self.truncated = false;
+        self.data_truncated = false;
     }
 }
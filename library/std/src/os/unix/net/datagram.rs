@@ -865,6 +865,121 @@ pub fn passcred(&self) -> io::Result<bool> {
         self.0.passcred()
     }
 
+    /// Moves the socket to pass a pidfd of the sending process in [`SocketAncillary`] along
+    /// with every message. The pidfd is received as [`AncillaryData::ScmPidfd`].
+    ///
+    /// Set the socket option `SO_PASSPIDFD`, available since Linux 6.5.
+    ///
+    /// [`AncillaryData::ScmPidfd`]: crate::os::unix::net::AncillaryData::ScmPidfd
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::UnixDatagram;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixDatagram::unbound()?;
+    ///     sock.set_passpidfd(true).expect("set_passpidfd function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passpidfd(&self, passpidfd: bool) -> io::Result<()> {
+        self.0.set_passpidfd(passpidfd)
+    }
+
+    /// Get the current value of the socket for passing a pidfd in [`SocketAncillary`].
+    /// This value can be change by [`set_passpidfd`].
+    ///
+    /// Get the socket option `SO_PASSPIDFD`.
+    ///
+    /// [`set_passpidfd`]: UnixDatagram::set_passpidfd
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passpidfd(&self) -> io::Result<bool> {
+        self.0.passpidfd()
+    }
+
+    /// Moves the socket to pass the security context of the sending socket in
+    /// [`SocketAncillary`] along with every message. The context is received as
+    /// [`AncillaryData::ScmSecurity`].
+    ///
+    /// Set the socket option `SO_PASSSEC`.
+    ///
+    /// [`AncillaryData::ScmSecurity`]: crate::os::unix::net::AncillaryData::ScmSecurity
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::UnixDatagram;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixDatagram::unbound()?;
+    ///     sock.set_passsec(true).expect("set_passsec function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passsec(&self, passsec: bool) -> io::Result<()> {
+        self.0.set_passsec(passsec)
+    }
+
+    /// Get the current value of the socket for passing the security context in
+    /// [`SocketAncillary`]. This value can be change by [`set_passsec`].
+    ///
+    /// Get the socket option `SO_PASSSEC`.
+    ///
+    /// [`set_passsec`]: UnixDatagram::set_passsec
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passsec(&self) -> io::Result<bool> {
+        self.0.passsec()
+    }
+
+    /// Moves the socket to pass the time at which each message was received in
+    /// [`SocketAncillary`]. The time is received as [`AncillaryData::ScmTimestamp`].
+    ///
+    /// Set the socket option `SO_TIMESTAMPNS`.
+    ///
+    /// [`AncillaryData::ScmTimestamp`]: crate::os::unix::net::AncillaryData::ScmTimestamp
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::UnixDatagram;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixDatagram::unbound()?;
+    ///     sock.set_timestamp(true).expect("set_timestamp function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_timestamp(&self, timestamp: bool) -> io::Result<()> {
+        self.0.set_timestamp(timestamp)
+    }
+
+    /// Get the current value of the socket for passing the receive time in [`SocketAncillary`].
+    /// This value can be change by [`set_timestamp`].
+    ///
+    /// Get the socket option `SO_TIMESTAMPNS`.
+    ///
+    /// [`set_timestamp`]: UnixDatagram::set_timestamp
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn timestamp(&self) -> io::Result<bool> {
+        self.0.timestamp()
+    }
+
     /// Set the id of the socket for network filtering purpose
     ///
     #[cfg_attr(
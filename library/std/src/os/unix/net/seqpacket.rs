@@ -0,0 +1,1007 @@
+use super::{recv_vectored_with_ancillary_from, send_vectored_with_ancillary_to, SocketAncillary};
+use super::{sockaddr_un, SocketAddr};
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::net::Shutdown;
+use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+use crate::os::unix::ucred::{self, UCred};
+use crate::path::Path;
+use crate::sys::cvt;
+use crate::sys::net::Socket;
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::time::Duration;
+use crate::{fmt, mem};
+
+/// A structure representing a Unix sequenced-packet socket server.
+///
+/// Sequenced-packet sockets (`SOCK_SEQPACKET`) are connection-oriented like [`UnixStream`],
+/// but preserve message boundaries like [`UnixDatagram`]: every [`send`] on one end is
+/// received by exactly one [`recv`] on the other end.
+///
+/// [`UnixStream`]: crate::os::unix::net::UnixStream
+/// [`UnixDatagram`]: crate::os::unix::net::UnixDatagram
+/// [`send`]: UnixSeqpacket::send
+/// [`recv`]: UnixSeqpacket::recv
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_socket_seqpacket)]
+/// use std::os::unix::net::{UnixSeqpacket, UnixSeqpacketListener};
+/// use std::thread;
+///
+/// fn handle_client(socket: UnixSeqpacket) -> std::io::Result<()> {
+///     let mut buf = [0; 1024];
+///     let len = socket.recv(&mut buf)?;
+///     socket.send(&buf[..len])?;
+///     Ok(())
+/// }
+///
+/// fn main() -> std::io::Result<()> {
+///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+///
+///     loop {
+///         let (socket, _addr) = listener.accept()?;
+///         thread::spawn(move || handle_client(socket));
+///     }
+/// }
+/// ```
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+pub struct UnixSeqpacketListener(Socket);
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacketListener {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacketListener");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacketListener {
+    /// Creates a new `UnixSeqpacketListener` bound to the specified socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// let listener = match UnixSeqpacketListener::bind("/path/to/the/socket") {
+    ///     Ok(sock) => sock,
+    ///     Err(e) => {
+    ///         println!("Couldn't bind: {e:?}");
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacketListener> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+
+            cvt(libc::bind(inner.as_raw_fd(), &addr as *const _ as *const _, len as _))?;
+            // A negative backlog is clamped to the system maximum, `somaxconn`.
+            cvt(libc::listen(inner.as_raw_fd(), -1))?;
+
+            Ok(UnixSeqpacketListener(inner))
+        }
+    }
+
+    /// Creates a new `UnixSeqpacketListener` bound to the specified [`socket address`].
+    ///
+    /// [`socket address`]: crate::os::unix::net::SocketAddr
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::linux::net::SocketAddrExt;
+    /// use std::os::unix::net::{SocketAddr, UnixSeqpacketListener};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let addr = SocketAddr::from_abstract_name(b"hidden")?;
+    ///     let listener = UnixSeqpacketListener::bind_addr(&addr)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn bind_addr(socket_addr: &SocketAddr) -> io::Result<UnixSeqpacketListener> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            cvt(libc::bind(
+                inner.as_raw_fd(),
+                &socket_addr.addr as *const _ as *const _,
+                socket_addr.len as _,
+            ))?;
+            cvt(libc::listen(inner.as_raw_fd(), -1))?;
+            Ok(UnixSeqpacketListener(inner))
+        }
+    }
+
+    /// Accepts a new incoming connection to this listener.
+    ///
+    /// This function will block the calling thread until a new connection is established. When
+    /// established, the corresponding [`UnixSeqpacket`] and the remote peer's address will be
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///
+    ///     match listener.accept() {
+    ///         Ok((socket, addr)) => println!("Got a client: {addr:?}"),
+    ///         Err(e) => println!("accept function failed: {e:?}"),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn accept(&self) -> io::Result<(UnixSeqpacket, SocketAddr)> {
+        let mut storage: libc::sockaddr_un = unsafe { mem::zeroed() };
+        let mut len = mem::size_of_val(&storage) as libc::socklen_t;
+        let sock = self.0.accept(&mut storage as *mut _ as *mut _, &mut len)?;
+        let addr = SocketAddr::from_parts(storage, len)?;
+        Ok((UnixSeqpacket(sock), addr))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixSeqpacketListener` is a reference to the same socket that this object
+    /// references. Both handles can be used to accept incoming connections and options set on
+    /// one listener will affect the other.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///     let listener_copy = listener.try_clone().expect("try_clone failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacketListener> {
+        self.0.duplicate().map(UnixSeqpacketListener)
+    }
+
+    /// Returns the local socket address of this listener.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///     let addr = listener.local_addr().expect("Couldn't get local address");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    ///
+    /// This will result in the `accept` operation becoming nonblocking, i.e., immediately
+    /// returning from their calls. If the IO operation could not be completed and needs to be
+    /// retried, an error with kind [`io::ErrorKind::WouldBlock`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///     listener.set_nonblocking(true).expect("Couldn't set non blocking");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/tmp/sock")?;
+    ///
+    ///     if let Ok(Some(err)) = listener.take_error() {
+    ///         println!("Got error: {err:?}");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl AsRawFd for UnixSeqpacketListener {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_inner().as_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl FromRawFd for UnixSeqpacketListener {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixSeqpacketListener {
+        UnixSeqpacketListener(Socket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd))))
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl IntoRawFd for UnixSeqpacketListener {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_inner().into_inner().into_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl AsFd for UnixSeqpacketListener {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_inner().as_fd()
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl From<OwnedFd> for UnixSeqpacketListener {
+    #[inline]
+    fn from(fd: OwnedFd) -> UnixSeqpacketListener {
+        UnixSeqpacketListener(Socket::from_inner(FromInner::from_inner(fd)))
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl From<UnixSeqpacketListener> for OwnedFd {
+    #[inline]
+    fn from(listener: UnixSeqpacketListener) -> OwnedFd {
+        listener.0.into_inner().into_inner()
+    }
+}
+
+/// A Unix sequenced-packet socket.
+///
+/// Messages sent on a `UnixSeqpacket` are delivered reliably and in order, like on a
+/// [`UnixStream`], but each [`send`] is received as a single message by one [`recv`] call,
+/// like on a [`UnixDatagram`]. If the buffer passed to [`recv`] is too small for a message,
+/// the rest of the message is discarded. [`recv_vectored_with_ancillary`] reports when that
+/// happens.
+///
+/// [`UnixStream`]: crate::os::unix::net::UnixStream
+/// [`UnixDatagram`]: crate::os::unix::net::UnixDatagram
+/// [`send`]: UnixSeqpacket::send
+/// [`recv`]: UnixSeqpacket::recv
+/// [`recv_vectored_with_ancillary`]: UnixSeqpacket::recv_vectored_with_ancillary
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_socket_seqpacket)]
+/// use std::os::unix::net::UnixSeqpacket;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = UnixSeqpacket::connect("/path/to/my/socket")?;
+///     socket.send(b"hello")?;
+///     socket.send(b"world")?;
+///     let mut buf = [0; 100];
+///     let len = socket.recv(&mut buf)?;
+///     println!("got a message: {:?}", &buf[..len]);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+pub struct UnixSeqpacket(Socket);
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacket {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacket");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        if let Ok(addr) = self.peer_addr() {
+            builder.field("peer", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacket {
+    /// Connects to the socket named by `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// let socket = match UnixSeqpacket::connect("/tmp/sock") {
+    ///     Ok(sock) => sock,
+    ///     Err(e) => {
+    ///         println!("Couldn't connect: {e:?}");
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacket> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+
+            cvt(libc::connect(inner.as_raw_fd(), &addr as *const _ as *const _, len))?;
+            Ok(UnixSeqpacket(inner))
+        }
+    }
+
+    /// Connects to the socket specified by [`address`].
+    ///
+    /// [`address`]: crate::os::unix::net::SocketAddr
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::{UnixSeqpacket, UnixSeqpacketListener};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///     let addr = listener.local_addr()?;
+    ///     let sock = UnixSeqpacket::connect_addr(&addr)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn connect_addr(socket_addr: &SocketAddr) -> io::Result<UnixSeqpacket> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            cvt(libc::connect(
+                inner.as_raw_fd(),
+                &socket_addr.addr as *const _ as *const _,
+                socket_addr.len,
+            ))?;
+            Ok(UnixSeqpacket(inner))
+        }
+    }
+
+    /// Creates an unnamed pair of connected sockets.
+    ///
+    /// Returns two `UnixSeqpacket`s which are connected to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// let (sock1, sock2) = match UnixSeqpacket::pair() {
+    ///     Ok((sock1, sock2)) => (sock1, sock2),
+    ///     Err(e) => {
+    ///         println!("Couldn't create a pair of sockets: {e:?}");
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn pair() -> io::Result<(UnixSeqpacket, UnixSeqpacket)> {
+        let (i1, i2) = Socket::new_pair(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+        Ok((UnixSeqpacket(i1), UnixSeqpacket(i2)))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixSeqpacket` is a reference to the same socket that this object references.
+    /// Both handles will send and receive messages on the same connection, and options set on one
+    /// handle will be propagated to the other.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let sock_copy = socket.try_clone().expect("Couldn't clone socket");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacket> {
+        self.0.duplicate().map(UnixSeqpacket)
+    }
+
+    /// Returns the socket address of the local half of this connection.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let addr = socket.local_addr().expect("Couldn't get local address");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Returns the socket address of the remote half of this connection.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let addr = socket.peer_addr().expect("Couldn't get peer address");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getpeername(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Gets the peer credentials for this Unix domain socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(peer_credentials_unix_socket, unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let peer_cred = socket.peer_cred().expect("Couldn't get peer credentials");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "peer_credentials_unix_socket", issue = "42839", reason = "unstable")]
+    pub fn peer_cred(&self) -> io::Result<UCred> {
+        ucred::peer_cred(self)
+    }
+
+    /// Returns a pidfd referring to the peer process of this connection.
+    ///
+    /// The pidfd refers to the process which connected or created the socket pair, and, unlike
+    /// the PID in [`peer_cred`], can't be recycled to refer to another process. It can be
+    /// waited on or signaled through [`PidFd`].
+    ///
+    /// Get the socket option `SO_PEERPIDFD`, available since Linux 6.5.
+    ///
+    /// [`peer_cred`]: UnixSeqpacket::peer_cred
+    /// [`PidFd`]: crate::os::linux::process::PidFd
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(peer_credentials_unix_socket, unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let pidfd = socket.peer_pidfd().expect("Couldn't get peer pidfd");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "peer_credentials_unix_socket", issue = "42839", reason = "unstable")]
+    pub fn peer_pidfd(&self) -> io::Result<OwnedFd> {
+        self.0.peer_pidfd()
+    }
+
+    /// Returns the security context of the peer socket of this connection, as defined by the
+    /// security module in use (e.g. SELinux).
+    ///
+    /// Get the socket option `SO_PEERSEC`. It fails with `ENOPROTOOPT` if no security module
+    /// provides a context.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(peer_credentials_unix_socket, unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let context = socket.peer_security_context()?;
+    ///     println!("peer context: {}", String::from_utf8_lossy(&context));
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "peer_credentials_unix_socket", issue = "42839", reason = "unstable")]
+    pub fn peer_security_context(&self) -> io::Result<Vec<u8>> {
+        self.0.peer_sec()
+    }
+
+    /// Sends a message on the socket to the connected peer.
+    ///
+    /// The whole buffer is sent as a single message. On success, returns the number of bytes
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.send(b"omelette au fromage").expect("send function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    /// Receives a single message from the connected peer.
+    ///
+    /// On success, returns the number of bytes read. If the message is longer than `buf`, the
+    /// rest of it is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let mut buf = vec![0; 10];
+    ///     socket.recv(buf.as_mut_slice()).expect("recv function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    /// Receives a single message from the connected peer, without removing it from the queue.
+    /// On success, returns the number of bytes peeked.
+    ///
+    /// Successive calls return the same message. This is accomplished by passing `MSG_PEEK` as a
+    /// flag to the underlying `recv` system call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let mut buf = [0; 10];
+    ///     let len = socket.peek(&mut buf).expect("peek failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf)
+    }
+
+    /// Receives a single message and its ancillary data from the socket.
+    ///
+    /// On success, returns the number of bytes read and whether the message was truncated because
+    /// `bufs` were too small for it. The rest of a truncated message is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data, unix_socket_seqpacket)]
+    /// use std::os::unix::net::{UnixSeqpacket, SocketAncillary, AncillaryData};
+    /// use std::io::IoSliceMut;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let mut buf = [0; 64];
+    ///     let bufs = &mut [IoSliceMut::new(&mut buf)][..];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     let (size, truncated) = socket.recv_vectored_with_ancillary(bufs, &mut ancillary)?;
+    ///     println!("received {size} bytes, truncated: {truncated}");
+    ///     for ancillary_result in ancillary.messages() {
+    ///         if let AncillaryData::ScmRights(scm_rights) = ancillary_result.unwrap() {
+    ///             for fd in scm_rights {
+    ///                 println!("receive file descriptor: {fd}");
+    ///             }
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn recv_vectored_with_ancillary(
+        &self,
+        bufs: &mut [IoSliceMut<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<(usize, bool)> {
+        let (count, truncated, _) = recv_vectored_with_ancillary_from(&self.0, bufs, ancillary)?;
+
+        Ok((count, truncated))
+    }
+
+    /// Sends a single message and ancillary data on the socket.
+    ///
+    /// On success, returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data, unix_socket_seqpacket)]
+    /// use std::os::unix::net::{UnixSeqpacket, SocketAncillary};
+    /// use std::io::IoSlice;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let buf = [1; 8];
+    ///     let bufs = &[IoSlice::new(&buf)][..];
+    ///     let fds = [0, 1, 2];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     ancillary.add_fds(&fds[..]);
+    ///     socket.send_vectored_with_ancillary(bufs, &mut ancillary)
+    ///         .expect("send_vectored_with_ancillary function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn send_vectored_with_ancillary(
+        &self,
+        bufs: &[IoSlice<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<usize> {
+        send_vectored_with_ancillary_to(&self.0, None, bufs, ancillary)
+    }
+
+    /// Sets the read timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`recv`] calls will block indefinitely. An [`Err`]
+    /// is returned if the zero [`Duration`] is passed to this method.
+    ///
+    /// [`recv`]: UnixSeqpacket::recv
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_read_timeout(Some(Duration::new(1, 0))).expect("Couldn't set read timeout");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_RCVTIMEO)
+    }
+
+    /// Sets the write timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`send`] calls will block indefinitely. An [`Err`]
+    /// is returned if the zero [`Duration`] is passed to this method.
+    ///
+    /// [`send`]: UnixSeqpacket::send
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_write_timeout(Some(Duration::new(1, 0))).expect("Couldn't set write timeout");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_SNDTIMEO)
+    }
+
+    /// Returns the read timeout of this socket.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn read_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_RCVTIMEO)
+    }
+
+    /// Returns the write timeout of this socket.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_SNDTIMEO)
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_nonblocking(true).expect("Couldn't set nonblocking");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Moves the socket to pass unix credentials as control message in [`SocketAncillary`].
+    ///
+    /// Set the socket option `SO_PASSCRED`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data, unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_passcred(true).expect("Couldn't set passcred");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
+        self.0.set_passcred(passcred)
+    }
+
+    /// Get the current value of the socket for passing unix credentials in [`SocketAncillary`].
+    /// This value can be change by [`set_passcred`].
+    ///
+    /// Get the socket option `SO_PASSCRED`.
+    ///
+    /// [`set_passcred`]: UnixSeqpacket::set_passcred
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passcred(&self) -> io::Result<bool> {
+        self.0.passcred()
+    }
+
+    /// Moves the socket to pass a pidfd of the sending process in [`SocketAncillary`] along
+    /// with every message. The pidfd is received as [`AncillaryData::ScmPidfd`].
+    ///
+    /// Set the socket option `SO_PASSPIDFD`, available since Linux 6.5.
+    ///
+    /// [`AncillaryData::ScmPidfd`]: crate::os::unix::net::AncillaryData::ScmPidfd
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data, unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_passpidfd(true).expect("set_passpidfd function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passpidfd(&self, passpidfd: bool) -> io::Result<()> {
+        self.0.set_passpidfd(passpidfd)
+    }
+
+    /// Get the current value of the socket for passing a pidfd in [`SocketAncillary`].
+    /// This value can be change by [`set_passpidfd`].
+    ///
+    /// Get the socket option `SO_PASSPIDFD`.
+    ///
+    /// [`set_passpidfd`]: UnixSeqpacket::set_passpidfd
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passpidfd(&self) -> io::Result<bool> {
+        self.0.passpidfd()
+    }
+
+    /// Moves the socket to pass the security context of the sending socket in
+    /// [`SocketAncillary`] along with every message. The context is received as
+    /// [`AncillaryData::ScmSecurity`].
+    ///
+    /// Set the socket option `SO_PASSSEC`.
+    ///
+    /// [`AncillaryData::ScmSecurity`]: crate::os::unix::net::AncillaryData::ScmSecurity
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data, unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_passsec(true).expect("set_passsec function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passsec(&self, passsec: bool) -> io::Result<()> {
+        self.0.set_passsec(passsec)
+    }
+
+    /// Get the current value of the socket for passing the security context in
+    /// [`SocketAncillary`]. This value can be change by [`set_passsec`].
+    ///
+    /// Get the socket option `SO_PASSSEC`.
+    ///
+    /// [`set_passsec`]: UnixSeqpacket::set_passsec
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passsec(&self) -> io::Result<bool> {
+        self.0.passsec()
+    }
+
+    /// Moves the socket to pass the time at which each message was received in
+    /// [`SocketAncillary`]. The time is received as [`AncillaryData::ScmTimestamp`].
+    ///
+    /// Set the socket option `SO_TIMESTAMPNS`.
+    ///
+    /// [`AncillaryData::ScmTimestamp`]: crate::os::unix::net::AncillaryData::ScmTimestamp
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data, unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_timestamp(true).expect("set_timestamp function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_timestamp(&self, timestamp: bool) -> io::Result<()> {
+        self.0.set_timestamp(timestamp)
+    }
+
+    /// Get the current value of the socket for passing the receive time in [`SocketAncillary`].
+    /// This value can be change by [`set_timestamp`].
+    ///
+    /// Get the socket option `SO_TIMESTAMPNS`.
+    ///
+    /// [`set_timestamp`]: UnixSeqpacket::set_timestamp
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn timestamp(&self) -> io::Result<bool> {
+        self.0.timestamp()
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     if let Ok(Some(err)) = socket.take_error() {
+    ///         println!("Got error: {err:?}");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Shuts down the read, write, or both halves of this connection.
+    ///
+    /// This function will cause all pending and future I/O calls on the specified portions to
+    /// immediately return with an appropriate value (see the documentation of [`Shutdown`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    /// use std::net::Shutdown;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.shutdown(Shutdown::Both).expect("shutdown function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        self.0.shutdown(how)
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl AsRawFd for UnixSeqpacket {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_inner().as_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl FromRawFd for UnixSeqpacket {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixSeqpacket {
+        UnixSeqpacket(Socket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd))))
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl IntoRawFd for UnixSeqpacket {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_inner().into_inner().into_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl AsFd for UnixSeqpacket {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_inner().as_fd()
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl From<OwnedFd> for UnixSeqpacket {
+    #[inline]
+    fn from(fd: OwnedFd) -> UnixSeqpacket {
+        UnixSeqpacket(Socket::from_inner(FromInner::from_inner(fd)))
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl From<UnixSeqpacket> for OwnedFd {
+    #[inline]
+    fn from(socket: UnixSeqpacket) -> OwnedFd {
+        socket.0.into_inner().into_inner()
+    }
+}
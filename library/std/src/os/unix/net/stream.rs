@@ -250,6 +250,62 @@ pub fn peer_cred(&self) -> io::Result<UCred> {
         ucred::peer_cred(self)
     }
 
+    /// Returns a pidfd referring to the peer process of this connection.
+    ///
+    /// The pidfd refers to the process which connected or created the socket pair, and, unlike
+    /// the PID in [`peer_cred`], can't be recycled to refer to another process. It can be
+    /// waited on or signaled through [`PidFd`].
+    ///
+    /// Get the socket option `SO_PEERPIDFD`, available since Linux 6.5.
+    ///
+    /// [`peer_cred`]: UnixStream::peer_cred
+    /// [`PidFd`]: crate::os::linux::process::PidFd
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(peer_credentials_unix_socket)]
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixStream::connect("/tmp/sock")?;
+    ///     let pidfd = sock.peer_pidfd().expect("Couldn't get peer pidfd");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "peer_credentials_unix_socket", issue = "42839", reason = "unstable")]
+    pub fn peer_pidfd(&self) -> io::Result<OwnedFd> {
+        self.0.peer_pidfd()
+    }
+
+    /// Returns the security context of the peer socket of this connection, as defined by the
+    /// security module in use (e.g. SELinux).
+    ///
+    /// Get the socket option `SO_PEERSEC`. It fails with `ENOPROTOOPT` if no security module
+    /// provides a context.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(peer_credentials_unix_socket)]
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixStream::connect("/tmp/sock")?;
+    ///     let context = sock.peer_security_context()?;
+    ///     println!("peer context: {}", String::from_utf8_lossy(&context));
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "peer_credentials_unix_socket", issue = "42839", reason = "unstable")]
+    pub fn peer_security_context(&self) -> io::Result<Vec<u8>> {
+        self.0.peer_sec()
+    }
+
     /// Sets the read timeout for the socket.
     ///
     /// If the provided value is [`None`], then [`read`] calls will block
@@ -457,6 +513,83 @@ pub fn passcred(&self) -> io::Result<bool> {
         self.0.passcred()
     }
 
+    /// Moves the socket to pass a pidfd of the sending process in [`SocketAncillary`] along
+    /// with every message. The pidfd is received as [`AncillaryData::ScmPidfd`].
+    ///
+    /// Set the socket option `SO_PASSPIDFD`, available since Linux 6.5.
+    ///
+    /// [`AncillaryData::ScmPidfd`]: crate::os::unix::net::AncillaryData::ScmPidfd
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixStream::connect("/tmp/sock")?;
+    ///     sock.set_passpidfd(true).expect("set_passpidfd function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passpidfd(&self, passpidfd: bool) -> io::Result<()> {
+        self.0.set_passpidfd(passpidfd)
+    }
+
+    /// Get the current value of the socket for passing a pidfd in [`SocketAncillary`].
+    /// This value can be change by [`set_passpidfd`].
+    ///
+    /// Get the socket option `SO_PASSPIDFD`.
+    ///
+    /// [`set_passpidfd`]: UnixStream::set_passpidfd
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passpidfd(&self) -> io::Result<bool> {
+        self.0.passpidfd()
+    }
+
+    /// Moves the socket to pass the security context of the sending socket in
+    /// [`SocketAncillary`] along with every message. The context is received as
+    /// [`AncillaryData::ScmSecurity`].
+    ///
+    /// Set the socket option `SO_PASSSEC`.
+    ///
+    /// [`AncillaryData::ScmSecurity`]: crate::os::unix::net::AncillaryData::ScmSecurity
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let sock = UnixStream::connect("/tmp/sock")?;
+    ///     sock.set_passsec(true).expect("set_passsec function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passsec(&self, passsec: bool) -> io::Result<()> {
+        self.0.set_passsec(passsec)
+    }
+
+    /// Get the current value of the socket for passing the security context in
+    /// [`SocketAncillary`]. This value can be change by [`set_passsec`].
+    ///
+    /// Get the socket option `SO_PASSSEC`.
+    ///
+    /// [`set_passsec`]: UnixStream::set_passsec
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passsec(&self) -> io::Result<bool> {
+        self.0.passsec()
+    }
+
     /// Set the id of the socket for network filtering purpose
     ///
     #[cfg_attr(
@@ -776,3 +776,110 @@ fn test_send_vectored_with_ancillary_unix_datagram() {
         unreachable!("must be ScmRights");
     }
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+#[cfg_attr(target_os = "android", ignore)] // Android SELinux rules prevent creating Unix sockets
+fn seqpacket_basic() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+
+    let listener = or_panic!(UnixSeqpacketListener::bind(&socket_path));
+    let thread = thread::spawn(move || {
+        let socket = or_panic!(listener.accept()).0;
+        let mut buf = [0; 16];
+        let len = or_panic!(socket.recv(&mut buf));
+        assert_eq!(&buf[..len], b"hello");
+        let len = or_panic!(socket.recv(&mut buf));
+        assert_eq!(&buf[..len], b"world!");
+        or_panic!(socket.send(b"bye"));
+    });
+
+    let socket = or_panic!(UnixSeqpacket::connect(&socket_path));
+    assert_eq!(Some(&*socket_path), socket.peer_addr().unwrap().as_pathname());
+    // Message boundaries are kept, even if both messages fit in one receive buffer.
+    or_panic!(socket.send(b"hello"));
+    or_panic!(socket.send(b"world!"));
+    let mut buf = [0; 16];
+    let len = or_panic!(socket.recv(&mut buf));
+    assert_eq!(&buf[..len], b"bye");
+    thread.join().unwrap();
+
+    // The peer has closed its end.
+    assert_eq!(or_panic!(socket.recv(&mut buf)), 0);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+#[cfg_attr(target_os = "android", ignore)] // Android SELinux rules prevent creating Unix sockets
+fn seqpacket_truncated() {
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+    or_panic!(s1.send(b"0123456789"));
+    or_panic!(s1.send(b"abc"));
+
+    let mut buf = [0; 4];
+    assert_eq!(or_panic!(s2.peek(&mut buf)), 4);
+    let mut ancillary_buffer = [0; 128];
+    let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    let (len, truncated) = or_panic!(
+        s2.recv_vectored_with_ancillary(&mut [IoSliceMut::new(&mut buf)], &mut ancillary)
+    );
+    assert_eq!(len, 4);
+    assert!(truncated);
+    assert!(ancillary.data_truncated());
+    assert_eq!(&buf, b"0123");
+
+    // The rest of the first message was discarded.
+    let (len, truncated) = or_panic!(
+        s2.recv_vectored_with_ancillary(&mut [IoSliceMut::new(&mut buf)], &mut ancillary)
+    );
+    assert_eq!(len, 3);
+    assert!(!truncated);
+    assert!(!ancillary.data_truncated());
+    assert_eq!(&buf[..3], b"abc");
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+#[cfg_attr(target_os = "android", ignore)] // Android SELinux rules prevent creating Unix sockets
+fn seqpacket_ancillary_credentials_and_timestamp() {
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+    or_panic!(s2.set_passcred(true));
+    or_panic!(s2.set_timestamp(true));
+    assert!(or_panic!(s2.passcred()));
+    assert!(or_panic!(s2.timestamp()));
+
+    let cred = or_panic!(s2.peer_cred());
+    assert_eq!(cred.pid, Some(unsafe { libc::getpid() }));
+
+    let before = crate::time::SystemTime::now();
+    or_panic!(s1.send(b"hello"));
+
+    let mut buf = [0; 8];
+    let mut ancillary_buffer = [0; 128];
+    let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    let (len, truncated) = or_panic!(
+        s2.recv_vectored_with_ancillary(&mut [IoSliceMut::new(&mut buf)], &mut ancillary)
+    );
+    assert_eq!(len, 5);
+    assert!(!truncated);
+
+    let mut got_creds = false;
+    let mut got_timestamp = false;
+    for data in ancillary.messages() {
+        match data.unwrap() {
+            AncillaryData::ScmCredentials(creds) => {
+                let creds = Vec::from_iter(creds);
+                assert_eq!(creds.len(), 1);
+                assert_eq!(creds[0].get_pid(), cred.pid.unwrap());
+                got_creds = true;
+            }
+            AncillaryData::ScmTimestamp(time) => {
+                assert!(time >= before);
+                got_timestamp = true;
+            }
+            _ => unreachable!("must be ScmCredentials or ScmTimestamp"),
+        }
+    }
+    assert!(got_creds && got_timestamp);
+}
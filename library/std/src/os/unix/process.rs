@@ -187,6 +187,41 @@ fn arg0<S>(&mut self, arg: S) -> &mut process::Command
     /// ```
     #[stable(feature = "process_set_process_group", since = "1.64.0")]
     fn process_group(&mut self, pgroup: i32) -> &mut process::Command;
+
+    /// Sets whether the child process should be started in a new session.
+    /// Equivalent to a `setsid` call in the child process.
+    ///
+    /// The child becomes the leader of both a new session and a new process
+    /// group, whose IDs are equal to its process ID, and it is detached from
+    /// the controlling terminal of the parent. This makes sure that signals
+    /// from the terminal never reach the child or its descendants, and that
+    /// the whole tree can be signaled with [`ChildExt::signal_process_group`].
+    ///
+    /// As a session leader cannot change its process group, this cannot be
+    /// combined with [`process_group`]; spawning the command will fail.
+    ///
+    /// Spawning the command also fails, with [`io::ErrorKind::Unsupported`], on
+    /// platforms without sessions, such as Fuchsia and VxWorks.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_setsid)]
+    /// use std::process::Command;
+    /// use std::os::unix::process::CommandExt;
+    ///
+    /// Command::new("sleep")
+    ///     .arg("10")
+    ///     .setsid(true)
+    ///     .spawn()?
+    ///     .wait()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`process_group`]: CommandExt::process_group
+    #[unstable(feature = "process_setsid", issue = "105376")]
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command;
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -232,6 +267,76 @@ fn process_group(&mut self, pgroup: i32) -> &mut process::Command {
         self.as_inner_mut().pgroup(pgroup);
         self
     }
+
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command {
+        self.as_inner_mut().setsid(setsid);
+        self
+    }
+}
+
+/// Unix-specific extensions to [`process::Child`].
+///
+/// This trait is sealed: it cannot be implemented outside the standard library.
+/// This is so that future additional methods are not breaking changes.
+#[unstable(feature = "process_group_kill", issue = "none")]
+pub trait ChildExt: Sealed {
+    /// Sends the signal `signal` to every process in the process group of the
+    /// child.
+    ///
+    /// This reaches the descendants of the child too, as long as they have
+    /// not moved to another process group, which makes it possible to stop
+    /// a whole tree of processes at once. The child should have been put into
+    /// a process group of its own with [`CommandExt::process_group`] or
+    /// [`CommandExt::setsid`]. If it is still in the process group of the
+    /// current process, an [`InvalidInput`] error is returned instead of
+    /// signaling the current process as well.
+    ///
+    /// If the child has already been waited for, its process group ID may
+    /// have been reused by an unrelated process group, so this does nothing
+    /// and returns `Ok(())`, like [`Child::kill`]. Remaining processes in the
+    /// group should therefore be signaled before waiting for the child.
+    ///
+    /// [`InvalidInput`]: io::ErrorKind::InvalidInput
+    /// [`Child::kill`]: process::Child::kill
+    fn signal_process_group(&mut self, signal: i32) -> io::Result<()>;
+
+    /// Forces every process in the process group of the child to exit, by
+    /// sending `SIGKILL` to the group.
+    ///
+    /// See [`signal_process_group`] for the requirements.
+    ///
+    /// # Examples
+    ///
+    /// Running a command with a timeout, and stopping everything it started
+    /// once the timeout elapses:
+    ///
+    /// ```no_run
+    /// #![feature(child_wait_timeout, process_group_kill)]
+    /// use std::os::unix::process::{ChildExt, CommandExt};
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let mut child = Command::new("make").process_group(0).spawn()?;
+    /// if child.wait_timeout(Duration::from_secs(600))?.is_none() {
+    ///     child.kill_process_group()?;
+    ///     child.wait()?;
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [`signal_process_group`]: ChildExt::signal_process_group
+    fn kill_process_group(&mut self) -> io::Result<()>;
+}
+
+#[unstable(feature = "process_group_kill", issue = "none")]
+impl ChildExt for process::Child {
+    fn signal_process_group(&mut self, signal: i32) -> io::Result<()> {
+        self.handle.signal_process_group(signal)
+    }
+
+    fn kill_process_group(&mut self) -> io::Result<()> {
+        self.signal_process_group(libc::SIGKILL)
+    }
 }
 
 /// Unix-specific extensions to [`process::ExitStatus`] and
@@ -43,11 +43,11 @@ pub struct UCred {
 pub mod impl_linux {
     use super::UCred;
     use crate::os::unix::io::AsRawFd;
-    use crate::os::unix::net::UnixStream;
     use crate::{io, mem};
     use libc::{c_void, getsockopt, socklen_t, ucred, SOL_SOCKET, SO_PEERCRED};
 
-    pub fn peer_cred(socket: &UnixStream) -> io::Result<UCred> {
+    // Generic so that it also serves `UnixSeqpacket`, which only exists on these platforms.
+    pub fn peer_cred<S: AsRawFd>(socket: &S) -> io::Result<UCred> {
         let ucred_size = mem::size_of::<ucred>();
 
         // Trivial sanity checks.
@@ -119,6 +119,7 @@
 #[stable(feature = "command_access", since = "1.57.0")]
 pub use crate::sys_common::process::CommandEnvs;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use crate::time::Duration;
 
 /// Representation of a running or exited child process.
 ///
@@ -2032,6 +2033,54 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         Ok(self.handle.try_wait()?.map(ExitStatus))
     }
 
+    /// Waits for the child to exit for at most `timeout`, returning the status
+    /// that it exited with.
+    ///
+    /// If the child exits before the timeout elapses, then `Ok(Some(status))`
+    /// is returned, and on Unix the process ID is reaped, as with [`wait`]. If
+    /// it is still running once the timeout has elapsed, then `Ok(None)` is
+    /// returned, and the child is left running.
+    ///
+    /// The stdin handle to the child process, if any, will be closed
+    /// before waiting. This helps avoid deadlock: it ensures that the
+    /// child does not block waiting for input from the parent, while
+    /// the parent waits for the child to exit.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This currently uses `WaitForSingleObject` on Windows, and a pidfd on
+    /// Linux 5.3 or later. On other Unix platforms, it checks whether the child
+    /// has exited at increasing intervals of up to 50 milliseconds, so it may
+    /// return a bit later than the child exited.
+    ///
+    /// # Examples
+    ///
+    /// Killing a command that takes too long:
+    ///
+    /// ```no_run
+    /// #![feature(child_wait_timeout)]
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let mut child = Command::new("sleep").arg("60").spawn()?;
+    /// match child.wait_timeout(Duration::from_secs(5))? {
+    ///     Some(status) => println!("exited with: {status}"),
+    ///     None => {
+    ///         child.kill()?;
+    ///         child.wait()?;
+    ///         println!("killed after timing out");
+    ///     }
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [`wait`]: Child::wait
+    #[unstable(feature = "child_wait_timeout", issue = "none")]
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        drop(self.stdin.take());
+        Ok(self.handle.wait_timeout(timeout)?.map(ExitStatus))
+    }
+
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning an `Output`
     /// instance.
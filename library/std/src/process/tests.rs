@@ -192,6 +192,29 @@ fn test_process_output_output() {
     assert_eq!(stderr, Vec::new());
 }
 
+#[test]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn test_wait_timeout() {
+    use crate::time::Duration;
+
+    let mut prog = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "ping -n 60 127.0.0.1 > NUL"]).spawn().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("sleep 60").spawn().unwrap()
+    };
+    assert!(prog.wait_timeout(Duration::from_millis(10)).unwrap().is_none());
+    prog.kill().unwrap();
+    assert!(prog.wait_timeout(Duration::from_secs(60)).unwrap().is_some());
+
+    let mut prog = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "exit 1"]).spawn().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("false").spawn().unwrap()
+    };
+    assert_eq!(prog.wait_timeout(Duration::from_secs(60)).unwrap().unwrap().code(), Some(1));
+    assert_eq!(prog.wait_timeout(Duration::ZERO).unwrap().unwrap().code(), Some(1));
+}
+
 #[test]
 #[cfg_attr(any(target_os = "vxworks"), ignore)]
 fn test_process_output_error() {
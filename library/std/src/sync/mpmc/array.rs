@@ -409,6 +409,36 @@ pub(crate) fn recv(&self, deadline: Option<Instant>) -> Result<T, RecvTimeoutErr
         }
     }
 
+    /// Returns `true` if a receive operation would not block.
+    pub(crate) fn is_recv_ready(&self) -> bool {
+        !self.is_empty() || self.is_disconnected()
+    }
+
+    /// Registers `oper` to be selected once a receive operation may no longer block.
+    pub(crate) fn watch_recv(&self, oper: Operation, cx: &Context) {
+        self.receivers.watch(oper, cx);
+    }
+
+    /// Unregisters an operation previously registered with `watch_recv`.
+    pub(crate) fn unwatch_recv(&self, oper: Operation) {
+        self.receivers.unwatch(oper);
+    }
+
+    /// Returns `true` if a send operation would not block.
+    pub(crate) fn is_send_ready(&self) -> bool {
+        !self.is_full() || self.is_disconnected()
+    }
+
+    /// Registers `oper` to be selected once a send operation may no longer block.
+    pub(crate) fn watch_send(&self, oper: Operation, cx: &Context) {
+        self.senders.watch(oper, cx);
+    }
+
+    /// Unregisters an operation previously registered with `watch_send`.
+    pub(crate) fn unwatch_send(&self, oper: Operation) {
+        self.senders.unwatch(oper);
+    }
+
     /// Returns the current number of messages inside the channel.
     pub(crate) fn len(&self) -> usize {
         loop {
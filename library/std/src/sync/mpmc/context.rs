@@ -100,6 +100,12 @@ pub fn try_select(&self, select: Selected) -> Result<(), Selected> {
             .map_err(|e| e.into())
     }
 
+    /// Returns the selected operation.
+    #[inline]
+    pub fn selected(&self) -> Selected {
+        Selected::from(self.inner.select.load(Ordering::Acquire))
+    }
+
     /// Stores a packet.
     ///
     /// This method must be called after `try_select` succeeds and there is a packet to provide.
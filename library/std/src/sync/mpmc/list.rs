@@ -456,6 +456,27 @@ pub(crate) fn recv(&self, deadline: Option<Instant>) -> Result<T, RecvTimeoutErr
         }
     }
 
+    /// Returns `true` if a receive operation would not block.
+    pub(crate) fn is_recv_ready(&self) -> bool {
+        !self.is_empty() || self.is_disconnected()
+    }
+
+    /// Registers `oper` to be selected once a receive operation may no longer block.
+    pub(crate) fn watch_recv(&self, oper: Operation, cx: &Context) {
+        self.receivers.watch(oper, cx);
+    }
+
+    /// Unregisters an operation previously registered with `watch_recv`.
+    pub(crate) fn unwatch_recv(&self, oper: Operation) {
+        self.receivers.unwatch(oper);
+    }
+
+    /// Returns `true` if a send operation would not block, which is always the case as the
+    /// channel is unbounded.
+    pub(crate) fn is_send_ready(&self) -> bool {
+        true
+    }
+
     /// Returns the current number of messages inside the channel.
     pub(crate) fn len(&self) -> usize {
         loop {
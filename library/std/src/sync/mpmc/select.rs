@@ -1,3 +1,10 @@
+use super::context::Context;
+use super::error::{ReadyTimeoutError, TryReadyError};
+use super::{Receiver, ReceiverFlavor, Sender, SenderFlavor};
+use crate::fmt;
+use crate::thread;
+use crate::time::{Duration, Instant};
+
 /// Temporary data that gets initialized during a blocking operation, and is consumed by
 /// `read` or `write`.
 ///
@@ -69,3 +76,345 @@ fn into(self) -> usize {
         }
     }
 }
+
+/// A channel operation that can be waited on with a [`Select`].
+pub(crate) trait SelectHandle {
+    /// Returns `true` if the operation could proceed without blocking.
+    fn is_ready(&self) -> bool;
+
+    /// Registers `oper` to be selected once the operation may no longer block.
+    ///
+    /// Returns `true` if the operation is ready right away, in which case it must still be
+    /// unregistered with `unwatch`.
+    fn watch(&self, oper: Operation, cx: &Context) -> bool;
+
+    /// Unregisters an operation previously registered with `watch`.
+    fn unwatch(&self, oper: Operation);
+}
+
+impl<T> SelectHandle for Receiver<T> {
+    fn is_ready(&self) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.is_recv_ready(),
+            ReceiverFlavor::List(chan) => chan.is_recv_ready(),
+            ReceiverFlavor::Zero(chan) => chan.is_recv_ready(),
+        }
+    }
+
+    fn watch(&self, oper: Operation, cx: &Context) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.watch_recv(oper, cx),
+            ReceiverFlavor::List(chan) => chan.watch_recv(oper, cx),
+            ReceiverFlavor::Zero(chan) => chan.watch_recv(oper, cx),
+        }
+        // Check again, as the operation might have become ready before it was registered.
+        self.is_ready()
+    }
+
+    fn unwatch(&self, oper: Operation) {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.unwatch_recv(oper),
+            ReceiverFlavor::List(chan) => chan.unwatch_recv(oper),
+            ReceiverFlavor::Zero(chan) => chan.unwatch_recv(oper),
+        }
+    }
+}
+
+impl<T> SelectHandle for Sender<T> {
+    fn is_ready(&self) -> bool {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.is_send_ready(),
+            SenderFlavor::List(chan) => chan.is_send_ready(),
+            SenderFlavor::Zero(chan) => chan.is_send_ready(),
+        }
+    }
+
+    fn watch(&self, oper: Operation, cx: &Context) -> bool {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.watch_send(oper, cx),
+            // Sending into an unbounded channel never blocks.
+            SenderFlavor::List(_) => return true,
+            SenderFlavor::Zero(chan) => chan.watch_send(oper, cx),
+        }
+        // Check again, as the operation might have become ready before it was registered.
+        self.is_ready()
+    }
+
+    fn unwatch(&self, oper: Operation) {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.unwatch_send(oper),
+            SenderFlavor::List(_) => {}
+            SenderFlavor::Zero(chan) => chan.unwatch_send(oper),
+        }
+    }
+}
+
+/// How long a select operation may block.
+#[derive(Clone, Copy)]
+enum Timeout {
+    /// No blocking.
+    Now,
+
+    /// Block forever.
+    Never,
+
+    /// Block until the given instant.
+    At(Instant),
+}
+
+/// Waits until one of several channel operations becomes ready.
+///
+/// Operations are added with [`recv`] and [`send`], which return an index identifying the
+/// operation. [`ready`] and its variants then block until at least one of the operations could
+/// proceed without blocking, and return the index of such an operation. An operation is also
+/// ready when its channel is disconnected, as it would fail right away.
+///
+/// Being ready does not reserve anything: once an operation is reported as ready, the actual
+/// operation has to be performed with [`Receiver::try_recv`] or [`Sender::try_send`], which
+/// might still fail if another thread got there first, in which case selecting can simply be
+/// retried.
+///
+/// An operation on a zero-capacity channel is only ready while another thread is blocked on the
+/// opposite operation, so two threads can't pair up by both using `Select`.
+///
+/// [`recv`]: Select::recv
+/// [`send`]: Select::send
+/// [`ready`]: Select::ready
+///
+/// # Examples
+///
+/// ```
+/// #![feature(mpmc_channel)]
+///
+/// use std::sync::mpmc::{channel, Select, TryRecvError};
+/// use std::thread;
+///
+/// let (tx1, rx1) = channel();
+/// let (tx2, rx2) = channel();
+///
+/// thread::spawn(move || tx1.send(1).unwrap());
+/// thread::spawn(move || tx2.send(2).unwrap());
+///
+/// let mut sel = Select::new();
+/// let oper1 = sel.recv(&rx1);
+/// let oper2 = sel.recv(&rx2);
+///
+/// let mut received = Vec::new();
+/// while received.len() < 2 {
+///     let index = sel.ready();
+///     let msg = match index {
+///         i if i == oper1 => rx1.try_recv(),
+///         i if i == oper2 => rx2.try_recv(),
+///         _ => unreachable!(),
+///     };
+///     match msg {
+///         Ok(msg) => received.push(msg),
+///         Err(TryRecvError::Empty) => {}
+///         // The sending thread has already exited, stop waiting on this channel.
+///         Err(TryRecvError::Disconnected) => sel.remove(index),
+///     }
+/// }
+/// received.sort();
+/// assert_eq!(received, [1, 2]);
+/// ```
+#[unstable(feature = "mpmc_channel", issue = "none")]
+pub struct Select<'a> {
+    /// The operations and their indices.
+    handles: Vec<(&'a dyn SelectHandle, usize)>,
+
+    /// The index that will be given to the next operation.
+    next_index: usize,
+}
+
+impl<'a> Select<'a> {
+    /// Creates an empty list of channel operations.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn new() -> Select<'a> {
+        Select { handles: Vec::new(), next_index: 0 }
+    }
+
+    /// Adds a receive operation and returns its index.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn recv<T>(&mut self, r: &'a Receiver<T>) -> usize {
+        self.add(r)
+    }
+
+    /// Adds a send operation and returns its index.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn send<T>(&mut self, s: &'a Sender<T>) -> usize {
+        self.add(s)
+    }
+
+    fn add(&mut self, handle: &'a dyn SelectHandle) -> usize {
+        let index = self.next_index;
+        self.handles.push((handle, index));
+        self.next_index += 1;
+        index
+    }
+
+    /// Removes a previously added operation.
+    ///
+    /// This is useful once an operation's channel is disconnected, as the operation would
+    /// otherwise keep being reported as ready.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no operation with this index is in the list.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn remove(&mut self, index: usize) {
+        let pos = self
+            .handles
+            .iter()
+            .position(|&(_, i)| i == index)
+            .expect("no operation with this index in the list");
+        self.handles.swap_remove(pos);
+    }
+
+    /// Returns the index of an operation that is ready, without blocking.
+    ///
+    /// Returns an error if none of the operations is ready.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn try_ready(&mut self) -> Result<usize, TryReadyError> {
+        self.run_ready(Timeout::Now).ok_or(TryReadyError)
+    }
+
+    /// Blocks until one of the operations is ready and returns its index.
+    ///
+    /// If the list of operations is empty, this blocks forever.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready(&mut self) -> usize {
+        self.run_ready(Timeout::Never).unwrap()
+    }
+
+    /// Blocks for a limited time until one of the operations is ready and returns its index.
+    ///
+    /// Returns an error if none of the operations became ready in time.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready_timeout(&mut self, timeout: Duration) -> Result<usize, ReadyTimeoutError> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.ready_deadline(deadline),
+            // So far in the future that it's practically the same as waiting indefinitely.
+            None => Ok(self.ready()),
+        }
+    }
+
+    /// Blocks until a given deadline for one of the operations to be ready and returns its
+    /// index.
+    ///
+    /// Returns an error if none of the operations became ready in time.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
+    pub fn ready_deadline(&mut self, deadline: Instant) -> Result<usize, ReadyTimeoutError> {
+        self.run_ready(Timeout::At(deadline)).ok_or(ReadyTimeoutError)
+    }
+
+    fn run_ready(&mut self, timeout: Timeout) -> Option<usize> {
+        if self.handles.is_empty() {
+            // Nothing can ever become ready, so just wait for the timeout.
+            match timeout {
+                Timeout::Now => return None,
+                Timeout::Never => loop {
+                    thread::park();
+                },
+                Timeout::At(deadline) => {
+                    while let Some(timeout) = deadline.checked_duration_since(Instant::now()) {
+                        if timeout.is_zero() {
+                            break;
+                        }
+                        thread::sleep(timeout);
+                    }
+                    return None;
+                }
+            }
+        }
+
+        // Start with a different operation every time, so that the ones added first are not
+        // favored when several operations are ready.
+        self.handles.rotate_left(1);
+
+        loop {
+            if let Some(&(_, index)) = self.handles.iter().find(|(handle, _)| handle.is_ready()) {
+                return Some(index);
+            }
+
+            let deadline = match timeout {
+                Timeout::Now => return None,
+                Timeout::Never => None,
+                Timeout::At(deadline) if Instant::now() >= deadline => return None,
+                Timeout::At(deadline) => Some(deadline),
+            };
+
+            let handles = &mut self.handles;
+            let res = Context::with(|cx| {
+                let mut sel = Selected::Waiting;
+                let mut registered = 0;
+
+                for entry in handles.iter_mut() {
+                    registered += 1;
+                    let oper = Operation::hook(entry);
+                    if entry.0.watch(oper, cx) {
+                        // The operation is already ready, unless another one got selected in
+                        // the meantime.
+                        sel = match cx.try_select(Selected::Operation(oper)) {
+                            Ok(()) => Selected::Operation(oper),
+                            Err(s) => s,
+                        };
+                        break;
+                    }
+
+                    // If one of the operations registered so far is already selected, there is
+                    // no need to register the others.
+                    sel = cx.selected();
+                    if sel != Selected::Waiting {
+                        break;
+                    }
+                }
+
+                if sel == Selected::Waiting {
+                    sel = cx.wait_until(deadline);
+                }
+
+                for entry in handles.iter_mut().take(registered) {
+                    let oper = Operation::hook(entry);
+                    entry.0.unwatch(oper);
+                }
+
+                match sel {
+                    Selected::Operation(oper) => handles
+                        .iter_mut()
+                        .find_map(|entry| (Operation::hook(entry) == oper).then_some(entry.1)),
+                    // Timed out, or woken up spuriously.
+                    _ => None,
+                }
+            });
+
+            // The selected operation might not be ready anymore if another thread got there
+            // first, in which case start over.
+            if let Some(index) = res {
+                if self.handles.iter().any(|&(handle, i)| i == index && handle.is_ready()) {
+                    return Some(index);
+                }
+            }
+        }
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
+impl<'a> Clone for Select<'a> {
+    fn clone(&self) -> Select<'a> {
+        Select { handles: self.handles.clone(), next_index: self.next_index }
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
+impl<'a> Default for Select<'a> {
+    fn default() -> Select<'a> {
+        Select::new()
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
+impl fmt::Debug for Select<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("Select { .. }")
+    }
+}
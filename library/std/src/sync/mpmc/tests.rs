@@ -0,0 +1,128 @@
+use super::*;
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+#[test]
+fn smoke_cloned_receivers() {
+    let (tx, rx1) = channel::<i32>();
+    let rx2 = rx1.clone();
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    assert_eq!(rx2.recv().unwrap(), 1);
+    assert_eq!(rx1.recv().unwrap(), 2);
+    assert_eq!(rx1.try_recv(), Err(TryRecvError::Empty));
+    assert_eq!(rx2.try_recv(), Err(TryRecvError::Empty));
+}
+
+#[test]
+fn receivers_disconnect() {
+    let (tx, rx1) = sync_channel::<i32>(1);
+    let rx2 = rx1.clone();
+    drop(rx1);
+    tx.send(1).unwrap();
+    assert_eq!(rx2.recv().unwrap(), 1);
+    drop(rx2);
+    assert_eq!(tx.send(2), Err(SendError(2)));
+}
+
+#[test]
+fn each_message_received_once() {
+    for cap in [None, Some(0), Some(3)] {
+        let (tx, rx) = match cap {
+            None => channel::<usize>(),
+            Some(cap) => sync_channel::<usize>(cap),
+        };
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let rx = rx.clone();
+                thread::spawn(move || rx.iter().collect::<Vec<_>>())
+            })
+            .collect();
+        drop(rx);
+
+        for i in 0..1000 {
+            tx.send(i).unwrap();
+        }
+        drop(tx);
+
+        let mut received: Vec<_> = workers.into_iter().flat_map(|w| w.join().unwrap()).collect();
+        received.sort();
+        assert_eq!(received, (0..1000).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn select_recv() {
+    let (tx1, rx1) = channel::<i32>();
+    let (tx2, rx2) = sync_channel::<i32>(1);
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx1);
+    let oper2 = sel.recv(&rx2);
+    assert_eq!(sel.try_ready(), Err(TryReadyError));
+
+    tx2.send(2).unwrap();
+    assert_eq!(sel.try_ready(), Ok(oper2));
+    assert_eq!(rx2.try_recv(), Ok(2));
+
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        tx1.send(1).unwrap();
+    });
+    assert_eq!(sel.ready(), oper1);
+    assert_eq!(rx1.try_recv(), Ok(1));
+    t.join().unwrap();
+
+    // A disconnected channel is always ready.
+    assert_eq!(sel.ready(), oper1);
+    assert_eq!(rx1.try_recv(), Err(TryRecvError::Disconnected));
+    sel.remove(oper1);
+    assert_eq!(sel.ready_timeout(Duration::from_millis(10)), Err(ReadyTimeoutError));
+}
+
+#[test]
+fn select_send() {
+    let (tx1, rx1) = sync_channel::<i32>(1);
+    let (tx2, rx2) = sync_channel::<i32>(0);
+    tx1.send(1).unwrap();
+
+    let mut sel = Select::new();
+    let oper1 = sel.send(&tx1);
+    let oper2 = sel.send(&tx2);
+    assert_eq!(sel.try_ready(), Err(TryReadyError));
+
+    // A zero-capacity channel is ready to send into once a receiver is blocked on it.
+    let t = thread::spawn(move || rx2.recv().unwrap());
+    assert_eq!(sel.ready(), oper2);
+    loop {
+        match tx2.try_send(2) {
+            Ok(()) => break,
+            Err(TrySendError::Full(_)) => thread::yield_now(),
+            Err(TrySendError::Disconnected(_)) => panic!(),
+        }
+    }
+    assert_eq!(t.join().unwrap(), 2);
+
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        rx1.recv().unwrap()
+    });
+    sel.remove(oper2);
+    assert_eq!(sel.ready(), oper1);
+    assert_eq!(t.join().unwrap(), 1);
+}
+
+#[test]
+fn select_timeout() {
+    let (_tx, rx) = channel::<i32>();
+    let mut sel = Select::new();
+    sel.recv(&rx);
+
+    let start = Instant::now();
+    assert_eq!(sel.ready_timeout(Duration::from_millis(100)), Err(ReadyTimeoutError));
+    assert!(start.elapsed() >= Duration::from_millis(100));
+
+    let mut empty = Select::new();
+    assert_eq!(empty.try_ready(), Err(TryReadyError));
+    assert_eq!(empty.ready_deadline(Instant::now()), Err(ReadyTimeoutError));
+}
@@ -295,6 +295,40 @@ pub(crate) fn disconnect(&self) -> bool {
         }
     }
 
+    /// Returns `true` if a receive operation would not block, that is if a sender is currently
+    /// blocked on the channel or if the channel is disconnected.
+    pub(crate) fn is_recv_ready(&self) -> bool {
+        let inner = self.inner.lock().unwrap();
+        inner.senders.can_select() || inner.is_disconnected
+    }
+
+    /// Registers `oper` to be selected once a receive operation may no longer block.
+    pub(crate) fn watch_recv(&self, oper: Operation, cx: &Context) {
+        self.inner.lock().unwrap().receivers.watch(oper, cx);
+    }
+
+    /// Unregisters an operation previously registered with `watch_recv`.
+    pub(crate) fn unwatch_recv(&self, oper: Operation) {
+        self.inner.lock().unwrap().receivers.unwatch(oper);
+    }
+
+    /// Returns `true` if a send operation would not block, that is if a receiver is currently
+    /// blocked on the channel or if the channel is disconnected.
+    pub(crate) fn is_send_ready(&self) -> bool {
+        let inner = self.inner.lock().unwrap();
+        inner.receivers.can_select() || inner.is_disconnected
+    }
+
+    /// Registers `oper` to be selected once a send operation may no longer block.
+    pub(crate) fn watch_send(&self, oper: Operation, cx: &Context) {
+        self.inner.lock().unwrap().senders.watch(oper, cx);
+    }
+
+    /// Unregisters an operation previously registered with `watch_send`.
+    pub(crate) fn unwatch_send(&self, oper: Operation) {
+        self.inner.lock().unwrap().senders.unwatch(oper);
+    }
+
     /// Returns the current number of messages inside the channel.
     pub(crate) fn len(&self) -> usize {
         0
@@ -144,9 +144,8 @@
 mod sync_tests;
 
 // MPSC channels are built as a wrapper around MPMC channels, which
-// were ported from the `crossbeam-channel` crate. MPMC channels are
-// not exposed publicly, but if you are curious about the implementation,
-// that's where everything is.
+// were ported from the `crossbeam-channel` crate. If you are curious
+// about the implementation, that's where everything is.
 
 use crate::error;
 use crate::fmt;
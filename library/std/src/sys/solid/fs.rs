@@ -455,6 +455,36 @@ pub fn seek(&self, pos: SeekFrom) -> io::Result<u64> {
         }
     }
 
+    pub fn copy_range_to(
+        &self,
+        _offset: u64,
+        _len: u64,
+        _dest: &File,
+        _dest_offset: u64,
+    ) -> io::Result<u64> {
+        unsupported()
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn duplicate(&self) -> io::Result<File> {
         unsupported()
     }
@@ -1220,6 +1220,222 @@ pub fn seek(&self, pos: SeekFrom) -> io::Result<u64> {
         Ok(n as u64)
     }
 
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn copy_range_to(
+        &self,
+        offset: u64,
+        len: u64,
+        dest: &File,
+        dest_offset: u64,
+    ) -> io::Result<u64> {
+        use super::kernel_copy::{copy_regular_file_range, CopyResult};
+
+        let reader = self.as_raw_fd();
+        let writer = dest.as_raw_fd();
+        match copy_regular_file_range(reader, offset, writer, dest_offset, len) {
+            CopyResult::Ended(bytes) => Ok(bytes),
+            CopyResult::Error(e, _) => Err(e),
+            CopyResult::Fallback(written) => {
+                let copied = crate::sys_common::fs::copy_range(
+                    |buf, offset| self.read_at(buf, offset),
+                    |buf, offset| dest.write_at(buf, offset),
+                    offset + written,
+                    len - written,
+                    dest_offset + written,
+                )?;
+                Ok(copied + written)
+            }
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn copy_range_to(
+        &self,
+        offset: u64,
+        len: u64,
+        dest: &File,
+        dest_offset: u64,
+    ) -> io::Result<u64> {
+        crate::sys_common::fs::copy_range(
+            |buf, offset| self.read_at(buf, offset),
+            |buf, offset| dest.write_at(buf, offset),
+            offset,
+            len,
+            dest_offset,
+        )
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    ))]
+    pub fn lock(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), libc::LOCK_EX) })?;
+        Ok(())
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    )))]
+    pub fn lock(&self) -> io::Result<()> {
+        Err(io::const_io_error!(io::ErrorKind::Unsupported, "lock() not supported"))
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    ))]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), libc::LOCK_SH) })?;
+        Ok(())
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    )))]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        Err(io::const_io_error!(io::ErrorKind::Unsupported, "lock_shared() not supported"))
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    ))]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        match cvt(unsafe { libc::flock(self.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) }) {
+            Ok(_) => Ok(true),
+            Err(err) if err.raw_os_error() == Some(libc::EWOULDBLOCK) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    )))]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        Err(io::const_io_error!(io::ErrorKind::Unsupported, "try_lock() not supported"))
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    ))]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        match cvt(unsafe { libc::flock(self.as_raw_fd(), libc::LOCK_SH | libc::LOCK_NB) }) {
+            Ok(_) => Ok(true),
+            Err(err) if err.raw_os_error() == Some(libc::EWOULDBLOCK) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    )))]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        Err(io::const_io_error!(io::ErrorKind::Unsupported, "try_lock_shared() not supported"))
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    ))]
+    pub fn unlock(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), libc::LOCK_UN) })?;
+        Ok(())
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    )))]
+    pub fn unlock(&self) -> io::Result<()> {
+        Err(io::const_io_error!(io::ErrorKind::Unsupported, "unlock() not supported"))
+    }
+
     pub fn duplicate(&self) -> io::Result<File> {
         self.0.duplicate().map(File)
     }
@@ -1867,6 +2083,30 @@ pub fn lchown(path: &Path, uid: u32, gid: u32) -> io::Result<()> {
     })
 }
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn sendfile(reader: &File, offset: u64, len: u64, writer: c_int) -> io::Result<u64> {
+    use super::kernel_copy::{sendfile_range, CopyResult};
+
+    match sendfile_range(reader.as_raw_fd(), offset, writer, len) {
+        CopyResult::Ended(bytes) => Ok(bytes),
+        CopyResult::Error(e, _) => Err(e),
+        CopyResult::Fallback(written) => {
+            let copied = crate::sys_common::fs::copy_range(
+                |buf, offset| reader.read_at(buf, offset),
+                // The writer may be a socket or a pipe, which have no offsets to write at.
+                |buf, _| {
+                    let ret = cvt(unsafe { libc::write(writer, buf.as_ptr().cast(), buf.len()) })?;
+                    Ok(ret as usize)
+                },
+                offset + written,
+                len - written,
+                0,
+            )?;
+            Ok(copied + written)
+        }
+    }
+}
+
 #[cfg(not(any(target_os = "fuchsia", target_os = "vxworks")))]
 pub fn chroot(dir: &Path) -> io::Result<()> {
     run_path_with_cstr(dir, |dir| cvt(unsafe { libc::chroot(dir.as_ptr()) }).map(|_| ()))
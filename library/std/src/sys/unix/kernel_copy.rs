@@ -60,9 +60,9 @@
 use crate::sys::cvt;
 use crate::sys::weak::syscall;
 #[cfg(not(all(target_os = "linux", target_env = "gnu")))]
-use libc::sendfile as sendfile64;
+use libc::{off_t as sendfile_off_t, sendfile as sendfile64};
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
-use libc::sendfile64;
+use libc::{off64_t as sendfile_off_t, sendfile64};
 use libc::{EBADF, EINVAL, ENOSYS, EOPNOTSUPP, EOVERFLOW, EPERM, EXDEV};
 
 #[cfg(test)]
@@ -550,6 +550,40 @@ fn update_take(&self, reader: &mut impl CopyRead) {
 /// `Fallback` may indicate non-zero number of bytes already written
 /// if one of the files' cursor +`max_len` would exceed u64::MAX (`EOVERFLOW`).
 pub(super) fn copy_regular_files(reader: RawFd, writer: RawFd, max_len: u64) -> CopyResult {
+    copy_file_range_loop(reader, None, writer, None, max_len)
+}
+
+/// Like [`copy_regular_files`], but copies from and to explicit offsets instead of the files'
+/// cursors, which are left untouched.
+///
+/// `Fallback` may indicate a non-zero number of bytes already written, in which case the
+/// callers must resume the copy at the offsets advanced by that amount.
+pub(super) fn copy_regular_file_range(
+    reader: RawFd,
+    reader_offset: u64,
+    writer: RawFd,
+    writer_offset: u64,
+    len: u64,
+) -> CopyResult {
+    // Offsets which don't fit in a `loff_t` are rejected by the kernel with EINVAL,
+    // let the fallback report the error.
+    let (Ok(mut reader_offset), Ok(mut writer_offset)) =
+        (libc::loff_t::try_from(reader_offset), libc::loff_t::try_from(writer_offset))
+    else {
+        return CopyResult::Fallback(0);
+    };
+    copy_file_range_loop(reader, Some(&mut reader_offset), writer, Some(&mut writer_offset), len)
+}
+
+/// Calls copy_file_range in a loop, either from the files' cursors or, if offsets are given,
+/// from those offsets, which the kernel advances as data is copied.
+fn copy_file_range_loop(
+    reader: RawFd,
+    mut reader_offset: Option<&mut libc::loff_t>,
+    writer: RawFd,
+    mut writer_offset: Option<&mut libc::loff_t>,
+    max_len: u64,
+) -> CopyResult {
     use crate::cmp;
 
     const NOT_PROBED: u8 = 0;
@@ -598,10 +632,12 @@ fn copy_file_range(
         // this allows us to copy large chunks without hitting EOVERFLOW,
         // unless someone sets a file offset close to u64::MAX - 1GB, in which case a fallback would be required
         let bytes_to_copy = cmp::min(bytes_to_copy as usize, 0x4000_0000usize);
+        let off_in = reader_offset.as_deref_mut().map_or(ptr::null_mut(), |off| off as *mut _);
+        let off_out = writer_offset.as_deref_mut().map_or(ptr::null_mut(), |off| off as *mut _);
         let copy_result = unsafe {
             // We actually don't have to adjust the offsets,
             // because copy_file_range adjusts the file offset automatically
-            cvt(copy_file_range(reader, ptr::null_mut(), writer, ptr::null_mut(), bytes_to_copy, 0))
+            cvt(copy_file_range(reader, off_in, writer, off_out, bytes_to_copy, 0))
         };
 
         match copy_result {
@@ -645,6 +681,45 @@ fn copy_file_range(
     CopyResult::Ended(written)
 }
 
+/// Calls sendfile in a loop to send up to `len` bytes from `reader_offset` in `reader` to
+/// `writer`, leaving the cursor of `reader` untouched.
+///
+/// Like [`sendfile_splice`], this does _not_ fall back to a generic copy loop. `Fallback` may
+/// indicate a non-zero number of bytes already written, in which case the callers must resume
+/// the copy at the offset advanced by that amount.
+pub(super) fn sendfile_range(
+    reader: RawFd,
+    reader_offset: u64,
+    writer: RawFd,
+    len: u64,
+) -> CopyResult {
+    // Offsets which don't fit in an `off_t` are rejected by the kernel with EINVAL,
+    // let the fallback report the error.
+    let Ok(mut offset) = sendfile_off_t::try_from(reader_offset) else {
+        return CopyResult::Fallback(0);
+    };
+
+    let mut written = 0u64;
+    while written < len {
+        // according to its manpage that's the maximum size sendfile() will copy per invocation
+        let chunk_size = crate::cmp::min(len - written, 0x7ffff000_u64) as usize;
+        // The kernel advances `offset` by the number of bytes sent.
+        match cvt(unsafe { sendfile64(writer, reader, &mut offset, chunk_size) }) {
+            Ok(0) => break, // EOF
+            Ok(ret) => written += ret as u64,
+            Err(err) => {
+                return match err.raw_os_error() {
+                    // sendfile is not supported (ENOSYS), disallowed (EPERM), doesn't support
+                    // these file descriptors (EINVAL), or the offset would overflow (EOVERFLOW).
+                    Some(ENOSYS | EPERM | EINVAL | EOVERFLOW) => CopyResult::Fallback(written),
+                    _ => CopyResult::Error(err, written),
+                };
+            }
+        }
+    }
+    CopyResult::Ended(written)
+}
+
 #[derive(PartialEq)]
 enum SpliceMode {
     Sendfile,
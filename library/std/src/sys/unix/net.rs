@@ -29,6 +29,20 @@
 
 pub struct Socket(FileDesc);
 
+// Since Linux 6.5, not yet exported by the libc crate.
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        any(target_os = "android", target_os = "linux"),
+        any(target_arch = "sparc", target_arch = "sparc64")
+    ))] {
+        const SO_PASSPIDFD: c_int = 0x55;
+        const SO_PEERPIDFD: c_int = 0x56;
+    } else if #[cfg(any(target_os = "android", target_os = "linux"))] {
+        const SO_PASSPIDFD: c_int = 76;
+        const SO_PEERPIDFD: c_int = 77;
+    }
+}
+
 pub fn init() {}
 
 pub fn cvt_gai(err: c_int) -> io::Result<()> {
@@ -421,6 +435,87 @@ pub fn quickack(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_REUSEPORT, reuseport as c_int)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn reuseport(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_REUSEPORT)?;
+        Ok(raw != 0)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn set_bind_device(&self, device: Option<&[u8]>) -> io::Result<()> {
+        // An empty name removes the binding.
+        let device = device.unwrap_or(&[]);
+        cvt(unsafe {
+            libc::setsockopt(
+                self.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_BINDTODEVICE,
+                device.as_ptr() as *const c_void,
+                device.len() as libc::socklen_t,
+            )
+        })?;
+        Ok(())
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn bind_device(&self) -> io::Result<Option<Vec<u8>>> {
+        let mut buf = [0u8; libc::IFNAMSIZ];
+        let mut len = buf.len() as libc::socklen_t;
+        cvt(unsafe {
+            libc::getsockopt(
+                self.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_BINDTODEVICE,
+                buf.as_mut_ptr() as *mut c_void,
+                &mut len,
+            )
+        })?;
+        let name = &buf[..len as usize];
+        let name = name.split(|&b| b == 0).next().unwrap_or(name);
+        Ok(if name.is_empty() { None } else { Some(name.to_vec()) })
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()> {
+        let secs = cmp::min(idle.as_secs(), c_int::MAX as u64) as c_int;
+        setsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPIDLE, secs)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn keepalive_idle(&self) -> io::Result<Duration> {
+        let raw: c_int = getsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPIDLE)?;
+        Ok(Duration::from_secs(raw as u64))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        let secs = cmp::min(interval.as_secs(), c_int::MAX as u64) as c_int;
+        setsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPINTVL, secs)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        let raw: c_int = getsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPINTVL)?;
+        Ok(Duration::from_secs(raw as u64))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn set_keepalive_count(&self, count: u32) -> io::Result<()> {
+        let count = cmp::min(count, c_int::MAX as u32) as c_int;
+        setsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPCNT, count)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux",))]
+    pub fn keepalive_count(&self) -> io::Result<u32> {
+        let raw: c_int = getsockopt(self, libc::IPPROTO_TCP, libc::TCP_KEEPCNT)?;
+        Ok(raw as u32)
+    }
+
     #[cfg(any(target_os = "android", target_os = "linux",))]
     pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
         setsockopt(self, libc::SOL_SOCKET, libc::SO_PASSCRED, passcred as libc::c_int)
@@ -432,6 +527,77 @@ pub fn passcred(&self) -> io::Result<bool> {
         Ok(passcred != 0)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn set_passpidfd(&self, passpidfd: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_SOCKET, SO_PASSPIDFD, passpidfd as libc::c_int)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn passpidfd(&self) -> io::Result<bool> {
+        let passpidfd: libc::c_int = getsockopt(self, libc::SOL_SOCKET, SO_PASSPIDFD)?;
+        Ok(passpidfd != 0)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn set_passsec(&self, passsec: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_PASSSEC, passsec as libc::c_int)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn passsec(&self) -> io::Result<bool> {
+        let passsec: libc::c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_PASSSEC)?;
+        Ok(passsec != 0)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn set_timestamp(&self, timestamp: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_TIMESTAMPNS, timestamp as libc::c_int)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn timestamp(&self) -> io::Result<bool> {
+        let timestamp: libc::c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_TIMESTAMPNS)?;
+        Ok(timestamp != 0)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn peer_pidfd(&self) -> io::Result<crate::os::fd::OwnedFd> {
+        let pidfd: libc::c_int = getsockopt(self, libc::SOL_SOCKET, SO_PEERPIDFD)?;
+        Ok(unsafe { crate::os::fd::OwnedFd::from_raw_fd(pidfd) })
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn peer_sec(&self) -> io::Result<Vec<u8>> {
+        // Security contexts are usually short, but can be arbitrarily long. If the buffer is
+        // too small, the kernel fails with `ERANGE` and reports the required length.
+        let mut buf = Vec::with_capacity(256);
+        loop {
+            let mut len = buf.capacity() as libc::socklen_t;
+            let ret = unsafe {
+                libc::getsockopt(
+                    self.as_raw_fd(),
+                    libc::SOL_SOCKET,
+                    libc::SO_PEERSEC,
+                    buf.as_mut_ptr() as *mut c_void,
+                    &mut len,
+                )
+            };
+            if ret == 0 {
+                unsafe { buf.set_len(len as usize) };
+                // The context may or may not include a terminating nul byte.
+                if buf.last() == Some(&0) {
+                    buf.pop();
+                }
+                return Ok(buf);
+            }
+            let err = io::Error::last_os_error();
+            if err.raw_os_error() != Some(libc::ERANGE) || len as usize <= buf.capacity() {
+                return Err(err);
+            }
+            buf.reserve(len as usize);
+        }
+    }
+
     #[cfg(target_os = "netbsd")]
     pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
         setsockopt(self, 0 as libc::c_int, libc::LOCAL_CREDS, passcred as libc::c_int)
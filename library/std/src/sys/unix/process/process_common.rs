@@ -14,6 +14,11 @@
 use crate::sys::pipe::{self, AnonPipe};
 use crate::sys_common::process::{CommandEnv, CommandEnvs};
 use crate::sys_common::IntoInner;
+#[cfg(not(any(target_os = "espidf", target_os = "horizon", target_os = "vita")))]
+use crate::{
+    thread,
+    time::{Duration, Instant},
+};
 
 #[cfg(not(target_os = "fuchsia"))]
 use crate::sys::fs::OpenOptions;
@@ -107,6 +112,7 @@ pub struct Command {
     #[cfg(target_os = "linux")]
     create_pidfd: bool,
     pgroup: Option<pid_t>,
+    setsid: bool,
 }
 
 // Create a new type for argv, so that we can make it `Send` and `Sync`
@@ -197,6 +203,7 @@ pub fn new(program: &OsStr) -> Command {
             stdout: None,
             stderr: None,
             pgroup: None,
+            setsid: false,
         }
     }
 
@@ -222,6 +229,7 @@ pub fn new(program: &OsStr) -> Command {
             stderr: None,
             create_pidfd: false,
             pgroup: None,
+            setsid: false,
         }
     }
 
@@ -260,6 +268,9 @@ pub fn groups(&mut self, groups: &[gid_t]) {
     pub fn pgroup(&mut self, pgroup: pid_t) {
         self.pgroup = Some(pgroup);
     }
+    pub fn setsid(&mut self, setsid: bool) {
+        self.setsid = setsid;
+    }
 
     #[cfg(target_os = "linux")]
     pub fn create_pidfd(&mut self, val: bool) {
@@ -332,6 +343,17 @@ pub fn get_groups(&self) -> Option<&[gid_t]> {
     pub fn get_pgroup(&self) -> Option<pid_t> {
         self.pgroup
     }
+    // Only the generic Unix implementation supports starting a new session.
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "vxworks",
+        target_os = "espidf",
+        target_os = "horizon",
+        target_os = "vita"
+    )))]
+    pub fn get_setsid(&self) -> bool {
+        self.setsid
+    }
 
     pub fn get_closures(&mut self) -> &mut Vec<Box<dyn FnMut() -> io::Result<()> + Send + Sync>> {
         &mut self.closures
@@ -547,6 +569,9 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             if self.pgroup.is_some() {
                 debug_command.field("pgroup", &self.pgroup);
             }
+            if self.setsid {
+                debug_command.field("setsid", &self.setsid);
+            }
 
             #[cfg(target_os = "linux")]
             {
@@ -576,6 +601,37 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Waits for at most `timeout` for `try_wait` to return an exit status, by
+/// calling it repeatedly with exponentially increasing sleeps in between.
+///
+/// This is used where there is no way to block until a child exits or a
+/// timeout elapses, whichever comes first.
+#[cfg(not(any(target_os = "espidf", target_os = "horizon", target_os = "vita")))]
+pub(crate) fn wait_timeout_by_polling<T>(
+    timeout: Duration,
+    mut try_wait: impl FnMut() -> io::Result<Option<T>>,
+) -> io::Result<Option<T>> {
+    const MAX_SLEEP: Duration = Duration::from_millis(50);
+
+    // If the deadline can't be represented, we might as well wait forever.
+    let deadline = Instant::now().checked_add(timeout);
+    let mut sleep = Duration::from_millis(1);
+    loop {
+        if let Some(status) = try_wait()? {
+            return Ok(Some(status));
+        }
+        let remaining = match deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => MAX_SLEEP,
+        };
+        if remaining.is_zero() {
+            return Ok(None);
+        }
+        thread::sleep(sleep.min(remaining));
+        sleep = (sleep * 2).min(MAX_SLEEP);
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub struct ExitCode(u8);
 
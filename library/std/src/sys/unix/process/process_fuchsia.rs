@@ -3,6 +3,7 @@
 use crate::mem;
 use crate::num::{NonZeroI32, NonZeroI64};
 use crate::ptr;
+use crate::time::Duration;
 
 use crate::sys::process::process_common::*;
 use crate::sys::process::zircon::{zx_handle_t, Handle};
@@ -27,6 +28,12 @@ pub fn spawn(
                 "nul byte found in provided data",
             ));
         }
+        if self.get_setsid() {
+            return Err(io::const_io_error!(
+                io::ErrorKind::Unsupported,
+                "creating a new session is not supported on Fuchsia",
+            ));
+        }
 
         let (ours, theirs) = self.setup_io(default, needs_stdin)?;
 
@@ -233,6 +240,17 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         }
         Ok(Some(ExitStatus(proc_info.return_code)))
     }
+
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        wait_timeout_by_polling(timeout, || self.try_wait())
+    }
+
+    pub fn signal_process_group(&mut self, _signal: c_int) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "process groups are not supported on Fuchsia",
+        ))
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
@@ -5,6 +5,7 @@
 use crate::sys;
 use crate::sys::cvt;
 use crate::sys::process::process_common::*;
+use crate::time::Duration;
 use core::ffi::NonZero_c_int;
 
 #[cfg(target_os = "linux")]
@@ -42,7 +43,6 @@
     if #[cfg(all(target_os = "nto", target_env = "nto71"))] {
         use crate::thread;
         use libc::{c_char, posix_spawn_file_actions_t, posix_spawnattr_t};
-        use crate::time::Duration;
         use crate::sync::LazyLock;
         // Get smallest amount of time we can sleep.
         // Return a common value if it cannot be determined.
@@ -418,6 +418,10 @@ unsafe fn do_exec(
             cvt(libc::setpgid(0, pgroup))?;
         }
 
+        if self.get_setsid() {
+            cvt(libc::setsid())?;
+        }
+
         // emscripten has no signal support.
         #[cfg(not(target_os = "emscripten"))]
         {
@@ -526,6 +530,7 @@ fn posix_spawn(
             || !self.get_closures().is_empty()
             || self.get_groups().is_some()
             || self.get_create_pidfd()
+            || self.get_setsid()
         {
             return Ok(None);
         }
@@ -794,6 +799,49 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
             Ok(Some(ExitStatus::new(status)))
         }
     }
+
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        if let Some(status) = self.try_wait()? {
+            return Ok(Some(status));
+        }
+
+        // A pidfd becomes readable once the process has exited, so we can
+        // wait for that instead of polling. The child hasn't been reaped yet,
+        // so its PID can't have been reused and opening a pidfd is race-free.
+        #[cfg(target_os = "linux")]
+        {
+            let opened;
+            let pidfd = match self.pidfd {
+                Some(ref pidfd) => Some(pidfd),
+                None => {
+                    opened = PidFd::open(self.pid as u32).ok();
+                    opened.as_ref()
+                }
+            };
+            if let Some(pidfd) = pidfd {
+                return if pidfd.poll_exit(timeout)? { self.try_wait() } else { Ok(None) };
+            }
+        }
+
+        wait_timeout_by_polling(timeout, || self.try_wait())
+    }
+
+    pub fn signal_process_group(&mut self, signal: c_int) -> io::Result<()> {
+        // As in `kill`, the PID may have been reused by now if we've already
+        // waited on this process, and so may its process group ID.
+        if self.status.is_some() {
+            return Ok(());
+        }
+        let pgid = cvt(unsafe { libc::getpgid(self.pid) })?;
+        // Don't take down our own process group along with the child.
+        if pgid == unsafe { libc::getpgrp() } {
+            return Err(io::const_io_error!(
+                ErrorKind::InvalidInput,
+                "the child process is not in a process group of its own",
+            ));
+        }
+        cvt(unsafe { libc::kill(-pgid, signal) }).map(drop)
+    }
 }
 
 /// Unix exit statuses
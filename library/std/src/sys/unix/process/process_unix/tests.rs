@@ -60,3 +60,42 @@ fn test_command_fork_no_unwind() {
             || signal == libc::SIGSEGV
     );
 }
+
+#[test]
+#[cfg_attr(any(target_os = "emscripten", target_os = "vxworks"), ignore)]
+fn test_kill_process_group() {
+    use crate::io::{BufRead, BufReader, ErrorKind, Read};
+    use crate::os::unix::process::ChildExt;
+    use crate::process::Stdio;
+
+    let mut in_own_group = Command::new("sh");
+    in_own_group.process_group(0);
+    let mut in_own_session = Command::new("sh");
+    in_own_session.setsid(true);
+
+    for mut command in [in_own_group, in_own_session] {
+        // The background `sleep` keeps stdout open until it is killed as well.
+        let mut child = command
+            .args(["-c", "sleep 60 & echo started; wait"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut stdout = BufReader::new(child.stdout.take().unwrap());
+        let mut line = String::new();
+        stdout.read_line(&mut line).unwrap();
+        assert_eq!(line, "started\n");
+
+        child.kill_process_group().unwrap();
+        stdout.read_to_end(&mut Vec::new()).unwrap();
+        assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGKILL));
+        // The process group ID may have been reused now.
+        child.kill_process_group().unwrap();
+    }
+
+    // A child in our own process group must not take us down with it.
+    let mut child = Command::new("sleep").arg("60").spawn().unwrap();
+    let err = child.kill_process_group().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
@@ -5,6 +5,7 @@
 use crate::sys::cvt;
 use crate::sys::process::process_common::*;
 use crate::sys_common::thread;
+use crate::time::Duration;
 use core::ffi::NonZero_c_int;
 use libc::RTP_ID;
 use libc::{self, c_char, c_int};
@@ -28,6 +29,12 @@ pub fn spawn(
                 "nul byte found in provided data",
             ));
         }
+        if self.get_setsid() {
+            return Err(io::const_io_error!(
+                ErrorKind::Unsupported,
+                "creating a new session is not supported on VxWorks",
+            ));
+        }
         let (ours, theirs) = self.setup_io(default, needs_stdin)?;
         let mut p = Process { pid: 0, status: None };
 
@@ -176,6 +183,17 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
             Ok(Some(ExitStatus::new(status)))
         }
     }
+
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        wait_timeout_by_polling(timeout, || self.try_wait())
+    }
+
+    pub fn signal_process_group(&mut self, _signal: c_int) -> io::Result<()> {
+        Err(io::const_io_error!(
+            ErrorKind::Unsupported,
+            "process groups are not supported on VxWorks",
+        ))
+    }
 }
 
 /// Unix exit statuses
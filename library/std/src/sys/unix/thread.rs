@@ -7,6 +7,10 @@
 use crate::sys::{os, stack_overflow};
 use crate::time::Duration;
 
+#[cfg(target_os = "linux")]
+use crate::os::linux::thread::SchedPolicy;
+#[cfg(target_os = "linux")]
+use crate::sys::cvt;
 #[cfg(all(target_os = "linux", target_env = "gnu"))]
 use crate::sys::weak::dlsym;
 #[cfg(any(target_os = "solaris", target_os = "illumos", target_os = "nto"))]
@@ -299,6 +303,71 @@ fn drop(&mut self) {
     result
 }
 
+/// Scheduling options set through `os::linux::thread::BuilderExt`.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Default, Clone)]
+pub struct SchedOptions {
+    pub affinity: Option<Vec<usize>>,
+    pub nice: Option<i32>,
+    pub policy: Option<SchedPolicy>,
+}
+
+#[cfg(target_os = "linux")]
+impl SchedOptions {
+    pub fn is_empty(&self) -> bool {
+        self.affinity.is_none() && self.nice.is_none() && self.policy.is_none()
+    }
+
+    /// Applies the options to the current thread.
+    pub fn apply(&self) -> io::Result<()> {
+        if let Some(cpus) = &self.affinity {
+            let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+            for &cpu in cpus {
+                if cpu >= libc::CPU_SETSIZE as usize {
+                    return Err(io::const_io_error!(
+                        io::ErrorKind::InvalidInput,
+                        "CPU index out of range of the affinity mask",
+                    ));
+                }
+                unsafe { libc::CPU_SET(cpu, &mut set) };
+            }
+            cvt(unsafe { libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set) })?;
+        }
+
+        if let Some(policy) = self.policy {
+            let (policy, priority) = match policy {
+                SchedPolicy::Other => (libc::SCHED_OTHER, 0),
+                SchedPolicy::Batch => (libc::SCHED_BATCH, 0),
+                SchedPolicy::Idle => (libc::SCHED_IDLE, 0),
+                SchedPolicy::Fifo(priority) => (libc::SCHED_FIFO, priority),
+                SchedPolicy::RoundRobin(priority) => (libc::SCHED_RR, priority),
+            };
+            // Some libcs have additional fields for policies Linux doesn't support.
+            let mut param: libc::sched_param = unsafe { mem::zeroed() };
+            param.sched_priority = priority;
+            cvt(unsafe { libc::sched_setscheduler(0, policy, &param) })?;
+        }
+
+        if let Some(nice) = self.nice {
+            // Unlike what POSIX specifies, the nice value is a property of each thread on Linux,
+            // which is selected by passing its thread ID.
+            let tid = unsafe { libc::syscall(libc::SYS_gettid) } as libc::id_t;
+            cvt(unsafe { libc::setpriority(libc::PRIO_PROCESS, tid, nice) })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn current_affinity() -> io::Result<Vec<usize>> {
+    let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+    cvt(unsafe { libc::sched_getaffinity(0, mem::size_of::<libc::cpu_set_t>(), &mut set) })?;
+    Ok((0..libc::CPU_SETSIZE as usize)
+        .filter(|&cpu| unsafe { libc::CPU_ISSET(cpu, &set) })
+        .collect())
+}
+
 pub fn available_parallelism() -> io::Result<NonZeroUsize> {
     cfg_if::cfg_if! {
         if #[cfg(any(
@@ -238,6 +238,36 @@ pub fn seek(&self, _pos: SeekFrom) -> io::Result<u64> {
         self.0
     }
 
+    pub fn copy_range_to(
+        &self,
+        _offset: u64,
+        _len: u64,
+        _dest: &File,
+        _dest_offset: u64,
+    ) -> io::Result<u64> {
+        self.0
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.0
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.0
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.0
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.0
+    }
+
     pub fn duplicate(&self) -> io::Result<File> {
         self.0
     }
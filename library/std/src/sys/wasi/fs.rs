@@ -465,6 +465,42 @@ pub fn seek(&self, pos: SeekFrom) -> io::Result<u64> {
         self.fd.seek(pos)
     }
 
+    pub fn copy_range_to(
+        &self,
+        offset: u64,
+        len: u64,
+        dest: &File,
+        dest_offset: u64,
+    ) -> io::Result<u64> {
+        crate::sys_common::fs::copy_range(
+            |buf, offset| self.fd.pread(&mut [IoSliceMut::new(buf)], offset),
+            |buf, offset| dest.fd.pwrite(&[IoSlice::new(buf)], offset),
+            offset,
+            len,
+            dest_offset,
+        )
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        unsupported()
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        unsupported()
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn duplicate(&self) -> io::Result<File> {
         // https://github.com/CraneStation/wasmtime/blob/master/docs/WASI-rationale.md#why-no-dup
         unsupported()
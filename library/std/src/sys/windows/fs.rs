@@ -476,6 +476,87 @@ pub fn seek(&self, pos: SeekFrom) -> io::Result<u64> {
         Ok(newpos as u64)
     }
 
+    pub fn copy_range_to(
+        &self,
+        offset: u64,
+        len: u64,
+        dest: &File,
+        dest_offset: u64,
+    ) -> io::Result<u64> {
+        crate::sys_common::fs::copy_range(
+            |buf, offset| self.read_at(buf, offset),
+            |buf, offset| dest.write_at(buf, offset),
+            offset,
+            len,
+            dest_offset,
+        )
+    }
+
+    fn acquire_lock(&self, flags: c::LOCK_FILE_FLAGS) -> io::Result<()> {
+        unsafe {
+            // Lock the whole file, however large it may grow.
+            let mut overlapped: c::OVERLAPPED = mem::zeroed();
+            let result = cvt(c::LockFileEx(
+                self.handle.as_raw_handle(),
+                flags,
+                0,
+                u32::MAX,
+                u32::MAX,
+                &mut overlapped,
+            ));
+            match result {
+                // Handles opened for asynchronous I/O report a lock which isn't acquired yet as
+                // a pending operation, wait for it to complete.
+                Err(err) if err.raw_os_error() == Some(c::ERROR_IO_PENDING as i32) => {
+                    let mut transferred = 0;
+                    cvt(c::GetOverlappedResult(
+                        self.handle.as_raw_handle(),
+                        &overlapped,
+                        &mut transferred,
+                        c::TRUE,
+                    ))
+                    .map(drop)
+                }
+                result => result.map(drop),
+            }
+        }
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        self.acquire_lock(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.acquire_lock(0)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        match self.acquire_lock(c::LOCKFILE_EXCLUSIVE_LOCK | c::LOCKFILE_FAIL_IMMEDIATELY) {
+            Ok(()) => Ok(true),
+            Err(err) if err.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        match self.acquire_lock(c::LOCKFILE_FAIL_IMMEDIATELY) {
+            Ok(()) => Ok(true),
+            Err(err) if err.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        // A handle may hold both a shared and an exclusive lock on the file, which are released
+        // one at a time.
+        cvt(unsafe { c::UnlockFile(self.handle.as_raw_handle(), 0, 0, u32::MAX, u32::MAX) })?;
+        match cvt(unsafe { c::UnlockFile(self.handle.as_raw_handle(), 0, 0, u32::MAX, u32::MAX) }) {
+            Ok(_) => Ok(()),
+            Err(err) if err.raw_os_error() == Some(c::ERROR_NOT_LOCKED as i32) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
     pub fn duplicate(&self) -> io::Result<File> {
         Ok(Self { handle: self.handle.try_clone()? })
     }
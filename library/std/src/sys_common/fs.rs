@@ -1,8 +1,10 @@
 #![allow(dead_code)] // not used on all platforms
 
+use crate::cmp;
 use crate::fs;
 use crate::io::{self, Error, ErrorKind};
 use crate::path::Path;
+use crate::sys_common::io::DEFAULT_BUF_SIZE;
 
 pub(crate) const NOT_FILE_ERROR: Error = io::const_io_error!(
     ErrorKind::InvalidInput,
@@ -25,6 +27,49 @@ pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
     Ok(ret)
 }
 
+/// Copies up to `len` bytes from `offset` in a file to `dest_offset` in another file, through
+/// a buffer, using the given positional read and write functions.
+///
+/// The copy stops early when the end of the source file is reached. Returns the number of
+/// bytes copied.
+pub fn copy_range(
+    read_at: impl Fn(&mut [u8], u64) -> io::Result<usize>,
+    write_at: impl Fn(&[u8], u64) -> io::Result<usize>,
+    offset: u64,
+    len: u64,
+    dest_offset: u64,
+) -> io::Result<u64> {
+    let mut buf = [0; DEFAULT_BUF_SIZE];
+    let mut copied = 0;
+    while copied < len {
+        let to_read = cmp::min(len - copied, buf.len() as u64) as usize;
+        let n = match read_at(&mut buf[..to_read], offset + copied) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        let mut chunk = &buf[..n];
+        while !chunk.is_empty() {
+            match write_at(chunk, dest_offset + copied) {
+                Ok(0) => {
+                    return Err(io::const_io_error!(
+                        ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ));
+                }
+                Ok(n) => {
+                    chunk = &chunk[n..];
+                    copied += n as u64;
+                }
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    Ok(copied)
+}
+
 pub fn remove_dir_all(path: &Path) -> io::Result<()> {
     let filetype = fs::symlink_metadata(path)?.file_type();
     if filetype.is_symlink() { fs::remove_file(path) } else { remove_dir_all_recursive(path) }
@@ -265,8 +265,15 @@ pub struct Builder {
     name: Option<String>,
     // The size of the stack for the spawned thread in bytes
     stack_size: Option<usize>,
+    // Scheduling options set through `os::linux::thread::BuilderExt`
+    #[cfg(target_os = "linux")]
+    pub(crate) sched: imp::SchedOptions,
 }
 
+/// Allows extension traits within `std`.
+#[unstable(feature = "sealed", issue = "none")]
+impl crate::sealed::Sealed for Builder {}
+
 impl Builder {
     /// Generates the base configuration for spawning a thread, from which
     /// configuration methods can be chained.
@@ -288,7 +295,12 @@ impl Builder {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn new() -> Builder {
-        Builder { name: None, stack_size: None }
+        Builder {
+            name: None,
+            stack_size: None,
+            #[cfg(target_os = "linux")]
+            sched: Default::default(),
+        }
     }
 
     /// Names the thread-to-be. Currently the name is used for identification
@@ -468,7 +480,12 @@ unsafe fn spawn_unchecked_<'a, 'scope, F, T>(
         T: Send + 'a,
         'scope: 'a,
     {
-        let Builder { name, stack_size } = self;
+        let Builder {
+            name,
+            stack_size,
+            #[cfg(target_os = "linux")]
+            sched,
+        } = self;
 
         let stack_size = stack_size.unwrap_or_else(thread::min_stack);
 
@@ -511,8 +528,28 @@ fn drop(&mut self) {
             }
         }
 
+        // The new thread applies its scheduling options to itself, and reports whether that
+        // succeeded before running `f`, so that a failure can be returned from here.
+        #[cfg(target_os = "linux")]
+        let (sched, sched_result) = if sched.is_empty() {
+            (None, None)
+        } else {
+            let (tx, rx) = crate::sync::mpsc::sync_channel(1);
+            (Some((sched, tx)), Some(rx))
+        };
+
         let f = MaybeDangling::new(f);
         let main = move || {
+            #[cfg(target_os = "linux")]
+            if let Some((sched, tx)) = sched {
+                let result = sched.apply();
+                let failed = result.is_err();
+                let _ = tx.send(result);
+                if failed {
+                    return;
+                }
+            }
+
             if let Some(name) = their_thread.cname() {
                 imp::Thread::set_name(name);
             }
@@ -545,7 +582,7 @@ fn drop(&mut self) {
             scope_data.increment_num_running_threads();
         }
 
-        Ok(JoinInner {
+        let inner = JoinInner {
             // SAFETY:
             //
             // `imp::Thread::new` takes a closure with a `'static` lifetime, since it's passed
@@ -569,7 +606,18 @@ fn drop(&mut self) {
             },
             thread: my_thread,
             packet: my_packet,
-        })
+        };
+
+        #[cfg(target_os = "linux")]
+        if let Some(rx) = sched_result {
+            // Applying the options doesn't panic, so the thread always sends its result.
+            if let Err(e) = rx.recv().unwrap() {
+                inner.native.join();
+                return Err(e);
+            }
+        }
+
+        Ok(inner)
     }
 }
 
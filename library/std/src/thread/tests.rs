@@ -69,6 +69,42 @@ fn test_named_thread_truncation() {
     result.unwrap().join().unwrap();
 }
 
+#[cfg(target_os = "linux")]
+#[test]
+fn test_linux_sched_options() {
+    use crate::io::ErrorKind;
+    use crate::os::linux::thread::{current_affinity, BuilderExt, SchedPolicy};
+
+    let cpus = current_affinity().unwrap();
+    let cpu = *cpus.last().unwrap();
+
+    // None of these options need any privileges.
+    let (affinity, policy, nice) = Builder::new()
+        .affinity(&[cpu])
+        .sched_policy(SchedPolicy::Batch)
+        .nice(19)
+        .spawn(|| unsafe {
+            let tid = libc::syscall(libc::SYS_gettid) as libc::id_t;
+            (
+                current_affinity().unwrap(),
+                libc::sched_getscheduler(0),
+                libc::getpriority(libc::PRIO_PROCESS, tid),
+            )
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(affinity, [cpu]);
+    assert_eq!(policy, libc::SCHED_BATCH);
+    assert_eq!(nice, 19);
+
+    // The options of the spawning thread are left alone.
+    assert_eq!(current_affinity().unwrap(), cpus);
+
+    let err = Builder::new().affinity(&[usize::MAX]).spawn(|| {}).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
 #[test]
 #[should_panic]
 fn test_invalid_named_thread() {
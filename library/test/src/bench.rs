@@ -9,8 +9,11 @@
 
 use crate::stats;
 use std::cmp;
-use std::io;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, prelude::*, BufReader, BufWriter};
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
@@ -84,6 +87,174 @@ pub fn fmt_bench_samples(bs: &BenchSamples) -> String {
     output
 }
 
+/// Number of samples the summary of a benchmark is computed from.
+pub const BENCH_SAMPLE_COUNT: usize = 50;
+
+/// Critical value of the standard normal distribution for a two-sided 95% confidence interval.
+const CONFIDENCE_Z_95: f64 = 1.96;
+
+/// First line of a file written by `--save-baseline`, identifying its format.
+const BASELINE_HEADER: &str = "# libtest benchmark baseline v1";
+
+/// Benchmark results saved by a previous run with `--save-baseline`, which later runs can be
+/// compared against with `--baseline`.
+///
+/// The file format is line based: after the header, each line holds the fields of the
+/// `stats::Summary` of one benchmark and its throughput, separated by tabs, followed by the name
+/// of the benchmark.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BenchBaseline(BTreeMap<String, BenchSamples>);
+
+impl BenchBaseline {
+    pub fn new() -> BenchBaseline {
+        BenchBaseline(BTreeMap::new())
+    }
+
+    pub fn insert(&mut self, name: &str, samples: BenchSamples) {
+        self.0.insert(name.to_owned(), samples);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&BenchSamples> {
+        self.0.get(name)
+    }
+
+    pub fn load(path: &Path) -> io::Result<BenchBaseline> {
+        BenchBaseline::read_from(BufReader::new(File::open(path)?))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_to(&mut out)?;
+        out.flush()
+    }
+
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        writeln!(out, "{BASELINE_HEADER}")?;
+        writeln!(
+            out,
+            "# sum\tmin\tmax\tmean\tmedian\tvar\tstd_dev\tstd_dev_pct\tmedian_abs_dev\t\
+             median_abs_dev_pct\tq1\tq2\tq3\tiqr\tmb_s\tname"
+        )?;
+        for (name, bs) in &self.0 {
+            let s = &bs.ns_iter_summ;
+            let (q1, q2, q3) = s.quartiles;
+            writeln!(
+                out,
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{q1}\t{q2}\t{q3}\t{}\t{}\t{name}",
+                s.sum,
+                s.min,
+                s.max,
+                s.mean,
+                s.median,
+                s.var,
+                s.std_dev,
+                s.std_dev_pct,
+                s.median_abs_dev,
+                s.median_abs_dev_pct,
+                s.iqr,
+                bs.mb_s,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn read_from<R: BufRead>(input: R) -> io::Result<BenchBaseline> {
+        let invalid = |line: usize, msg: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid benchmark baseline (line {line}): {msg}"),
+            )
+        };
+
+        let mut baseline = BenchBaseline::new();
+        for (i, line) in input.lines().enumerate() {
+            let line = line?;
+            if i == 0 {
+                if line != BASELINE_HEADER {
+                    return Err(invalid(1, "unknown format"));
+                }
+                continue;
+            }
+            if line.starts_with('#') || line.is_empty() {
+                continue;
+            }
+
+            // The name comes last, so that it may contain tabs.
+            let mut fields = line.splitn(16, '\t');
+            let mut next_field = || fields.next().ok_or_else(|| invalid(i + 1, "missing fields"));
+            let mut next_number =
+                || next_field()?.parse::<f64>().map_err(|e| invalid(i + 1, &e.to_string()));
+
+            // Fields are evaluated in the order they are written, which matches the file.
+            let ns_iter_summ = stats::Summary {
+                sum: next_number()?,
+                min: next_number()?,
+                max: next_number()?,
+                mean: next_number()?,
+                median: next_number()?,
+                var: next_number()?,
+                std_dev: next_number()?,
+                std_dev_pct: next_number()?,
+                median_abs_dev: next_number()?,
+                median_abs_dev_pct: next_number()?,
+                quartiles: (next_number()?, next_number()?, next_number()?),
+                iqr: next_number()?,
+            };
+            let mb_s =
+                next_field()?.parse::<usize>().map_err(|e| invalid(i + 1, &e.to_string()))?;
+            let name = next_field()?;
+            baseline.insert(name, BenchSamples { ns_iter_summ, mb_s });
+        }
+        Ok(baseline)
+    }
+}
+
+/// Result of comparing a benchmark with its result from a baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchComparison {
+    /// Change of the mean time per iteration, as a percentage of the baseline.
+    pub change_pct: f64,
+    /// Bounds of the 95% confidence interval of `change_pct`.
+    pub interval_pct: (f64, f64),
+    /// Whether the benchmark got slower by more than the regression threshold, and the slowdown is
+    /// statistically significant, i.e. the confidence interval doesn't include zero.
+    pub regressed: bool,
+}
+
+/// Compares the result of a benchmark with its result from a baseline. A slowdown of more than
+/// `threshold_pct` percent is reported as a regression.
+pub fn compare_bench_samples(
+    baseline: &BenchSamples,
+    current: &BenchSamples,
+    threshold_pct: f64,
+) -> BenchComparison {
+    let old = &baseline.ns_iter_summ;
+    let new = &current.ns_iter_summ;
+    if old.mean <= 0.0 {
+        // Nothing meaningful to compare against, e.g. the benchmark didn't call `iter`.
+        return BenchComparison { change_pct: 0.0, interval_pct: (0.0, 0.0), regressed: false };
+    }
+
+    let to_pct = |ns: f64| ns / old.mean * 100.0;
+    let (lo, hi) = stats::mean_difference_interval(
+        old,
+        BENCH_SAMPLE_COUNT,
+        new,
+        BENCH_SAMPLE_COUNT,
+        CONFIDENCE_Z_95,
+    );
+    let change_pct = to_pct(new.mean - old.mean);
+    let interval_pct = (to_pct(lo), to_pct(hi));
+    let regressed = change_pct > threshold_pct && interval_pct.0 > 0.0;
+
+    BenchComparison { change_pct, interval_pct, regressed }
+}
+
+pub fn fmt_bench_comparison(comparison: &BenchComparison) -> String {
+    let BenchComparison { change_pct, interval_pct: (lo, hi), .. } = *comparison;
+    format!("{change_pct:+.2}% (95% CI: {lo:+.2}% .. {hi:+.2}%)")
+}
+
 // Format a number with thousands separators
 fn fmt_thousands_sep(mut n: usize, sep: char) -> String {
     use std::fmt::Write;
@@ -139,7 +310,7 @@ pub fn iter<T, F>(inner: &mut F) -> stats::Summary
     n = cmp::max(1, n);
 
     let mut total_run = Duration::new(0, 0);
-    let samples: &mut [f64] = &mut [0.0_f64; 50];
+    let samples: &mut [f64] = &mut [0.0_f64; BENCH_SAMPLE_COUNT];
     loop {
         let loop_start = Instant::now();
 
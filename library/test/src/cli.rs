@@ -2,8 +2,9 @@
 
 use std::env;
 use std::path::PathBuf;
+use std::time::Duration;
 
-use super::options::{ColorConfig, Options, OutputFormat, RunIgnored};
+use super::options::{ColorConfig, Options, OutputFormat, RunIgnored, TestShard};
 use super::time::TestTimeOptions;
 use std::io::{self, IsTerminal};
 
@@ -13,6 +14,8 @@ pub struct TestOpts {
     pub filters: Vec<String>,
     pub filter_exact: bool,
     pub force_run_in_process: bool,
+    /// Run every test in its own subprocess, even when panic=unwind.
+    pub isolate: bool,
     pub exclude_should_panic: bool,
     pub run_ignored: RunIgnored,
     pub run_tests: bool,
@@ -23,9 +26,24 @@ pub struct TestOpts {
     pub format: OutputFormat,
     pub shuffle: bool,
     pub shuffle_seed: Option<u64>,
+    pub shard: Option<TestShard>,
+    /// Number of times a failing test is run again before it's reported as
+    /// failed. A test that passes on a retry is reported as flaky.
+    pub retries: usize,
+    /// File to save the benchmark results of this run to.
+    pub save_baseline: Option<PathBuf>,
+    /// File with the benchmark results of a previous run to compare against.
+    pub baseline: Option<PathBuf>,
+    /// Slowdown, in percent, above which a benchmark is considered to have regressed compared
+    /// to the baseline.
+    pub regression_threshold: f64,
     pub test_threads: Option<usize>,
     pub skip: Vec<String>,
     pub time_options: Option<TestTimeOptions>,
+    /// Time after which a test that is still running is stopped and reported as failed.
+    pub timeout: Option<Duration>,
+    /// File to write a JSON report of the test run to, in addition to the console output.
+    pub report_file: Option<PathBuf>,
     /// Stop at first failing test.
     /// May run a few more tests due to threading, but will
     /// abort as soon as possible.
@@ -43,6 +61,9 @@ pub fn use_color(&self) -> bool {
     }
 }
 
+/// Default slowdown, in percent, above which a benchmark is considered to have regressed.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 5.0;
+
 /// Result of parsing the options.
 pub type OptRes = Result<TestOpts, String>;
 /// Result of parsing the option part.
@@ -53,6 +74,7 @@ fn optgroups() -> getopts::Options {
     opts.optflag("", "include-ignored", "Run ignored and not ignored tests")
         .optflag("", "ignored", "Run only ignored tests")
         .optflag("", "force-run-in-process", "Forces tests to run in-process when panic=abort")
+        .optflag("", "isolate", "Run each test in its own subprocess, even when panic=unwind")
         .optflag("", "exclude-should-panic", "Excludes tests marked as should_panic")
         .optflag("", "test", "Run tests and not benchmarks")
         .optflag("", "bench", "Run benchmarks instead of tests")
@@ -102,8 +124,9 @@ fn optgroups() -> getopts::Options {
             pretty = Print verbose output;
             terse  = Display one character per test;
             json   = Output a json document;
-            junit  = Output a JUnit document",
-            "pretty|terse|json|junit",
+            junit  = Output a JUnit document;
+            tap    = Output a TAP version 14 document",
+            "pretty|terse|json|junit|tap",
         )
         .optflag("", "show-output", "Show captured stdout of successful tests")
         .optopt(
@@ -148,6 +171,61 @@ fn optgroups() -> getopts::Options {
             "shuffle-seed",
             "Run tests in random order; seed the random number generator with SEED",
             "SEED",
+        )
+        .optopt(
+            "",
+            "shard-index",
+            "Only run the tests in shard INDEX (zero-based) out of the number of shards
+            given by --shard-count",
+            "INDEX",
+        )
+        .optopt(
+            "",
+            "shard-count",
+            "Split the tests remaining after filtering into COUNT disjoint shards;
+            must be used together with --shard-index",
+            "COUNT",
+        )
+        .optopt(
+            "",
+            "retries",
+            "Run each failing test up to N more times; a test that passes on a retry
+            is reported as flaky instead of failed",
+            "N",
+        )
+        .optopt(
+            "",
+            "save-baseline",
+            "Save the results of the benchmarks to PATH, to compare later runs against",
+            "PATH",
+        )
+        .optopt(
+            "",
+            "baseline",
+            "Compare the results of the benchmarks with the ones saved to PATH by
+            --save-baseline, and fail if any of them regressed",
+            "PATH",
+        )
+        .optopt(
+            "",
+            "regression-threshold",
+            "Slowdown, in percent, above which a benchmark is considered to have
+            regressed compared to the baseline (default: 5)",
+            "PERCENT",
+        )
+        .optopt(
+            "",
+            "timeout",
+            "Fail tests that are still running after SECONDS; tests run in a subprocess
+            are killed",
+            "SECONDS",
+        )
+        .optopt(
+            "",
+            "report-file",
+            "Write a JSON report of the test run to PATH, with the time taken and the
+            output captured for every test; the console output is not affected",
+            "PATH",
         );
     opts
 }
@@ -171,6 +249,39 @@ fn usage(binary: &str, options: &getopts::Options) {
 tests in the same order again. Note that --shuffle and --shuffle-seed do not
 affect whether the tests are run in parallel.
 
+The tests left after filtering can be split into disjoint shards with
+--shard-count and --shard-index (or RUST_TEST_SHARD_COUNT and
+RUST_TEST_SHARD_INDEX), so that several processes can each run their own part
+of the suite. A test is assigned to a shard based on its name only, so the
+assignment is stable across runs and is not affected by --shuffle.
+
+Failing tests can be run again with --retries. A test that passes on one of
+its retries is reported as flaky rather than failed. Dynamically generated
+tests, such as doctests, are never retried.
+
+The results of a --bench run can be saved with --save-baseline and compared
+against in a later run with --baseline. A benchmark whose mean time per
+iteration got slower by more than --regression-threshold percent, with 95%
+confidence, is reported as failed.
+
+A test that is still running after --timeout seconds is reported as failed
+right away. Tests that run in a subprocess (when the test harness was built
+with panic=abort) are killed. Tests that run in-process can't be stopped, so
+their thread is left running in the background and its result is ignored.
+
+With --report-file, a JSON report of the test run is written to the given
+file, while the console output stays as selected by --format (pretty when that
+is json). The report has the events of --format json, plus the time taken by
+every test, its captured output and the time limits of --report-time that
+apply to it. Its "started" event holds a "format_version", which changes only
+when existing fields do.
+
+With --isolate, every test runs in a fresh subprocess, as is always the case
+when the test harness is built with panic=abort. Tests then can't affect each
+other through global state, environment variables or signal handlers, and a
+test that crashes the process only fails itself. Dynamically generated tests,
+such as doctests, still run in-process.
+
 All tests have their standard output and standard error captured by default.
 This can be overridden with the --nocapture flag or setting RUST_TEST_NOCAPTURE
 environment variable to a value other than "0". Logging is not captured by default.
@@ -257,10 +368,19 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
 
     // Unstable flags
     let force_run_in_process = unstable_optflag!(matches, allow_unstable, "force-run-in-process");
+    let isolate = unstable_optflag!(matches, allow_unstable, "isolate");
     let exclude_should_panic = unstable_optflag!(matches, allow_unstable, "exclude-should-panic");
     let time_options = get_time_options(&matches, allow_unstable)?;
     let shuffle = get_shuffle(&matches, allow_unstable)?;
     let shuffle_seed = get_shuffle_seed(&matches, allow_unstable)?;
+    let shard = get_shard(&matches, allow_unstable)?;
+    let retries = get_retries(&matches, allow_unstable)?;
+    let save_baseline =
+        unstable_optopt!(matches, allow_unstable, "save-baseline").map(PathBuf::from);
+    let baseline = unstable_optopt!(matches, allow_unstable, "baseline").map(PathBuf::from);
+    let regression_threshold = get_regression_threshold(&matches, allow_unstable)?;
+    let timeout = get_timeout(&matches, allow_unstable)?;
+    let report_file = unstable_optopt!(matches, allow_unstable, "report-file").map(PathBuf::from);
 
     let include_ignored = matches.opt_present("include-ignored");
     let quiet = matches.opt_present("quiet");
@@ -277,7 +397,11 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
     let nocapture = get_nocapture(&matches)?;
     let test_threads = get_test_threads(&matches)?;
     let color = get_color_config(&matches)?;
-    let format = get_format(&matches, quiet, allow_unstable)?;
+    let mut format = get_format(&matches, quiet, allow_unstable)?;
+    if report_file.is_some() && format == OutputFormat::Json {
+        // The JSON output goes to the report file, show the human-readable output instead.
+        format = if quiet { OutputFormat::Terse } else { OutputFormat::Pretty };
+    }
 
     let options = Options::new().display_output(matches.opt_present("show-output"));
 
@@ -286,6 +410,7 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
         filters,
         filter_exact: exact,
         force_run_in_process,
+        isolate,
         exclude_should_panic,
         run_ignored,
         run_tests,
@@ -296,9 +421,16 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
         format,
         shuffle,
         shuffle_seed,
+        shard,
+        retries,
+        save_baseline,
+        baseline,
+        regression_threshold,
         test_threads,
         skip,
         time_options,
+        timeout,
+        report_file,
         options,
         fail_fast: false,
     };
@@ -375,6 +507,106 @@ fn get_shuffle_seed(matches: &getopts::Matches, allow_unstable: bool) -> OptPart
     Ok(shuffle_seed)
 }
 
+fn get_shard(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<Option<TestShard>> {
+    let parse_shard_number = |option_name: &str, env_name: &str, value: Option<String>| {
+        let value = match value {
+            Some(value) => value,
+            None if allow_unstable => match env::var(env_name) {
+                Ok(value) => value,
+                Err(_) => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+        match value.parse::<usize>() {
+            Ok(n) => Ok(Some(n)),
+            Err(e) => Err(format!("argument for --{option_name} must be a number (error: {e})")),
+        }
+    };
+
+    let index = parse_shard_number(
+        "shard-index",
+        "RUST_TEST_SHARD_INDEX",
+        unstable_optopt!(matches, allow_unstable, "shard-index"),
+    )?;
+    let count = parse_shard_number(
+        "shard-count",
+        "RUST_TEST_SHARD_COUNT",
+        unstable_optopt!(matches, allow_unstable, "shard-count"),
+    )?;
+
+    let shard = match (index, count) {
+        (None, None) => None,
+        (Some(_), None) | (None, Some(_)) => {
+            return Err("the options --shard-index and --shard-count must be used together".into());
+        }
+        (Some(_), Some(0)) => {
+            return Err("argument for --shard-count must not be 0".into());
+        }
+        (Some(index), Some(count)) if index >= count => {
+            return Err(format!(
+                "argument for --shard-index must be less than --shard-count \
+                 (got index {index} for {count} shards)"
+            ));
+        }
+        (Some(index), Some(count)) => Some(TestShard { index, count }),
+    };
+
+    Ok(shard)
+}
+
+fn get_retries(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<usize> {
+    let retries = match unstable_optopt!(matches, allow_unstable, "retries") {
+        Some(n_str) => match n_str.parse::<usize>() {
+            Ok(n) => n,
+            Err(e) => {
+                return Err(format!(
+                    "argument for --retries must be a number \
+                     (error: {e})"
+                ));
+            }
+        },
+        None => 0,
+    };
+
+    Ok(retries)
+}
+
+fn get_regression_threshold(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<f64> {
+    let threshold = match unstable_optopt!(matches, allow_unstable, "regression-threshold") {
+        Some(pct_str) => match pct_str.parse::<f64>() {
+            Ok(pct) if pct >= 0.0 => pct,
+            Ok(_) => return Err("argument for --regression-threshold must not be negative".into()),
+            Err(e) => {
+                return Err(format!(
+                    "argument for --regression-threshold must be a number \
+                     (error: {e})"
+                ));
+            }
+        },
+        None => DEFAULT_REGRESSION_THRESHOLD,
+    };
+
+    Ok(threshold)
+}
+
+fn get_timeout(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<Option<Duration>> {
+    let timeout = match unstable_optopt!(matches, allow_unstable, "timeout") {
+        Some(secs_str) => match secs_str.parse::<f64>().map(Duration::try_from_secs_f64) {
+            Ok(Ok(timeout)) if !timeout.is_zero() => Some(timeout),
+            Ok(_) => return Err("argument for --timeout must be a positive number".into()),
+            Err(e) => {
+                return Err(format!(
+                    "argument for --timeout must be a number of seconds \
+                     (error: {e})"
+                ));
+            }
+        },
+        None => None,
+    };
+
+    Ok(timeout)
+}
+
 fn get_test_threads(matches: &getopts::Matches) -> OptPartRes<Option<usize>> {
     let test_threads = match matches.opt_str("test-threads") {
         Some(n_str) => match n_str.parse::<usize>() {
@@ -414,9 +646,15 @@ fn get_format(
             }
             OutputFormat::Junit
         }
+        Some("tap") => {
+            if !allow_unstable {
+                return Err("The \"tap\" format is only accepted on the nightly compiler with -Z unstable-options".into());
+            }
+            OutputFormat::Tap
+        }
         Some(v) => {
             return Err(format!(
-                "argument for --format must be pretty, terse, json or junit (was \
+                "argument for --format must be pretty, terse, json, junit or tap (was \
                  {v})"
             ));
         }
@@ -6,16 +6,22 @@
 use std::time::Instant;
 
 use super::{
-    bench::fmt_bench_samples,
+    bench::{
+        compare_bench_samples, fmt_bench_comparison, fmt_bench_samples, BenchBaseline,
+        BenchComparison,
+    },
     cli::TestOpts,
     event::{CompletedTest, TestEvent},
     filter_tests,
-    formatters::{JsonFormatter, JunitFormatter, OutputFormatter, PrettyFormatter, TerseFormatter},
-    helpers::{concurrency::get_concurrency, metrics::MetricMap},
+    formatters::{
+        JsonFormatter, JunitFormatter, OutputFormatter, PrettyFormatter, TapFormatter,
+        TeeFormatter, TerseFormatter,
+    },
+    helpers::{concurrency::get_concurrency, metrics::MetricMap, shard::shard_tests},
     options::{Options, OutputFormat},
     run_tests, term,
     test_result::TestResult,
-    time::{TestExecTime, TestSuiteExecTime},
+    time::{TestExecTime, TestSuiteExecTime, TestTimeOptions},
     types::{NamePadding, TestDesc, TestDescAndFn},
 };
 
@@ -86,15 +92,24 @@ pub struct ConsoleTestState {
     pub total: usize,
     pub passed: usize,
     pub failed: usize,
+    pub flaky: usize,
     pub ignored: usize,
     pub filtered_out: usize,
+    /// The number of tests left to other shards, if the tests are sharded.
+    pub sharded_out: Option<usize>,
     pub measured: usize,
     pub exec_time: Option<TestSuiteExecTime>,
     pub metrics: MetricMap,
     pub failures: Vec<(TestDesc, Vec<u8>)>,
     pub not_failures: Vec<(TestDesc, Vec<u8>)>,
+    pub flakies: Vec<(TestDesc, Vec<u8>)>,
     pub ignores: Vec<(TestDesc, Vec<u8>)>,
     pub time_failures: Vec<(TestDesc, Vec<u8>)>,
+    /// Results of the benchmarks run so far, to be saved with `--save-baseline`.
+    pub bench_results: BenchBaseline,
+    /// Baseline loaded with `--baseline` that benchmarks are compared against.
+    pub bench_baseline: Option<BenchBaseline>,
+    pub regression_threshold: f64,
     pub options: Options,
 }
 
@@ -104,21 +119,31 @@ pub fn new(opts: &TestOpts) -> io::Result<ConsoleTestState> {
             Some(ref path) => Some(File::create(path)?),
             None => None,
         };
+        let bench_baseline = match opts.baseline {
+            Some(ref path) => Some(BenchBaseline::load(path)?),
+            None => None,
+        };
 
         Ok(ConsoleTestState {
             log_out,
             total: 0,
             passed: 0,
             failed: 0,
+            flaky: 0,
             ignored: 0,
             filtered_out: 0,
+            sharded_out: None,
             measured: 0,
             exec_time: None,
             metrics: MetricMap::new(),
             failures: Vec::new(),
             not_failures: Vec::new(),
+            flakies: Vec::new(),
             ignores: Vec::new(),
             time_failures: Vec::new(),
+            bench_results: BenchBaseline::new(),
+            bench_baseline,
+            regression_threshold: opts.regression_threshold,
             options: opts.options,
         })
     }
@@ -161,6 +186,9 @@ pub fn write_log_result(
                     }
                     TestResult::TrBench(ref bs) => fmt_bench_samples(bs),
                     TestResult::TrTimedFail => "failed (time limit exceeded)".to_owned(),
+                    TestResult::TrFlaky(attempts) => {
+                        format!("flaky (passed on attempt {attempts})")
+                    }
                 },
                 name,
             )
@@ -171,8 +199,40 @@ pub fn write_log_result(
         self.write_log(|| "\n")
     }
 
+    pub fn write_log_retry(
+        &mut self,
+        test: &TestDesc,
+        result: &TestResult,
+        attempt: usize,
+    ) -> io::Result<()> {
+        self.write_log(|| {
+            let outcome = match *result {
+                TestResult::TrFailedMsg(ref msg) => format!("failed: {msg}"),
+                TestResult::TrTimedFail => "failed (time limit exceeded)".to_owned(),
+                _ => "failed".to_owned(),
+            };
+            format!("{outcome} (attempt {attempt}, retrying) {}\n", test.name)
+        })
+    }
+
+    /// Compares the result of a benchmark with the baseline, if there is one and it contains
+    /// this benchmark.
+    fn compare_bench_result(
+        &self,
+        test: &TestDesc,
+        result: &TestResult,
+    ) -> Option<BenchComparison> {
+        match (result, &self.bench_baseline) {
+            (TestResult::TrBench(bs), Some(baseline)) => {
+                let old = baseline.get(test.name.as_slice())?;
+                Some(compare_bench_samples(old, bs, self.regression_threshold))
+            }
+            _ => None,
+        }
+    }
+
     fn current_test_count(&self) -> usize {
-        self.passed + self.failed + self.ignored + self.measured
+        self.passed + self.failed + self.flaky + self.ignored + self.measured
     }
 }
 
@@ -184,7 +244,7 @@ pub fn list_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Res
     };
 
     let mut out: Box<dyn OutputFormatter> = match opts.format {
-        OutputFormat::Pretty | OutputFormat::Junit => {
+        OutputFormat::Pretty | OutputFormat::Junit | OutputFormat::Tap => {
             Box::new(PrettyFormatter::new(output, false, 0, false, None))
         }
         OutputFormat::Terse => Box::new(TerseFormatter::new(output, false, 0, false)),
@@ -192,8 +252,13 @@ pub fn list_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Res
     };
     let mut st = ConsoleTestDiscoveryState::new(opts)?;
 
+    let mut tests = filter_tests(opts, tests);
+    if let Some(shard) = opts.shard {
+        shard_tests(shard, &mut tests);
+    }
+
     out.write_discovery_start()?;
-    for test in filter_tests(opts, tests).into_iter() {
+    for test in tests.into_iter() {
         use crate::TestFn::*;
 
         let TestDescAndFn { desc, testfn } = test;
@@ -219,7 +284,11 @@ pub fn list_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Res
 }
 
 // Updates `ConsoleTestState` depending on result of the test execution.
-fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest) {
+fn handle_test_result(
+    st: &mut ConsoleTestState,
+    completed_test: CompletedTest,
+    comparison: Option<BenchComparison>,
+) {
     let test = completed_test.desc;
     let stdout = completed_test.stdout;
     match completed_test.result {
@@ -237,7 +306,24 @@ fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest)
                 bs.ns_iter_summ.median,
                 bs.ns_iter_summ.max - bs.ns_iter_summ.min,
             );
-            st.measured += 1
+            st.bench_results.insert(test.name.as_slice(), bs);
+            match comparison {
+                // A benchmark that regressed compared to the baseline counts as a failure.
+                Some(comparison) if comparison.regressed => {
+                    st.failed += 1;
+                    let mut stdout = stdout;
+                    stdout.extend_from_slice(
+                        format!(
+                            "note: benchmark regressed by more than {}%: {}\n",
+                            st.regression_threshold,
+                            fmt_bench_comparison(&comparison)
+                        )
+                        .as_bytes(),
+                    );
+                    st.failures.push((test, stdout));
+                }
+                _ => st.measured += 1,
+            }
         }
         TestResult::TrFailed => {
             st.failed += 1;
@@ -253,6 +339,10 @@ fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest)
             st.failed += 1;
             st.time_failures.push((test, stdout));
         }
+        TestResult::TrFlaky(_) => {
+            st.flaky += 1;
+            st.flakies.push((test, stdout));
+        }
     }
 }
 
@@ -264,12 +354,13 @@ fn on_test_event(
     out: &mut dyn OutputFormatter,
 ) -> io::Result<()> {
     match (*event).clone() {
-        TestEvent::TeFiltered(filtered_tests, shuffle_seed) => {
+        TestEvent::TeFiltered(filtered_tests, shuffle_seed, shard) => {
             st.total = filtered_tests;
-            out.write_run_start(filtered_tests, shuffle_seed)?;
+            out.write_run_start(filtered_tests, shuffle_seed, shard)?;
         }
-        TestEvent::TeFilteredOut(filtered_out) => {
+        TestEvent::TeFilteredOut(filtered_out, sharded_out) => {
             st.filtered_out = filtered_out;
+            st.sharded_out = sharded_out;
         }
         TestEvent::TeWait(ref test) => out.write_test_start(test)?,
         TestEvent::TeTimeout(ref test) => out.write_timeout(test)?,
@@ -281,7 +372,24 @@ fn on_test_event(
 
             st.write_log_result(test, result, exec_time.as_ref())?;
             out.write_result(test, result, exec_time.as_ref(), stdout, st)?;
-            handle_test_result(st, completed_test);
+
+            let comparison = st.compare_bench_result(test, result);
+            if let Some(ref comparison) = comparison {
+                st.write_log(|| {
+                    format!("compared to baseline: {}\n", fmt_bench_comparison(comparison))
+                })?;
+                out.write_bench_comparison(test, comparison)?;
+            }
+            handle_test_result(st, completed_test, comparison);
+        }
+        TestEvent::TeRetry(completed_test, attempt) => {
+            let test = &completed_test.desc;
+            let result = &completed_test.result;
+            let exec_time = &completed_test.exec_time;
+            let stdout = &completed_test.stdout;
+
+            st.write_log_retry(test, result, attempt)?;
+            out.write_retry(test, result, exec_time.as_ref(), stdout, attempt)?;
         }
     }
 
@@ -317,7 +425,15 @@ pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Resu
         }
         OutputFormat::Json => Box::new(JsonFormatter::new(output)),
         OutputFormat::Junit => Box::new(JunitFormatter::new(output)),
+        OutputFormat::Tap => Box::new(TapFormatter::new(output)),
     };
+    if let Some(ref path) = opts.report_file {
+        let report_out = OutputLocation::Raw(File::create(path)?);
+        let time_options =
+            opts.time_options.unwrap_or_else(|| TestTimeOptions::new_from_env(false));
+        let report = Box::new(JsonFormatter::new_report(report_out, time_options));
+        out = Box::new(TeeFormatter::new(out, report));
+    }
     let mut st = ConsoleTestState::new(opts)?;
 
     // Prevent the usage of `Instant` in some cases:
@@ -329,6 +445,10 @@ pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Resu
     run_tests(opts, tests, |x| on_test_event(&x, &mut st, &mut *out))?;
     st.exec_time = start_time.map(|t| TestSuiteExecTime(t.elapsed()));
 
+    if let Some(ref path) = opts.save_baseline {
+        st.bench_results.save(path)?;
+    }
+
     assert!(opts.fail_fast || st.current_test_count() == st.total);
 
     out.write_run_finish(&st)
@@ -1,6 +1,7 @@
 //! Module containing different events that can occur
 //! during tests execution process.
 
+use super::options::TestShard;
 use super::test_result::TestResult;
 use super::time::TestExecTime;
 use super::types::{TestDesc, TestId};
@@ -28,9 +29,13 @@ pub fn new(
 
 #[derive(Debug, Clone)]
 pub enum TestEvent {
-    TeFiltered(usize, Option<u64>),
+    TeFiltered(usize, Option<u64>, Option<TestShard>),
     TeWait(TestDesc),
     TeResult(CompletedTest),
+    /// A test failed and will be run again; holds the number of the failed attempt.
+    TeRetry(CompletedTest, usize),
     TeTimeout(TestDesc),
-    TeFilteredOut(usize),
+    /// The number of tests filtered out, and the number left to other shards if the tests are
+    /// sharded.
+    TeFilteredOut(usize, Option<usize>),
 }
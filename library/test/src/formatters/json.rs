@@ -2,19 +2,38 @@
 
 use super::OutputFormatter;
 use crate::{
+    bench::BenchComparison,
     console::{ConsoleTestDiscoveryState, ConsoleTestState, OutputLocation},
+    options::TestShard,
     test_result::TestResult,
     time,
     types::TestDesc,
 };
 
+/// Version of the events written to a `--report-file`. It is bumped whenever an existing field
+/// changes its meaning or goes away; new fields may be added without bumping it.
+pub(crate) const REPORT_FORMAT_VERSION: u32 = 1;
+
 pub(crate) struct JsonFormatter<T> {
     out: OutputLocation<T>,
+    /// Set when writing a `--report-file`: the time limits the tests are checked against, which
+    /// are written along with each result. In that case, the captured output of every test is
+    /// written as well, not only that of the failed ones.
+    report_time_options: Option<time::TestTimeOptions>,
 }
 
 impl<T: Write> JsonFormatter<T> {
     pub fn new(out: OutputLocation<T>) -> Self {
-        Self { out }
+        Self { out, report_time_options: None }
+    }
+
+    pub fn new_report(out: OutputLocation<T>, time_options: time::TestTimeOptions) -> Self {
+        Self { out, report_time_options: Some(time_options) }
+    }
+
+    #[cfg(test)]
+    pub fn output_location(&self) -> &OutputLocation<T> {
+        &self.out
     }
 
     fn writeln_message(&mut self, s: &str) -> io::Result<()> {
@@ -94,15 +113,30 @@ fn write_discovery_finish(&mut self, state: &ConsoleTestDiscoveryState) -> io::R
             ))
     }
 
-    fn write_run_start(&mut self, test_count: usize, shuffle_seed: Option<u64>) -> io::Result<()> {
+    fn write_run_start(
+        &mut self,
+        test_count: usize,
+        shuffle_seed: Option<u64>,
+        shard: Option<TestShard>,
+    ) -> io::Result<()> {
         let shuffle_seed_json = if let Some(shuffle_seed) = shuffle_seed {
             format!(r#", "shuffle_seed": {shuffle_seed}"#)
         } else {
             String::new()
         };
+        let shard_json = if let Some(TestShard { index, count }) = shard {
+            format!(r#", "shard_index": {index}, "shard_count": {count}"#)
+        } else {
+            String::new()
+        };
+        let version_json = if self.report_time_options.is_some() {
+            format!(r#", "format_version": {REPORT_FORMAT_VERSION}"#)
+        } else {
+            String::new()
+        };
         let newline = "\n";
         self.writeln_message(&format!(
-            r#"{{ "type": "suite", "event": "started", "test_count": {test_count}{shuffle_seed_json} }}{newline}"#
+            r#"{{ "type": "suite", "event": "started", "test_count": {test_count}{shuffle_seed_json}{shard_json}{version_json} }}{newline}"#
             ))
     }
 
@@ -122,48 +156,31 @@ fn write_result(
         stdout: &[u8],
         state: &ConsoleTestState,
     ) -> io::Result<()> {
+        let report = self.report_time_options.is_some();
         let display_stdout = state.options.display_output || *result != TestResult::TrOk;
-        let stdout = if display_stdout && !stdout.is_empty() {
+        let stdout = if report || (display_stdout && !stdout.is_empty()) {
             Some(String::from_utf8_lossy(stdout))
         } else {
             None
         };
-        match *result {
-            TestResult::TrOk => {
-                self.write_event("test", desc.name.as_slice(), "ok", exec_time, stdout, None)
+        let (event, extra) = match *result {
+            TestResult::TrOk => ("ok", None),
+
+            TestResult::TrFailed => ("failed", None),
+
+            TestResult::TrTimedFail => {
+                ("failed", Some(r#""reason": "time limit exceeded""#.to_string()))
             }
 
-            TestResult::TrFailed => {
-                self.write_event("test", desc.name.as_slice(), "failed", exec_time, stdout, None)
+            TestResult::TrFailedMsg(ref m) => {
+                ("failed", Some(format!(r#""message": "{}""#, EscapedString(m))))
             }
 
-            TestResult::TrTimedFail => self.write_event(
-                "test",
-                desc.name.as_slice(),
-                "failed",
-                exec_time,
-                stdout,
-                Some(r#""reason": "time limit exceeded""#),
-            ),
+            TestResult::TrFlaky(attempts) => ("flaky", Some(format!(r#""attempts": {attempts}"#))),
 
-            TestResult::TrFailedMsg(ref m) => self.write_event(
-                "test",
-                desc.name.as_slice(),
-                "failed",
-                exec_time,
-                stdout,
-                Some(&*format!(r#""message": "{}""#, EscapedString(m))),
-            ),
-
-            TestResult::TrIgnored => self.write_event(
-                "test",
-                desc.name.as_slice(),
+            TestResult::TrIgnored => (
                 "ignored",
-                exec_time,
-                stdout,
-                desc.ignore_message
-                    .map(|msg| format!(r#""message": "{}""#, EscapedString(msg)))
-                    .as_deref(),
+                desc.ignore_message.map(|msg| format!(r#""message": "{}""#, EscapedString(msg))),
             ),
 
             TestResult::TrBench(ref bs) => {
@@ -177,14 +194,72 @@ fn write_result(
                 };
                 let name = EscapedString(desc.name.as_slice());
 
-                self.writeln_message(&format!(
+                return self.writeln_message(&format!(
                     "{{ \"type\": \"bench\", \
                      \"name\": \"{name}\", \
                      \"median\": {median}, \
                      \"deviation\": {deviation}{mbps} }}\n",
-                ))
+                ));
             }
-        }
+        };
+
+        // Reports also say which time limits apply to the test, so that they can be checked
+        // without knowing how the test run was configured.
+        let extra = match self.report_time_options {
+            Some(time_options) => {
+                let warn_time = time_options.warn_time(desc).as_secs_f64();
+                let critical_time = time_options.critical_time(desc).as_secs_f64();
+                let thresholds_json =
+                    format!(r#""warn_time": {warn_time}, "critical_time": {critical_time}"#);
+                Some(match extra {
+                    Some(extra) => format!("{extra}, {thresholds_json}"),
+                    None => thresholds_json,
+                })
+            }
+            None => extra,
+        };
+        self.write_event("test", desc.name.as_slice(), event, exec_time, stdout, extra.as_deref())
+    }
+
+    fn write_retry(
+        &mut self,
+        desc: &TestDesc,
+        result: &TestResult,
+        exec_time: Option<&time::TestExecTime>,
+        stdout: &[u8],
+        attempt: usize,
+    ) -> io::Result<()> {
+        let stdout = if !stdout.is_empty() { Some(String::from_utf8_lossy(stdout)) } else { None };
+        let extra = match *result {
+            TestResult::TrTimedFail => {
+                format!(r#""attempt": {attempt}, "reason": "time limit exceeded""#)
+            }
+            TestResult::TrFailedMsg(ref m) => {
+                format!(r#""attempt": {attempt}, "message": "{}""#, EscapedString(m))
+            }
+            _ => format!(r#""attempt": {attempt}"#),
+        };
+        self.write_event("test", desc.name.as_slice(), "retry", exec_time, stdout, Some(&extra))
+    }
+
+    fn write_bench_comparison(
+        &mut self,
+        desc: &TestDesc,
+        comparison: &BenchComparison,
+    ) -> io::Result<()> {
+        let BenchComparison { change_pct, interval_pct: (ci_low_pct, ci_high_pct), regressed } =
+            *comparison;
+        let name = EscapedString(desc.name.as_slice());
+
+        self.writeln_message(&format!(
+            "{{ \"type\": \"bench\", \
+             \"event\": \"compared\", \
+             \"name\": \"{name}\", \
+             \"change_pct\": {change_pct}, \
+             \"ci_low_pct\": {ci_low_pct}, \
+             \"ci_high_pct\": {ci_high_pct}, \
+             \"regressed\": {regressed} }}\n",
+        ))
     }
 
     fn write_timeout(&mut self, desc: &TestDesc) -> io::Result<()> {
@@ -202,6 +277,12 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         let ignored = state.ignored;
         let measured = state.measured;
         let filtered_out = state.filtered_out;
+        let flaky_json =
+            if state.flaky > 0 { format!(r#", "flaky": {}"#, state.flaky) } else { String::new() };
+        let sharded_out_json = match state.sharded_out {
+            Some(sharded_out) => format!(r#", "sharded_out": {sharded_out}"#),
+            None => String::new(),
+        };
         let exec_time_json = if let Some(ref exec_time) = state.exec_time {
             format!(r#", "exec_time": {}"#, exec_time.0.as_secs_f64())
         } else {
@@ -210,7 +291,7 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         let newline = "\n";
 
         self.writeln_message(&format!(
-            r#"{{ "type": "suite", "event": "{event}", "passed": {passed}, "failed": {failed}, "ignored": {ignored}, "measured": {measured}, "filtered_out": {filtered_out}{exec_time_json} }}{newline}"#
+            r#"{{ "type": "suite", "event": "{event}", "passed": {passed}, "failed": {failed}{flaky_json}, "ignored": {ignored}, "measured": {measured}, "filtered_out": {filtered_out}{sharded_out_json}{exec_time_json} }}{newline}"#
         ))?;
 
         Ok(state.failed == 0)
@@ -219,7 +300,7 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
 
 /// A formatting utility used to print strings with characters in need of escaping.
 /// Base code taken form `libserialize::json::escape_str`
-struct EscapedString<S: AsRef<str>>(S);
+pub(crate) struct EscapedString<S: AsRef<str>>(pub(crate) S);
 
 impl<S: AsRef<str>> std::fmt::Display for EscapedString<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> ::std::fmt::Result {
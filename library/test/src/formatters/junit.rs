@@ -3,7 +3,9 @@
 
 use super::OutputFormatter;
 use crate::{
+    bench::{fmt_bench_comparison, BenchComparison},
     console::{ConsoleTestDiscoveryState, ConsoleTestState, OutputLocation},
+    options::TestShard,
     test_result::TestResult,
     time,
     types::{TestDesc, TestType},
@@ -12,11 +14,16 @@
 pub struct JunitFormatter<T> {
     out: OutputLocation<T>,
     results: Vec<(TestDesc, TestResult, Duration, Vec<u8>)>,
+    /// Failed attempts of tests that were retried, written alongside the final result.
+    retries: Vec<(TestDesc, TestResult, Duration, Vec<u8>)>,
+    /// Benchmarks that regressed compared to the baseline, with a description of the change.
+    regressions: Vec<(TestDesc, String)>,
+    shard: Option<TestShard>,
 }
 
 impl<T: Write> JunitFormatter<T> {
     pub fn new(out: OutputLocation<T>) -> Self {
-        Self { out, results: Vec::new() }
+        Self { out, results: Vec::new(), retries: Vec::new(), regressions: Vec::new(), shard: None }
     }
 
     fn write_message(&mut self, s: &str) -> io::Result<()> {
@@ -24,6 +31,31 @@ fn write_message(&mut self, s: &str) -> io::Result<()> {
 
         self.out.write_all(s.as_ref())
     }
+
+    /// Writes the failed attempts of a retried test as `flakyFailure` or `rerunFailure` elements,
+    /// following the convention used by Maven Surefire.
+    fn write_failed_attempts(&mut self, desc: &TestDesc, tag: &str) -> io::Result<()> {
+        let (attempts, retries) =
+            std::mem::take(&mut self.retries).into_iter().partition(|(d, ..)| d.name == desc.name);
+        self.retries = retries;
+        for (_, result, _, stdout) in attempts {
+            let message = match result {
+                TestResult::TrFailedMsg(ref m) => format!(" message=\"{m}\""),
+                _ => String::new(),
+            };
+            let ty = if result == TestResult::TrTimedFail { "timeout" } else { "assert" };
+            if stdout.is_empty() {
+                self.write_message(&format!("<{tag}{message} type=\"{ty}\"/>"))?;
+            } else {
+                self.write_message(&format!("<{tag}{message} type=\"{ty}\">"))?;
+                self.write_message("<system-out>")?;
+                self.write_message(&str_to_cdata(&String::from_utf8_lossy(&stdout)))?;
+                self.write_message("</system-out>")?;
+                self.write_message(&format!("</{tag}>"))?;
+            }
+        }
+        Ok(())
+    }
 }
 
 fn str_to_cdata(s: &str) -> String {
@@ -55,7 +87,11 @@ fn write_run_start(
         &mut self,
         _test_count: usize,
         _shuffle_seed: Option<u64>,
+        shard: Option<TestShard>,
     ) -> io::Result<()> {
+        // The shard is reported as a property of the testsuite node, which is only written once
+        // the run is complete.
+        self.shard = shard;
         // We write xml header on run start
         self.write_message("<?xml version=\"1.0\" encoding=\"UTF-8\"?>")
     }
@@ -85,6 +121,31 @@ fn write_result(
         self.results.push((desc.clone(), result.clone(), duration, stdout.to_vec()));
         Ok(())
     }
+
+    fn write_retry(
+        &mut self,
+        desc: &TestDesc,
+        result: &TestResult,
+        exec_time: Option<&time::TestExecTime>,
+        stdout: &[u8],
+        _attempt: usize,
+    ) -> io::Result<()> {
+        let duration = exec_time.map(|t| t.0).unwrap_or_default();
+        self.retries.push((desc.clone(), result.clone(), duration, stdout.to_vec()));
+        Ok(())
+    }
+
+    fn write_bench_comparison(
+        &mut self,
+        desc: &TestDesc,
+        comparison: &BenchComparison,
+    ) -> io::Result<()> {
+        if comparison.regressed {
+            self.regressions.push((desc.clone(), fmt_bench_comparison(comparison)));
+        }
+        Ok(())
+    }
+
     fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         self.write_message("<testsuites>")?;
 
@@ -97,6 +158,12 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
              >",
             state.failed, state.total, state.ignored
         ))?;
+        if let Some(TestShard { index, count }) = self.shard {
+            self.write_message("<properties>")?;
+            self.write_message(&format!("<property name=\"shard_index\" value=\"{index}\"/>"))?;
+            self.write_message(&format!("<property name=\"shard_count\" value=\"{count}\"/>"))?;
+            self.write_message("</properties>")?;
+        }
         for (desc, result, duration, stdout) in std::mem::take(&mut self.results) {
             let (class_name, test_name) = parse_class_name(&desc);
             match result {
@@ -110,6 +177,7 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
                         duration.as_secs_f64()
                     ))?;
                     self.write_message("<failure type=\"assert\"/>")?;
+                    self.write_failed_attempts(&desc, "rerunFailure")?;
                     if !stdout.is_empty() {
                         self.write_message("<system-out>")?;
                         self.write_message(&str_to_cdata(&String::from_utf8_lossy(&stdout)))?;
@@ -127,6 +195,7 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
                         duration.as_secs_f64()
                     ))?;
                     self.write_message(&format!("<failure message=\"{m}\" type=\"assert\"/>"))?;
+                    self.write_failed_attempts(&desc, "rerunFailure")?;
                     if !stdout.is_empty() {
                         self.write_message("<system-out>")?;
                         self.write_message(&str_to_cdata(&String::from_utf8_lossy(&stdout)))?;
@@ -144,15 +213,54 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
                         duration.as_secs_f64()
                     ))?;
                     self.write_message("<failure type=\"timeout\"/>")?;
+                    self.write_failed_attempts(&desc, "rerunFailure")?;
                     self.write_message("</testcase>")?;
                 }
 
                 TestResult::TrBench(ref b) => {
+                    let regression = self
+                        .regressions
+                        .iter()
+                        .find(|(regressed, _)| regressed.name == desc.name)
+                        .map(|(_, change)| change.clone());
+                    match regression {
+                        Some(change) => {
+                            self.write_message(&format!(
+                                "<testcase classname=\"benchmark::{}\" \
+                                 name=\"{}\" time=\"{}\">",
+                                class_name, test_name, b.ns_iter_summ.sum
+                            ))?;
+                            self.write_message(&format!(
+                                "<failure message=\"regressed compared to baseline: {change}\" \
+                                 type=\"regression\"/>"
+                            ))?;
+                            self.write_message("</testcase>")?;
+                        }
+                        None => {
+                            self.write_message(&format!(
+                                "<testcase classname=\"benchmark::{}\" \
+                                 name=\"{}\" time=\"{}\" />",
+                                class_name, test_name, b.ns_iter_summ.sum
+                            ))?;
+                        }
+                    }
+                }
+
+                TestResult::TrFlaky(_) => {
                     self.write_message(&format!(
-                        "<testcase classname=\"benchmark::{}\" \
-                         name=\"{}\" time=\"{}\" />",
-                        class_name, test_name, b.ns_iter_summ.sum
+                        "<testcase classname=\"{}\" \
+                         name=\"{}\" time=\"{}\">",
+                        class_name,
+                        test_name,
+                        duration.as_secs_f64()
                     ))?;
+                    self.write_failed_attempts(&desc, "flakyFailure")?;
+                    if !stdout.is_empty() && state.options.display_output {
+                        self.write_message("<system-out>")?;
+                        self.write_message(&str_to_cdata(&String::from_utf8_lossy(&stdout)))?;
+                        self.write_message("</system-out>")?;
+                    }
+                    self.write_message("</testcase>")?;
                 }
 
                 TestResult::TrOk => {
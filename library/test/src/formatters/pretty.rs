@@ -2,8 +2,9 @@
 
 use super::OutputFormatter;
 use crate::{
-    bench::fmt_bench_samples,
+    bench::{fmt_bench_comparison, fmt_bench_samples, BenchComparison},
     console::{ConsoleTestDiscoveryState, ConsoleTestState, OutputLocation},
+    options::TestShard,
     term,
     test_result::TestResult,
     time,
@@ -57,6 +58,17 @@ pub fn write_time_failed(&mut self) -> io::Result<()> {
         self.write_short_result("FAILED (time limit exceeded)", term::color::RED)
     }
 
+    pub fn write_flaky(&mut self, attempts: usize) -> io::Result<()> {
+        self.write_short_result(
+            &format!("ok (flaky, passed on attempt {attempts})"),
+            term::color::YELLOW,
+        )
+    }
+
+    pub fn write_retrying(&mut self, attempt: usize) -> io::Result<()> {
+        self.write_short_result(&format!("FAILED (attempt {attempt}), retrying"), term::color::RED)
+    }
+
     pub fn write_bench(&mut self) -> io::Result<()> {
         self.write_pretty("bench", term::color::CYAN)
     }
@@ -164,6 +176,10 @@ pub fn write_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
         self.write_results(&state.failures, "failures")
     }
 
+    pub fn write_flakies(&mut self, state: &ConsoleTestState) -> io::Result<()> {
+        self.write_results(&state.flakies, "flaky")
+    }
+
     pub fn write_time_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
         self.write_results(&state.time_failures, "failures (time limit exceeded)")
     }
@@ -208,14 +224,24 @@ fn plural(count: usize, s: &str) -> String {
         ))
     }
 
-    fn write_run_start(&mut self, test_count: usize, shuffle_seed: Option<u64>) -> io::Result<()> {
+    fn write_run_start(
+        &mut self,
+        test_count: usize,
+        shuffle_seed: Option<u64>,
+        shard: Option<TestShard>,
+    ) -> io::Result<()> {
         let noun = if test_count != 1 { "tests" } else { "test" };
         let shuffle_seed_msg = if let Some(shuffle_seed) = shuffle_seed {
             format!(" (shuffle seed: {shuffle_seed})")
         } else {
             String::new()
         };
-        self.write_plain(format!("\nrunning {test_count} {noun}{shuffle_seed_msg}\n"))
+        let shard_msg = if let Some(TestShard { index, count }) = shard {
+            format!(" (shard index: {index}, shard count: {count})")
+        } else {
+            String::new()
+        };
+        self.write_plain(format!("\nrunning {test_count} {noun}{shuffle_seed_msg}{shard_msg}\n"))
     }
 
     fn write_test_start(&mut self, desc: &TestDesc) -> io::Result<()> {
@@ -251,12 +277,46 @@ fn write_result(
                 self.write_plain(format!(": {}", fmt_bench_samples(bs)))?;
             }
             TestResult::TrTimedFail => self.write_time_failed()?,
+            TestResult::TrFlaky(attempts) => self.write_flaky(attempts)?,
         }
 
         self.write_time(desc, exec_time)?;
         self.write_plain("\n")
     }
 
+    fn write_retry(
+        &mut self,
+        desc: &TestDesc,
+        _: &TestResult,
+        exec_time: Option<&time::TestExecTime>,
+        _: &[u8],
+        attempt: usize,
+    ) -> io::Result<()> {
+        if self.is_multithreaded {
+            self.write_test_name(desc)?;
+        }
+
+        self.write_retrying(attempt)?;
+        self.write_time(desc, exec_time)?;
+        self.write_plain("\n")
+    }
+
+    fn write_bench_comparison(
+        &mut self,
+        _: &TestDesc,
+        comparison: &BenchComparison,
+    ) -> io::Result<()> {
+        self.write_plain(format!(
+            "    compared to baseline: {}",
+            fmt_bench_comparison(comparison)
+        ))?;
+        if comparison.regressed {
+            self.write_plain(" ")?;
+            self.write_pretty("REGRESSED", term::color::RED)?;
+        }
+        self.write_plain("\n")
+    }
+
     fn write_timeout(&mut self, desc: &TestDesc) -> io::Result<()> {
         self.write_plain(format!(
             "test {} has been running for over {} seconds\n",
@@ -269,6 +329,9 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         if state.options.display_output {
             self.write_successes(state)?;
         }
+        if !state.flakies.is_empty() {
+            self.write_flakies(state)?;
+        }
         let success = state.failed == 0;
         if !success {
             if !state.failures.is_empty() {
@@ -289,8 +352,16 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
             self.write_pretty("FAILED", term::color::RED)?;
         }
 
+        // Flaky tests can only occur when tests are retried, so they're left out of the summary
+        // unless there are some.
+        let flaky =
+            if state.flaky > 0 { format!("{} flaky; ", state.flaky) } else { String::new() };
+        let sharded_out = match state.sharded_out {
+            Some(sharded_out) => format!("; {sharded_out} in other shards"),
+            None => String::new(),
+        };
         let s = format!(
-            ". {} passed; {} failed; {} ignored; {} measured; {} filtered out",
+            ". {} passed; {} failed; {flaky}{} ignored; {} measured; {} filtered out{sharded_out}",
             state.passed, state.failed, state.ignored, state.measured, state.filtered_out
         );
 
@@ -0,0 +1,333 @@
+use std::collections::BTreeMap;
+use std::io::{self, prelude::Write};
+use std::time::Duration;
+
+use super::json::EscapedString;
+use super::OutputFormatter;
+use crate::{
+    bench::{fmt_bench_comparison, fmt_bench_samples, BenchComparison},
+    console::{ConsoleTestDiscoveryState, ConsoleTestState, OutputLocation},
+    options::TestShard,
+    test_result::TestResult,
+    time,
+    types::{TestDesc, TestType},
+};
+
+/// Formatter emitting a [TAP version 14](https://testanything.org/tap-version-14-specification.html)
+/// stream.
+///
+/// Tests are grouped into subtests by their module path (or by source file for doctests). Since
+/// the results of tests running in parallel arrive interleaved, the subtests are only written once
+/// the run is complete, while tests outside of any module are written as soon as their result is
+/// known. The test points are numbered in the order in which they are written, so the plan is
+/// written at the end of the run.
+pub(crate) struct TapFormatter<T> {
+    out: OutputLocation<T>,
+    /// The number of the last top-level test point written.
+    count: usize,
+    /// The finished tests of each subtest, by the name of the subtest.
+    subtests: BTreeMap<String, Vec<TestPoint>>,
+    /// The result of a benchmark, held back until its comparison with the baseline, if any, is
+    /// known, since that decides whether the benchmark failed.
+    pending_bench: Option<TestPoint>,
+    display_output: bool,
+}
+
+/// A finished test.
+struct TestPoint {
+    desc: TestDesc,
+    result: TestResult,
+    duration: Option<Duration>,
+    stdout: Vec<u8>,
+    /// The comparison with the baseline, for a benchmark that has one.
+    comparison: Option<BenchComparison>,
+}
+
+impl TestPoint {
+    fn failed(&self) -> bool {
+        is_failure(&self.result) || self.comparison.as_ref().is_some_and(|c| c.regressed)
+    }
+}
+
+impl<T: Write> TapFormatter<T> {
+    pub fn new(out: OutputLocation<T>) -> Self {
+        Self {
+            out,
+            count: 0,
+            subtests: BTreeMap::new(),
+            pending_bench: None,
+            display_output: false,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn output_location(&self) -> &OutputLocation<T> {
+        &self.out
+    }
+
+    fn write_line(&mut self, indent: &str, s: &str) -> io::Result<()> {
+        // As in the json formatter, write every line at once, so that it can't be interleaved
+        // with output from other threads.
+        self.out.write_all(format!("{indent}{s}\n").as_bytes())
+    }
+
+    fn write_pending_bench(&mut self, comparison: Option<&BenchComparison>) -> io::Result<()> {
+        match self.pending_bench.take() {
+            Some(mut point) => {
+                point.comparison = comparison.cloned();
+                self.finish_test(point)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Writes the test point of a test outside of any module, or adds it to its subtest.
+    fn finish_test(&mut self, point: TestPoint) -> io::Result<()> {
+        match split_group(&point.desc).0 {
+            Some(group) => {
+                self.subtests.entry(group.to_owned()).or_default().push(point);
+                Ok(())
+            }
+            None => {
+                self.count += 1;
+                self.write_test_point("", self.count, &point)
+            }
+        }
+    }
+
+    fn write_test_point(
+        &mut self,
+        indent: &str,
+        number: usize,
+        point: &TestPoint,
+    ) -> io::Result<()> {
+        let TestPoint { desc, result, duration, stdout, comparison } = point;
+        let name = escape_description(split_group(desc).1);
+        let failed = point.failed();
+
+        let mut yaml = Vec::new();
+        match *result {
+            TestResult::TrOk => {
+                self.write_line(indent, &format!("ok {number} - {name}"))?;
+            }
+            TestResult::TrIgnored => {
+                let reason = desc.ignore_message.map(|m| format!(" {m}")).unwrap_or_default();
+                self.write_line(indent, &format!("ok {number} - {name} # SKIP{reason}"))?;
+            }
+            TestResult::TrFlaky(attempts) => {
+                self.write_line(indent, &format!("ok {number} - {name}"))?;
+                yaml.push(format!("attempts: {attempts}"));
+            }
+            TestResult::TrBench(ref bs) => {
+                let ok = if failed { "not ok" } else { "ok" };
+                self.write_line(indent, &format!("{ok} {number} - {name}"))?;
+                yaml.push(format!("bench: \"{}\"", EscapedString(fmt_bench_samples(bs).trim())));
+                if let Some(comparison) = comparison {
+                    let change = fmt_bench_comparison(comparison);
+                    yaml.push(format!("compared_to_baseline: \"{}\"", EscapedString(change)));
+                }
+            }
+            TestResult::TrFailed => {
+                self.write_line(indent, &format!("not ok {number} - {name}"))?;
+            }
+            TestResult::TrFailedMsg(ref msg) => {
+                self.write_line(indent, &format!("not ok {number} - {name}"))?;
+                yaml.push(format!("message: \"{}\"", EscapedString(msg)));
+            }
+            TestResult::TrTimedFail => {
+                self.write_line(indent, &format!("not ok {number} - {name}"))?;
+                yaml.push("message: \"time limit exceeded\"".to_owned());
+            }
+        }
+
+        if failed {
+            yaml.insert(0, "severity: fail".to_owned());
+        }
+        if let Some(duration) = duration {
+            yaml.push(format!("duration_ms: {}", duration.as_secs_f64() * 1000.0));
+        }
+        if !stdout.is_empty() && (failed || self.display_output) {
+            yaml.push(yaml_block_scalar("stdout", &String::from_utf8_lossy(stdout)));
+        }
+
+        if !yaml.is_empty() {
+            // The YAML block is indented by two spaces relative to its test point.
+            let yaml_indent = format!("{indent}  ");
+            self.write_line(&yaml_indent, "---")?;
+            for entry in yaml {
+                for line in entry.lines() {
+                    self.write_line(&yaml_indent, line)?;
+                }
+            }
+            self.write_line(&yaml_indent, "...")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the name of the subtest a test belongs to, if any, and the name of the test within it.
+fn split_group(desc: &TestDesc) -> (Option<&str>, &str) {
+    let name = desc.name.as_slice();
+    let split = match desc.test_type {
+        // Doctests are named like "src/lib.rs - module::item (line 12)".
+        TestType::DocTest => name.split_once(" - "),
+        _ => name.rsplit_once("::"),
+    };
+    match split {
+        Some((group, test)) => (Some(group.trim()), test.trim()),
+        None => (None, name),
+    }
+}
+
+/// Escapes the characters that have a meaning in the description of a test point.
+fn escape_description(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('#', "\\#")
+}
+
+/// Formats a YAML block scalar holding `value`, preserving its line breaks.
+fn yaml_block_scalar(key: &str, value: &str) -> String {
+    // The indentation of the content has to be given explicitly if it starts with a space, as it
+    // would otherwise be taken as part of the indentation.
+    let indentation = if value.starts_with(' ') { "2" } else { "" };
+    // Keep the final line break only if there is one.
+    let chomping = if value.ends_with('\n') { "" } else { "-" };
+    let mut block = format!("{key}: |{indentation}{chomping}");
+    for line in value.lines() {
+        block.push_str("\n  ");
+        block.push_str(line);
+    }
+    block
+}
+
+fn is_failure(result: &TestResult) -> bool {
+    matches!(result, TestResult::TrFailed | TestResult::TrFailedMsg(_) | TestResult::TrTimedFail)
+}
+
+impl<T: Write> OutputFormatter for TapFormatter<T> {
+    fn write_discovery_start(&mut self) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::NotFound, "Not yet implemented!"))
+    }
+
+    fn write_test_discovered(&mut self, _desc: &TestDesc, _test_type: &str) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::NotFound, "Not yet implemented!"))
+    }
+
+    fn write_discovery_finish(&mut self, _state: &ConsoleTestDiscoveryState) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::NotFound, "Not yet implemented!"))
+    }
+
+    fn write_run_start(
+        &mut self,
+        _test_count: usize,
+        shuffle_seed: Option<u64>,
+        shard: Option<TestShard>,
+    ) -> io::Result<()> {
+        self.write_line("", "TAP version 14")?;
+        if let Some(shuffle_seed) = shuffle_seed {
+            self.write_line("", &format!("# shuffle seed: {shuffle_seed}"))?;
+        }
+        if let Some(TestShard { index, count }) = shard {
+            self.write_line("", &format!("# shard index: {index}, shard count: {count}"))?;
+        }
+        Ok(())
+    }
+
+    fn write_test_start(&mut self, _desc: &TestDesc) -> io::Result<()> {
+        // Benchmarks run one at a time, so a benchmark starting means that the previous one
+        // had no comparison with the baseline.
+        self.write_pending_bench(None)
+    }
+
+    fn write_timeout(&mut self, desc: &TestDesc) -> io::Result<()> {
+        self.write_pending_bench(None)?;
+        self.write_line(
+            "",
+            &format!(
+                "# test {} has been running for over {} seconds",
+                desc.name,
+                time::TEST_WARN_TIMEOUT_S
+            ),
+        )
+    }
+
+    fn write_result(
+        &mut self,
+        desc: &TestDesc,
+        result: &TestResult,
+        exec_time: Option<&time::TestExecTime>,
+        stdout: &[u8],
+        state: &ConsoleTestState,
+    ) -> io::Result<()> {
+        self.write_pending_bench(None)?;
+        self.display_output = state.options.display_output;
+        let point = TestPoint {
+            desc: desc.clone(),
+            result: result.clone(),
+            duration: exec_time.map(|t| t.0),
+            stdout: stdout.to_vec(),
+            comparison: None,
+        };
+        match result {
+            TestResult::TrBench(_) => {
+                self.pending_bench = Some(point);
+                Ok(())
+            }
+            _ => self.finish_test(point),
+        }
+    }
+
+    fn write_retry(
+        &mut self,
+        desc: &TestDesc,
+        _result: &TestResult,
+        _exec_time: Option<&time::TestExecTime>,
+        _stdout: &[u8],
+        attempt: usize,
+    ) -> io::Result<()> {
+        self.write_pending_bench(None)?;
+        self.write_line("", &format!("# test {} failed on attempt {attempt}, retrying", desc.name))
+    }
+
+    fn write_bench_comparison(
+        &mut self,
+        desc: &TestDesc,
+        comparison: &BenchComparison,
+    ) -> io::Result<()> {
+        debug_assert!(self.pending_bench.as_ref().is_some_and(|p| p.desc.name == desc.name));
+        self.write_pending_bench(Some(comparison))
+    }
+
+    fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
+        self.write_pending_bench(None)?;
+        for (group, mut points) in std::mem::take(&mut self.subtests) {
+            points.sort_by(|a, b| a.desc.name.as_slice().cmp(b.desc.name.as_slice()));
+            self.write_line("", &format!("# Subtest: {group}"))?;
+            self.write_line("    ", &format!("1..{}", points.len()))?;
+            for (i, point) in points.iter().enumerate() {
+                self.write_test_point("    ", i + 1, point)?;
+            }
+
+            self.count += 1;
+            let ok = if points.iter().any(TestPoint::failed) { "not ok" } else { "ok" };
+            let name = escape_description(&group);
+            self.write_line("", &format!("{ok} {} - {name}", self.count))?;
+        }
+        self.write_line("", &format!("1..{}", self.count))?;
+        let mut summary = format!(
+            "# passed: {}, failed: {}, flaky: {}, ignored: {}, measured: {}, filtered out: {}",
+            state.passed,
+            state.failed,
+            state.flaky,
+            state.ignored,
+            state.measured,
+            state.filtered_out
+        );
+        if let Some(sharded_out) = state.sharded_out {
+            summary.push_str(&format!(", in other shards: {sharded_out}"));
+        }
+        self.write_line("", &summary)?;
+
+        Ok(state.failed == 0)
+    }
+}
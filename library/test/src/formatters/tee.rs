@@ -0,0 +1,100 @@
+use std::io;
+
+use super::OutputFormatter;
+use crate::{
+    bench::BenchComparison,
+    console::{ConsoleTestDiscoveryState, ConsoleTestState},
+    options::TestShard,
+    test_result::TestResult,
+    time,
+    types::TestDesc,
+};
+
+/// Formatter passing everything on to two other formatters, used to write a report file while
+/// still showing the usual output on the console.
+pub(crate) struct TeeFormatter {
+    console: Box<dyn OutputFormatter>,
+    report: Box<dyn OutputFormatter>,
+}
+
+impl TeeFormatter {
+    pub fn new(console: Box<dyn OutputFormatter>, report: Box<dyn OutputFormatter>) -> Self {
+        Self { console, report }
+    }
+}
+
+impl OutputFormatter for TeeFormatter {
+    fn write_discovery_start(&mut self) -> io::Result<()> {
+        self.console.write_discovery_start()?;
+        self.report.write_discovery_start()
+    }
+
+    fn write_test_discovered(&mut self, desc: &TestDesc, test_type: &str) -> io::Result<()> {
+        self.console.write_test_discovered(desc, test_type)?;
+        self.report.write_test_discovered(desc, test_type)
+    }
+
+    fn write_discovery_finish(&mut self, state: &ConsoleTestDiscoveryState) -> io::Result<()> {
+        self.console.write_discovery_finish(state)?;
+        self.report.write_discovery_finish(state)
+    }
+
+    fn write_run_start(
+        &mut self,
+        test_count: usize,
+        shuffle_seed: Option<u64>,
+        shard: Option<TestShard>,
+    ) -> io::Result<()> {
+        self.console.write_run_start(test_count, shuffle_seed, shard)?;
+        self.report.write_run_start(test_count, shuffle_seed, shard)
+    }
+
+    fn write_test_start(&mut self, desc: &TestDesc) -> io::Result<()> {
+        self.console.write_test_start(desc)?;
+        self.report.write_test_start(desc)
+    }
+
+    fn write_timeout(&mut self, desc: &TestDesc) -> io::Result<()> {
+        self.console.write_timeout(desc)?;
+        self.report.write_timeout(desc)
+    }
+
+    fn write_result(
+        &mut self,
+        desc: &TestDesc,
+        result: &TestResult,
+        exec_time: Option<&time::TestExecTime>,
+        stdout: &[u8],
+        state: &ConsoleTestState,
+    ) -> io::Result<()> {
+        self.console.write_result(desc, result, exec_time, stdout, state)?;
+        self.report.write_result(desc, result, exec_time, stdout, state)
+    }
+
+    fn write_retry(
+        &mut self,
+        desc: &TestDesc,
+        result: &TestResult,
+        exec_time: Option<&time::TestExecTime>,
+        stdout: &[u8],
+        attempt: usize,
+    ) -> io::Result<()> {
+        self.console.write_retry(desc, result, exec_time, stdout, attempt)?;
+        self.report.write_retry(desc, result, exec_time, stdout, attempt)
+    }
+
+    fn write_bench_comparison(
+        &mut self,
+        desc: &TestDesc,
+        comparison: &BenchComparison,
+    ) -> io::Result<()> {
+        self.console.write_bench_comparison(desc, comparison)?;
+        self.report.write_bench_comparison(desc, comparison)
+    }
+
+    fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
+        let console_success = self.console.write_run_finish(state)?;
+        let report_success = self.report.write_run_finish(state)?;
+        Ok(console_success && report_success)
+    }
+}
@@ -0,0 +1,27 @@
+//! Helper module which splits the filtered tests into disjoint shards, so that
+//! several processes (possibly on different machines) can each run a subset.
+
+use crate::options::TestShard;
+use crate::types::{TestDescAndFn, TestName};
+
+/// Keeps only the tests that belong to `shard`.
+///
+/// A test is assigned to a shard based on a hash of its name alone, so the
+/// assignment doesn't depend on the order of the tests, on which other tests
+/// exist, or on the shuffle seed.
+pub fn shard_tests(shard: TestShard, tests: &mut Vec<TestDescAndFn>) {
+    tests.retain(|test| shard_of(&test.desc.name, shard.count) == shard.index);
+}
+
+fn shard_of(name: &TestName, count: usize) -> usize {
+    (fnv1a(name.as_slice().as_bytes()) % count as u64) as usize
+}
+
+// FNV-1a is used instead of `DefaultHasher`, whose algorithm is unspecified and
+// may change between releases, which would silently move tests between shards.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+}
@@ -30,7 +30,7 @@
 pub use self::types::TestName::*;
 pub use self::types::*;
 pub use self::ColorConfig::*;
-pub use cli::TestOpts;
+pub use cli::{TestOpts, DEFAULT_REGRESSION_THRESHOLD};
 
 // Module to be used by rustc to compile tests in libtest
 pub mod test {
@@ -40,7 +40,7 @@ pub mod test {
         cli::{parse_opts, TestOpts},
         filter_tests,
         helpers::metrics::{Metric, MetricMap},
-        options::{Options, RunIgnored, RunStrategy, ShouldPanic},
+        options::{Options, RunIgnored, RunStrategy, ShouldPanic, TestShard},
         run_test, test_main, test_main_static,
         test_result::{TestResult, TrFailed, TrFailedMsg, TrIgnored, TrOk},
         time::{TestExecTime, TestTimeOptions},
@@ -58,7 +58,7 @@ pub mod test {
     mem::ManuallyDrop,
     panic::{self, catch_unwind, AssertUnwindSafe, PanicInfo},
     process::{self, Command, Termination},
-    sync::mpsc::{channel, Sender},
+    sync::mpsc::{channel, Receiver, Sender},
     sync::{Arc, Mutex},
     thread,
     time::{Duration, Instant},
@@ -84,6 +84,7 @@ pub mod test {
 use event::{CompletedTest, TestEvent};
 use helpers::concurrency::get_concurrency;
 use helpers::exit_code::get_exit_code;
+use helpers::shard::shard_tests;
 use helpers::shuffle::{get_shuffle_seed, shuffle_tests};
 use options::RunStrategy;
 use test_result::*;
@@ -98,6 +99,12 @@ pub mod test {
 // The default console test runner. It accepts the command line
 // arguments and a vector of test_descs.
 pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>, options: Option<Options>) {
+    // Only the `test_main_static*` entry points know how to run a single test in a subprocess.
+    // Anything else would run the whole test suite again.
+    if env::var_os(SECONDARY_TEST_INVOKER_VAR).is_some() {
+        eprintln!("error: this test harness does not support running tests in a subprocess");
+        process::exit(ERROR_EXIT_CODE);
+    }
     let mut opts = match cli::parse_opts(args) {
         Some(Ok(o)) => o,
         Some(Err(msg)) => {
@@ -155,6 +162,12 @@ pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>, options: Option<Opt
 /// This is the entry point for the main function generated by `rustc --test`
 /// when panic=unwind.
 pub fn test_main_static(tests: &[&TestDescAndFn]) {
+    // If we're being run in SpawnedSecondary mode because of `--isolate`, run the
+    // test here. This will then exit the process.
+    if let Ok(name) = env::var(SECONDARY_TEST_INVOKER_VAR) {
+        run_spawned_secondary_test(name, tests, false);
+    }
+
     let args = env::args().collect::<Vec<_>>();
     let owned_tests: Vec<_> = tests.iter().map(make_owned_test).collect();
     test_main(&args, owned_tests, None)
@@ -169,35 +182,10 @@ pub fn test_main_static(tests: &[&TestDescAndFn]) {
 /// This is the entry point for the main function generated by `rustc --test`
 /// when panic=abort.
 pub fn test_main_static_abort(tests: &[&TestDescAndFn]) {
-    // If we're being run in SpawnedSecondary mode, run the test here. run_test
-    // will then exit the process.
+    // If we're being run in SpawnedSecondary mode, run the test here. This will
+    // then exit the process.
     if let Ok(name) = env::var(SECONDARY_TEST_INVOKER_VAR) {
-        env::remove_var(SECONDARY_TEST_INVOKER_VAR);
-
-        // Convert benchmarks to tests if we're not benchmarking.
-        let mut tests = tests.iter().map(make_owned_test).collect::<Vec<_>>();
-        if env::var(SECONDARY_TEST_BENCH_BENCHMARKS_VAR).is_ok() {
-            env::remove_var(SECONDARY_TEST_BENCH_BENCHMARKS_VAR);
-        } else {
-            tests = convert_benchmarks_to_tests(tests);
-        };
-
-        let test = tests
-            .into_iter()
-            .find(|test| test.desc.name.as_slice() == name)
-            .unwrap_or_else(|| panic!("couldn't find a test with the provided name '{name}'"));
-        let TestDescAndFn { desc, testfn } = test;
-        match testfn.into_runnable() {
-            Runnable::Test(runnable_test) => {
-                if runnable_test.is_dynamic() {
-                    panic!("only static tests are supported");
-                }
-                run_test_in_spawned_subprocess(desc, runnable_test);
-            }
-            Runnable::Bench(_) => {
-                panic!("benchmarks should not be executed into child processes")
-            }
-        }
+        run_spawned_secondary_test(name, tests, true);
     }
 
     let args = env::args().collect::<Vec<_>>();
@@ -205,6 +193,37 @@ pub fn test_main_static_abort(tests: &[&TestDescAndFn]) {
     test_main(&args, owned_tests, Some(Options::new().panic_abort(true)))
 }
 
+/// Runs the test called `name` in a subprocess spawned by `spawn_test_subprocess`,
+/// then exits the process.
+fn run_spawned_secondary_test(name: String, tests: &[&TestDescAndFn], panic_abort: bool) -> ! {
+    env::remove_var(SECONDARY_TEST_INVOKER_VAR);
+
+    // Convert benchmarks to tests if we're not benchmarking.
+    let mut tests = tests.iter().map(make_owned_test).collect::<Vec<_>>();
+    if env::var(SECONDARY_TEST_BENCH_BENCHMARKS_VAR).is_ok() {
+        env::remove_var(SECONDARY_TEST_BENCH_BENCHMARKS_VAR);
+    } else {
+        tests = convert_benchmarks_to_tests(tests);
+    };
+
+    let test = tests
+        .into_iter()
+        .find(|test| test.desc.name.as_slice() == name)
+        .unwrap_or_else(|| panic!("couldn't find a test with the provided name '{name}'"));
+    let TestDescAndFn { desc, testfn } = test;
+    match testfn.into_runnable() {
+        Runnable::Test(runnable_test) => {
+            if runnable_test.is_dynamic() {
+                panic!("only static tests are supported");
+            }
+            run_test_in_spawned_subprocess(desc, runnable_test, panic_abort);
+        }
+        Runnable::Bench(_) => {
+            panic!("benchmarks should not be executed into child processes")
+        }
+    }
+}
+
 /// Clones static values for putting into a dynamic vector, which test_main()
 /// needs to hand out ownership of tests to parallel test runners.
 ///
@@ -262,7 +281,7 @@ pub fn run_tests<F>(
 where
     F: FnMut(TestEvent) -> io::Result<()>,
 {
-    use std::collections::{self, HashMap};
+    use std::collections::{self, HashMap, HashSet};
     use std::hash::BuildHasherDefault;
     use std::sync::mpsc::RecvTimeoutError;
 
@@ -287,17 +306,80 @@ fn join(self, completed_test: &mut CompletedTest) {
     type TestMap =
         HashMap<TestId, RunningTest, BuildHasherDefault<collections::hash_map::DefaultHasher>>;
 
+    // A copy of a test that can be run again if it fails.
+    struct RetryEntry {
+        desc: TestDesc,
+        testfn: TestFn,
+        failed_attempts: usize,
+    }
+
+    type RetryMap =
+        HashMap<TestId, RetryEntry, BuildHasherDefault<collections::hash_map::DefaultHasher>>;
+
     struct TimeoutEntry {
         id: TestId,
         desc: TestDesc,
         timeout: Instant,
     }
 
+    type DeadlineMap = HashMap<
+        TestId,
+        (TestDesc, Instant),
+        BuildHasherDefault<collections::hash_map::DefaultHasher>,
+    >;
+
+    // Enforces `--timeout` for the tests that run in-process. Their threads can't be stopped, so
+    // a test that runs past its deadline is reported as failed and its thread is detached. What
+    // it reports if it ever finishes is ignored.
+    struct HardTimeouts {
+        timeout: Option<Duration>,
+        deadlines: DeadlineMap,
+        abandoned: HashSet<TestId, BuildHasherDefault<collections::hash_map::DefaultHasher>>,
+    }
+
+    impl HardTimeouts {
+        fn start(&mut self, id: TestId, desc: &TestDesc, strategy: RunStrategy) {
+            // Tests run in a subprocess are killed by the thread waiting for them instead.
+            if !matches!(strategy, RunStrategy::InProcess) {
+                return;
+            }
+            if let Some(timeout) = self.timeout {
+                self.deadlines.insert(id, (desc.clone(), time::get_test_deadline(timeout)));
+            }
+        }
+
+        fn next_deadline(&self) -> Option<Instant> {
+            self.deadlines.values().map(|&(_, deadline)| deadline).min()
+        }
+
+        // Gives up on a test that is past its deadline, if there is one, returning its result.
+        fn abandon_timed_out_test(&mut self) -> Option<CompletedTest> {
+            let now = Instant::now();
+            let id = *self.deadlines.iter().find(|(_, &(_, deadline))| deadline <= now)?.0;
+            let (desc, _) = self.deadlines.remove(&id).unwrap();
+            self.abandoned.insert(id);
+            let message = format!(
+                "test was still running after the timeout of {:.3}s\n",
+                self.timeout.unwrap().as_secs_f64()
+            );
+            Some(CompletedTest::new(id, desc, TrTimedFail, None, message.into_bytes()))
+        }
+    }
+
     let tests_len = tests.len();
 
     let mut filtered = FilteredTests { tests: Vec::new(), benches: Vec::new(), next_id: 0 };
 
-    for test in filter_tests(opts, tests) {
+    let mut tests = filter_tests(opts, tests);
+    let filtered_out = tests_len - tests.len();
+    // Tests run by other shards aren't filtered out by the user, so they're counted separately.
+    let sharded_out = opts.shard.map(|shard| {
+        let unsharded_len = tests.len();
+        shard_tests(shard, &mut tests);
+        unsharded_len - tests.len()
+    });
+
+    for test in tests {
         let mut desc = test.desc;
         desc.name = desc.name.with_padding(test.testfn.padding());
 
@@ -322,17 +404,27 @@ struct TimeoutEntry {
         };
     }
 
-    let filtered_out = tests_len - filtered.total_len();
-    let event = TestEvent::TeFilteredOut(filtered_out);
+    let event = TestEvent::TeFilteredOut(filtered_out, sharded_out);
     notify_about_test_event(event)?;
 
     let shuffle_seed = get_shuffle_seed(opts);
 
-    let event = TestEvent::TeFiltered(filtered.total_len(), shuffle_seed);
+    let event = TestEvent::TeFiltered(filtered.total_len(), shuffle_seed, opts.shard);
     notify_about_test_event(event)?;
 
     let concurrency = opts.test_threads.unwrap_or_else(get_concurrency);
 
+    // Keep copies of the tests that can be run more than once, in case they need to be retried.
+    let mut retries = RetryMap::default();
+    if opts.retries > 0 {
+        for (id, test) in &filtered.tests {
+            if let Some(testfn) = test.testfn.try_clone() {
+                let desc = test.desc.clone();
+                retries.insert(*id, RetryEntry { desc, testfn, failed_attempts: 0 });
+            }
+        }
+    }
+
     let mut remaining = filtered.tests;
     if let Some(shuffle_seed) = shuffle_seed {
         shuffle_tests(shuffle_seed, &mut remaining);
@@ -343,7 +435,7 @@ struct TimeoutEntry {
     let mut pending = 0;
 
     let (tx, rx) = channel::<CompletedTest>();
-    let run_strategy = if opts.options.panic_abort && !opts.force_run_in_process {
+    let run_strategy = if (opts.options.panic_abort || opts.isolate) && !opts.force_run_in_process {
         RunStrategy::SpawnPrimary
     } else {
         RunStrategy::InProcess
@@ -351,6 +443,11 @@ struct TimeoutEntry {
 
     let mut running_tests: TestMap = HashMap::default();
     let mut timeout_queue: VecDeque<TimeoutEntry> = VecDeque::new();
+    let mut hard_timeouts = HardTimeouts {
+        timeout: opts.timeout,
+        deadlines: HashMap::default(),
+        abandoned: HashSet::default(),
+    };
 
     fn get_timed_out_tests(
         running_tests: &TestMap,
@@ -370,25 +467,126 @@ fn get_timed_out_tests(
         timed_out
     }
 
-    fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
-        timeout_queue.front().map(|&TimeoutEntry { timeout: next_timeout, .. }| {
+    // Returns the test to run again, and the number of the attempt that just failed, if the test
+    // failed and has retries left. If the test passed after failing before, its result is
+    // changed to `TrFlaky`.
+    fn get_retry(
+        opts: &TestOpts,
+        retries: &mut RetryMap,
+        hard_timeouts: &HardTimeouts,
+        completed_test: &mut CompletedTest,
+    ) -> Option<(TestDescAndFn, usize)> {
+        // A test that ran past `--timeout` in-process is still running, so it can't be retried.
+        if hard_timeouts.abandoned.contains(&completed_test.id) {
+            return None;
+        }
+        let entry = retries.get_mut(&completed_test.id)?;
+        match completed_test.result {
+            TrFailed | TrFailedMsg(_) | TrTimedFail if entry.failed_attempts < opts.retries => {
+                entry.failed_attempts += 1;
+                let desc = entry.desc.clone();
+                let testfn = entry.testfn.try_clone().unwrap();
+                Some((TestDescAndFn { desc, testfn }, entry.failed_attempts))
+            }
+            TrOk if entry.failed_attempts > 0 => {
+                completed_test.result = TrFlaky(entry.failed_attempts + 1);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn calc_timeout(
+        timeout_queue: &VecDeque<TimeoutEntry>,
+        hard_timeouts: &HardTimeouts,
+    ) -> Option<Duration> {
+        let next_warning = timeout_queue.front().map(|entry| entry.timeout);
+        next_warning.into_iter().chain(hard_timeouts.next_deadline()).min().map(|next_timeout| {
             let now = Instant::now();
             if next_timeout >= now { next_timeout - now } else { Duration::new(0, 0) }
         })
     }
 
+    // Waits for the next test to complete, warning about the tests that have been running for
+    // long, and failing the ones that run past `--timeout`.
+    fn recv_test_result<F>(
+        rx: &Receiver<CompletedTest>,
+        running_tests: &mut TestMap,
+        timeout_queue: &mut VecDeque<TimeoutEntry>,
+        hard_timeouts: &mut HardTimeouts,
+        notify_about_test_event: &mut F,
+    ) -> io::Result<CompletedTest>
+    where
+        F: FnMut(TestEvent) -> io::Result<()>,
+    {
+        loop {
+            let res = match calc_timeout(timeout_queue, hard_timeouts) {
+                Some(timeout) => {
+                    let res = rx.recv_timeout(timeout);
+                    for test in get_timed_out_tests(running_tests, timeout_queue) {
+                        let event = TestEvent::TeTimeout(test);
+                        notify_about_test_event(event)?;
+                    }
+                    res
+                }
+                None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            match res {
+                Ok(completed_test) if hard_timeouts.abandoned.contains(&completed_test.id) => {
+                    // The test was already reported as failed when it ran past its deadline.
+                }
+                Ok(mut completed_test) => {
+                    hard_timeouts.deadlines.remove(&completed_test.id);
+                    let running_test = running_tests.remove(&completed_test.id).unwrap();
+                    running_test.join(&mut completed_test);
+                    return Ok(completed_test);
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    if let Some(completed_test) = hard_timeouts.abandon_timed_out_test() {
+                        // Dropping the join handle detaches the thread, which keeps running.
+                        running_tests.remove(&completed_test.id);
+                        return Ok(completed_test);
+                    }
+                    // Result is not yet ready, continue waiting.
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    unreachable!("the sender of test results is never dropped")
+                }
+            }
+        }
+    }
+
     if concurrency == 1 {
         while !remaining.is_empty() {
             let (id, test) = remaining.pop_front().unwrap();
             let event = TestEvent::TeWait(test.desc.clone());
             notify_about_test_event(event)?;
+            let strategy = get_run_strategy(opts, test.testfn.is_dynamic(), run_strategy);
+            hard_timeouts.start(id, &test.desc, strategy);
             let join_handle = run_test(opts, !opts.run_tests, id, test, run_strategy, tx.clone());
+            running_tests.insert(id, RunningTest { join_handle });
             // Wait for the test to complete.
-            let mut completed_test = rx.recv().unwrap();
-            RunningTest { join_handle }.join(&mut completed_test);
+            let mut completed_test = recv_test_result(
+                &rx,
+                &mut running_tests,
+                &mut timeout_queue,
+                &mut hard_timeouts,
+                &mut notify_about_test_event,
+            )?;
+
+            if let Some((test, attempt)) =
+                get_retry(opts, &mut retries, &hard_timeouts, &mut completed_test)
+            {
+                let id = completed_test.id;
+                let event = TestEvent::TeRetry(completed_test, attempt);
+                notify_about_test_event(event)?;
+                remaining.push_front((id, test));
+                continue;
+            }
 
             let fail_fast = match completed_test.result {
-                TrIgnored | TrOk | TrBench(_) => false,
+                TrIgnored | TrOk | TrBench(_) | TrFlaky(_) => false,
                 TrFailed | TrFailedMsg(_) | TrTimedFail => opts.fail_fast,
             };
 
@@ -396,6 +594,8 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
             notify_about_test_event(event)?;
 
             if fail_fast {
+                // Prevent abandoned test threads from panicking
+                std::mem::forget(rx);
                 return Ok(());
             }
         }
@@ -408,6 +608,8 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
 
                 let event = TestEvent::TeWait(desc.clone());
                 notify_about_test_event(event)?; //here no pad
+                let strategy = get_run_strategy(opts, test.testfn.is_dynamic(), run_strategy);
+                hard_timeouts.start(id, &desc, strategy);
                 let join_handle =
                     run_test(opts, !opts.run_tests, id, test, run_strategy, tx.clone());
                 running_tests.insert(id, RunningTest { join_handle });
@@ -415,36 +617,30 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
                 pending += 1;
             }
 
-            let mut res;
-            loop {
-                if let Some(timeout) = calc_timeout(&timeout_queue) {
-                    res = rx.recv_timeout(timeout);
-                    for test in get_timed_out_tests(&running_tests, &mut timeout_queue) {
-                        let event = TestEvent::TeTimeout(test);
-                        notify_about_test_event(event)?;
-                    }
-
-                    match res {
-                        Err(RecvTimeoutError::Timeout) => {
-                            // Result is not yet ready, continue waiting.
-                        }
-                        _ => {
-                            // We've got a result, stop the loop.
-                            break;
-                        }
-                    }
-                } else {
-                    res = rx.recv().map_err(|_| RecvTimeoutError::Disconnected);
-                    break;
-                }
+            let mut completed_test = recv_test_result(
+                &rx,
+                &mut running_tests,
+                &mut timeout_queue,
+                &mut hard_timeouts,
+                &mut notify_about_test_event,
+            )?;
+
+            if let Some((test, attempt)) =
+                get_retry(opts, &mut retries, &hard_timeouts, &mut completed_test)
+            {
+                let id = completed_test.id;
+                let event = TestEvent::TeRetry(completed_test, attempt);
+                notify_about_test_event(event)?;
+                // The retry keeps the id of the test, so the warning about the attempt that just
+                // failed must not be taken for one about the retry.
+                timeout_queue.retain(|entry| entry.id != id);
+                remaining.push_front((id, test));
+                pending -= 1;
+                continue;
             }
 
-            let mut completed_test = res.unwrap();
-            let running_test = running_tests.remove(&completed_test.id).unwrap();
-            running_test.join(&mut completed_test);
-
             let fail_fast = match completed_test.result {
-                TrIgnored | TrOk | TrBench(_) => false,
+                TrIgnored | TrOk | TrBench(_) | TrFlaky(_) => false,
                 TrFailed | TrFailedMsg(_) | TrTimedFail => opts.fail_fast,
             };
 
@@ -466,14 +662,25 @@ fn calc_timeout(timeout_queue: &VecDeque<TimeoutEntry>) -> Option<Duration> {
             let event = TestEvent::TeWait(b.desc.clone());
             notify_about_test_event(event)?;
             let join_handle = run_test(opts, false, id, b, run_strategy, tx.clone());
+            running_tests.insert(id, RunningTest { join_handle });
             // Wait for the test to complete.
-            let mut completed_test = rx.recv().unwrap();
-            RunningTest { join_handle }.join(&mut completed_test);
+            let completed_test = recv_test_result(
+                &rx,
+                &mut running_tests,
+                &mut timeout_queue,
+                &mut hard_timeouts,
+                &mut notify_about_test_event,
+            )?;
 
             let event = TestEvent::TeResult(completed_test);
             notify_about_test_event(event)?;
         }
     }
+
+    if !hard_timeouts.abandoned.is_empty() {
+        // Prevent abandoned test threads from panicking if they finish before the process exits
+        std::mem::forget(rx);
+    }
     Ok(())
 }
 
@@ -533,6 +740,18 @@ pub fn convert_benchmarks_to_tests(tests: Vec<TestDescAndFn>) -> Vec<TestDescAnd
         .collect()
 }
 
+/// Returns how a test is actually run when `strategy` is requested for the test run.
+fn get_run_strategy(opts: &TestOpts, is_dynamic: bool, strategy: RunStrategy) -> RunStrategy {
+    match strategy {
+        // A subprocess can't look up a dynamic test by its name, so with `--isolate` these still
+        // run in-process.
+        RunStrategy::SpawnPrimary if is_dynamic && !opts.options.panic_abort => {
+            RunStrategy::InProcess
+        }
+        _ => strategy,
+    }
+}
+
 pub fn run_test(
     opts: &TestOpts,
     force_ignore: bool,
@@ -556,37 +775,35 @@ pub fn run_test(
 
     match testfn.into_runnable() {
         Runnable::Test(runnable_test) => {
-            if runnable_test.is_dynamic() {
-                match strategy {
-                    RunStrategy::InProcess => (),
-                    _ => panic!("Cannot run dynamic test fn out-of-process"),
-                };
+            let strategy = get_run_strategy(opts, runnable_test.is_dynamic(), strategy);
+            if runnable_test.is_dynamic() && !matches!(strategy, RunStrategy::InProcess) {
+                panic!("Cannot run dynamic test fn out-of-process");
             }
-
             let name = desc.name.clone();
             let nocapture = opts.nocapture;
             let time_options = opts.time_options;
-            let bench_benchmarks = opts.bench_benchmarks;
+            // The report file has the time taken by every test.
+            let report_time = time_options.is_some() || opts.report_file.is_some();
+            let subprocess_opts = SubprocessOpts {
+                report_time,
+                time_opts: time_options,
+                timeout: opts.timeout,
+                bench_benchmarks: opts.bench_benchmarks,
+            };
 
             let runtest = move || match strategy {
                 RunStrategy::InProcess => run_test_in_process(
                     id,
                     desc,
                     nocapture,
-                    time_options.is_some(),
+                    report_time,
                     runnable_test,
                     monitor_ch,
                     time_options,
                 ),
-                RunStrategy::SpawnPrimary => spawn_test_subprocess(
-                    id,
-                    desc,
-                    nocapture,
-                    time_options.is_some(),
-                    monitor_ch,
-                    time_options,
-                    bench_benchmarks,
-                ),
+                RunStrategy::SpawnPrimary => {
+                    spawn_test_subprocess(id, desc, nocapture, monitor_ch, subprocess_opts)
+                }
             };
 
             // If the platform is single-threaded we're just going to run
@@ -676,15 +893,22 @@ fn fold_err<T, E>(
     }
 }
 
+/// The options of the test run that matter to `spawn_test_subprocess`.
+struct SubprocessOpts {
+    report_time: bool,
+    time_opts: Option<time::TestTimeOptions>,
+    timeout: Option<Duration>,
+    bench_benchmarks: bool,
+}
+
 fn spawn_test_subprocess(
     id: TestId,
     desc: TestDesc,
     nocapture: bool,
-    report_time: bool,
     monitor_ch: Sender<CompletedTest>,
-    time_opts: Option<time::TestTimeOptions>,
-    bench_benchmarks: bool,
+    opts: SubprocessOpts,
 ) {
+    let SubprocessOpts { report_time, time_opts, timeout, bench_benchmarks } = opts;
     let (result, test_output, exec_time) = (|| {
         let args = env::args().collect::<Vec<_>>();
         let current_exe = &args[0];
@@ -697,10 +921,20 @@ fn spawn_test_subprocess(
         if nocapture {
             command.stdout(process::Stdio::inherit());
             command.stderr(process::Stdio::inherit());
+        } else {
+            // `Command::output` does this by default, but not `Command::spawn`.
+            command.stdout(process::Stdio::piped());
+            command.stderr(process::Stdio::piped());
         }
 
         let start = report_time.then(Instant::now);
-        let output = match command.output() {
+        let output = match timeout {
+            Some(timeout) => command
+                .spawn()
+                .and_then(|child| wait_with_deadline(child, time::get_test_deadline(timeout))),
+            None => command.output().map(|out| (Some(out.status), out.stdout, out.stderr)),
+        };
+        let (status, stdout, stderr) = match output {
             Ok(out) => out,
             Err(e) => {
                 let err = format!("Failed to spawn {} as child for test: {:?}", args[0], e);
@@ -712,11 +946,16 @@ fn spawn_test_subprocess(
             TestExecTime(duration)
         });
 
-        let std::process::Output { stdout, stderr, status } = output;
         let mut test_output = stdout;
         formatters::write_stderr_delimiter(&mut test_output, &desc.name);
         test_output.extend_from_slice(&stderr);
 
+        let Some(status) = status else {
+            let timeout = timeout.unwrap().as_secs_f64();
+            writeln!(&mut test_output, "test killed after running for over {timeout:.3}s").unwrap();
+            return (TrTimedFail, test_output, exec_time);
+        };
+
         let result = match (|| -> Result<TestResult, String> {
             let exit_code = get_exit_code(status)?;
             Ok(get_result_from_exit_code(&desc, exit_code, &time_opts, &exec_time))
@@ -735,7 +974,89 @@ fn spawn_test_subprocess(
     monitor_ch.send(message).unwrap();
 }
 
-fn run_test_in_spawned_subprocess(desc: TestDesc, runnable_test: RunnableTest) -> ! {
+/// Waits for `child` to exit, killing it if it is still running at `deadline`, and collects its
+/// output. The returned exit status is `None` if the child had to be killed.
+fn wait_with_deadline(
+    mut child: process::Child,
+    deadline: Instant,
+) -> io::Result<(Option<process::ExitStatus>, Vec<u8>, Vec<u8>)> {
+    // Drain the pipes on their own threads, so that the child can't get stuck writing to a full
+    // pipe while we are polling for it to exit.
+    fn read_to_end<R: io::Read + Send + 'static>(pipe: Option<R>) -> Receiver<Vec<u8>> {
+        let (tx, rx) = channel();
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut buf);
+            }
+            let _ = tx.send(buf);
+        });
+        rx
+    }
+    let stdout = read_to_end(child.stdout.take());
+    let stderr = read_to_end(child.stderr.take());
+
+    let mut poll_interval = Duration::from_millis(1);
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            // This only fails if the child has exited in the meantime, which is fine too.
+            let _ = child.kill();
+            child.wait()?;
+            break None;
+        }
+        thread::sleep(poll_interval.min(deadline - now));
+        poll_interval = (poll_interval * 2).min(Duration::from_millis(100));
+    };
+
+    // Processes spawned by a killed child may still hold on to its pipes, so don't wait long for
+    // them to be closed.
+    let give_up = status.is_none().then(|| Instant::now() + Duration::from_secs(1));
+    let collect = |output: Receiver<Vec<u8>>| match give_up {
+        Some(give_up) => output
+            .recv_timeout(give_up.saturating_duration_since(Instant::now()))
+            .unwrap_or_default(),
+        None => output.recv().unwrap_or_default(),
+    };
+    Ok((status, collect(stdout), collect(stderr)))
+}
+
+fn run_test_in_spawned_subprocess(
+    desc: TestDesc,
+    runnable_test: RunnableTest,
+    panic_abort: bool,
+) -> ! {
+    // We don't support serializing TrFailedMsg, so just
+    // print the message out to stderr.
+    fn print_failure_message(test_result: &TestResult) {
+        if let TrFailedMsg(msg) = test_result {
+            eprintln!("{msg}");
+        }
+    }
+
+    fn exit_with_result(test_result: TestResult) -> ! {
+        if let TrOk = test_result {
+            process::exit(test_result::TR_OK);
+        } else {
+            process::exit(test_result::TR_FAILED);
+        }
+    }
+
+    if !panic_abort {
+        // With panic=unwind, a test may catch its own panics, or join threads that panicked,
+        // so only the outcome of the test counts, as when it runs in-process.
+        let result = fold_err(catch_unwind(AssertUnwindSafe(|| runnable_test.run())));
+        let test_result = match result {
+            Ok(()) => calc_result(&desc, Ok(()), &None, &None),
+            Err(e) => calc_result(&desc, Err(e.as_ref()), &None, &None),
+        };
+        print_failure_message(&test_result);
+        exit_with_result(test_result);
+    }
+
     let builtin_panic_hook = panic::take_hook();
     let record_result = Arc::new(move |panic_info: Option<&'_ PanicInfo<'_>>| {
         let test_result = match panic_info {
@@ -743,21 +1064,12 @@ fn run_test_in_spawned_subprocess(desc: TestDesc, runnable_test: RunnableTest) -
             None => calc_result(&desc, Ok(()), &None, &None),
         };
 
-        // We don't support serializing TrFailedMsg, so just
-        // print the message out to stderr.
-        if let TrFailedMsg(msg) = &test_result {
-            eprintln!("{msg}");
-        }
-
+        print_failure_message(&test_result);
         if let Some(info) = panic_info {
             builtin_panic_hook(info);
         }
 
-        if let TrOk = test_result {
-            process::exit(test_result::TR_OK);
-        } else {
-            process::exit(test_result::TR_FAILED);
-        }
+        exit_with_result(test_result);
     });
     let record_result2 = record_result.clone();
     panic::set_hook(Box::new(move |info| record_result2(Some(info))));
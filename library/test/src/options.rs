@@ -36,6 +36,8 @@ pub enum OutputFormat {
     Json,
     /// JUnit output
     Junit,
+    /// TAP (Test Anything Protocol) output
+    Tap,
 }
 
 /// Whether ignored test should be run or not
@@ -47,6 +49,15 @@ pub enum RunIgnored {
     Only,
 }
 
+/// Which slice of the filtered tests this process is responsible for
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TestShard {
+    /// Zero-based index of this shard, always less than `count`
+    pub index: usize,
+    /// Total number of shards the tests are split into
+    pub count: usize,
+}
+
 #[derive(Clone, Copy)]
 pub enum RunStrategy {
     /// Runs the test in the current process, and sends the result back over the
@@ -279,6 +279,28 @@ fn percentile_of_sorted(sorted_samples: &[f64], pct: f64) -> f64 {
     lo + (hi - lo) * d
 }
 
+/// Confidence interval for the difference between the means of two sample sets, `new` minus `old`,
+/// each described by its summary and the number of samples it was computed from. `z` is the
+/// critical value of the standard normal distribution for the desired confidence level, e.g.
+/// `1.96` for a 95% interval.
+///
+/// This uses the normal approximation of Welch's t-test, which does not assume that both sample
+/// sets have the same variance, and is reasonable once each set has a few dozen samples.
+///
+/// See: <https://en.wikipedia.org/wiki/Welch%27s_t-test>
+pub fn mean_difference_interval(
+    old: &Summary,
+    old_count: usize,
+    new: &Summary,
+    new_count: usize,
+    z: f64,
+) -> (f64, f64) {
+    assert!(old_count > 0 && new_count > 0);
+    let difference = new.mean - old.mean;
+    let standard_error = (old.var / old_count as f64 + new.var / new_count as f64).sqrt();
+    (difference - z * standard_error, difference + z * standard_error)
+}
+
 /// Winsorize a set of samples, replacing values above the `100-pct` percentile
 /// and below the `pct` percentile with those percentiles themselves. This is a
 /// way of minimizing the effect of outliers, at the cost of biasing the sample.
@@ -571,6 +571,20 @@ fn test_sum_f64_between_ints_that_sum_to_0() {
     assert_eq!([1e30f64, 1.2f64, -1e30f64].sum(), 1.2);
 }
 
+#[test]
+fn test_mean_difference_interval() {
+    let old = Summary::new(&[10.0, 11.0, 9.0, 10.0, 10.0]);
+    let new = Summary::new(&[12.0, 13.0, 11.0, 12.0, 12.0]);
+
+    let (lo, hi) = mean_difference_interval(&old, 5, &new, 5, 1.96);
+    assert_approx_eq!((lo + hi) / 2.0, 2.0);
+    // Both sets have a variance of 0.5, so the standard error is sqrt(0.5 / 5 + 0.5 / 5).
+    assert_approx_eq!(hi - lo, 2.0 * 1.96 * 0.2_f64.sqrt());
+
+    let (lo, hi) = mean_difference_interval(&old, 5, &old, 5, 1.96);
+    assert!(lo < 0.0 && hi > 0.0);
+}
+
 #[bench]
 pub fn sum_three_items(b: &mut Bencher) {
     b.iter(|| {
@@ -21,6 +21,9 @@ pub enum TestResult {
     TrIgnored,
     TrBench(BenchSamples),
     TrTimedFail,
+    /// The test failed at first, but passed when it was retried. Holds the
+    /// total number of attempts, including the passing one.
+    TrFlaky(usize),
 }
 
 /// Creates a `TestResult` depending on the raw result of test execution
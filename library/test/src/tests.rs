@@ -19,6 +19,7 @@
         TestDesc,
         TestDescAndFn,
         TestOpts,
+        TestShard,
         TrIgnored,
         TrOk,
         // FIXME (introduced by #65251)
@@ -37,6 +38,7 @@ fn new() -> TestOpts {
             filters: vec![],
             filter_exact: false,
             force_run_in_process: false,
+            isolate: false,
             exclude_should_panic: false,
             run_ignored: RunIgnored::No,
             run_tests: false,
@@ -47,9 +49,16 @@ fn new() -> TestOpts {
             format: OutputFormat::Pretty,
             shuffle: false,
             shuffle_seed: None,
+            shard: None,
+            retries: 0,
+            save_baseline: None,
+            baseline: None,
+            regression_threshold: DEFAULT_REGRESSION_THRESHOLD,
             test_threads: None,
             skip: vec![],
             time_options: None,
+            timeout: None,
+            report_file: None,
             options: Options::new(),
             fail_fast: false,
         }
@@ -474,6 +483,33 @@ fn test_time_options_threshold() {
     }
 }
 
+#[test]
+#[cfg(not(target_os = "emscripten"))]
+fn isolate_runs_dynamic_tests_in_process() {
+    let desc = TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName("whatever"),
+            ignore: false,
+            ignore_message: None,
+            source_file: "",
+            start_line: 0,
+            start_col: 0,
+            end_line: 0,
+            end_col: 0,
+            should_panic: ShouldPanic::No,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+        },
+        testfn: DynTestFn(Box::new(|| Ok(()))),
+    };
+    let (tx, rx) = channel();
+    let opts = TestOpts { isolate: true, ..TestOpts::new() };
+    run_test(&opts, false, TestId(0), desc, RunStrategy::SpawnPrimary, tx);
+    let result = rx.recv().unwrap().result;
+    assert_eq!(result, TrOk);
+}
+
 #[test]
 fn parse_ignored_flag() {
     let args = vec!["progname".to_string(), "filter".to_string(), "--ignored".to_string()];
@@ -748,6 +784,234 @@ pub fn order_depends_on_more_than_seed() {
     assert!(left.iter().zip(right).any(|(a, b)| a.0 != b.0));
 }
 
+#[test]
+fn parse_shard_options() {
+    let args = |extra: &[&str]| {
+        let mut args = vec!["progname".to_string(), "-Zunstable-options".to_string()];
+        args.extend(extra.iter().map(|s| s.to_string()));
+        args
+    };
+
+    let opts = parse_opts(&args(&["--shard-index", "2", "--shard-count", "3"])).unwrap().unwrap();
+    assert_eq!(opts.shard, Some(TestShard { index: 2, count: 3 }));
+
+    assert!(parse_opts(&args(&["--shard-index", "1"])).unwrap().is_err());
+    assert!(parse_opts(&args(&["--shard-index", "3", "--shard-count", "3"])).unwrap().is_err());
+    assert!(parse_opts(&args(&["--shard-index", "0", "--shard-count", "0"])).unwrap().is_err());
+}
+
+#[test]
+pub fn shards_partition_tests() {
+    let all_names: Vec<_> = sample_tests().into_iter().map(|t| t.desc.name).collect();
+
+    let count = 3;
+    let mut sharded_names = Vec::new();
+    for index in 0..count {
+        let mut tests = sample_tests();
+        helpers::shard::shard_tests(TestShard { index, count }, &mut tests);
+
+        // Sharding must not depend on the order of the tests.
+        let mut reversed = sample_tests();
+        reversed.reverse();
+        helpers::shard::shard_tests(TestShard { index, count }, &mut reversed);
+        reversed.reverse();
+        assert!(tests.iter().zip(&reversed).all(|(a, b)| a.desc.name == b.desc.name));
+
+        sharded_names.extend(tests.into_iter().map(|t| t.desc.name));
+    }
+
+    assert_eq!(sharded_names.len(), all_names.len());
+    assert!(all_names.iter().all(|name| sharded_names.contains(name)));
+}
+
+#[test]
+fn sharded_out_tests_are_not_filtered_out() {
+    let shard = TestShard { index: 1, count: 3 };
+    let opts = TestOpts { filters: vec!["test::".into()], shard: Some(shard), ..TestOpts::new() };
+    let mut tests = filter_tests(&opts, sample_tests());
+    let matching = tests.len();
+    helpers::shard::shard_tests(shard, &mut tests);
+
+    let mut counts = None;
+    let notify = |event: TestEvent| {
+        if let TestEvent::TeFilteredOut(filtered_out, sharded_out) = event {
+            counts = Some((filtered_out, sharded_out));
+        }
+        Ok(())
+    };
+    run_tests(&opts, sample_tests(), notify).unwrap();
+    let filtered_out = sample_tests().len() - matching;
+    assert_eq!(counts, Some((filtered_out, Some(matching - tests.len()))));
+}
+
+fn retried_test_events(testfn: fn() -> Result<(), String>, retries: usize) -> Vec<TestEvent> {
+    let desc = TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName("whatever"),
+            ignore: false,
+            ignore_message: None,
+            source_file: "",
+            start_line: 0,
+            start_col: 0,
+            end_line: 0,
+            end_col: 0,
+            should_panic: ShouldPanic::No,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+        },
+        testfn: StaticTestFn(testfn),
+    };
+    let mut events = Vec::new();
+    let notify = |event: TestEvent| {
+        if let TestEvent::TeResult(..) | TestEvent::TeRetry(..) = event {
+            events.push(event);
+        }
+        Ok(())
+    };
+    let opts = TestOpts { run_tests: true, retries, test_threads: Some(1), ..TestOpts::new() };
+    run_tests(&opts, vec![desc], notify).unwrap();
+    events
+}
+
+#[test]
+fn test_retry_flaky_test() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+    fn f() -> Result<(), String> {
+        if ATTEMPTS.fetch_add(1, Ordering::SeqCst) < 2 { Err("not yet".into()) } else { Ok(()) }
+    }
+
+    let events = retried_test_events(f, 3);
+    assert_eq!(events.len(), 3);
+    assert!(matches!(events[0], TestEvent::TeRetry(_, 1)));
+    assert!(matches!(events[1], TestEvent::TeRetry(_, 2)));
+    match events[2] {
+        TestEvent::TeResult(ref completed_test) => assert_eq!(completed_test.result, TrFlaky(3)),
+        _ => panic!("expected the final result of the test"),
+    }
+}
+
+#[test]
+fn test_retry_failing_test() {
+    fn f() -> Result<(), String> {
+        Err("always fails".into())
+    }
+
+    let events = retried_test_events(f, 2);
+    assert_eq!(events.len(), 3);
+    match events[2] {
+        TestEvent::TeResult(ref completed_test) => assert_ne!(completed_test.result, TrOk),
+        _ => panic!("expected the final result of the test"),
+    }
+
+    // Without retries, a failing test is reported right away.
+    let events = retried_test_events(f, 0);
+    assert_eq!(events.len(), 1);
+}
+
+#[test]
+#[cfg(not(any(target_os = "emscripten", target_family = "wasm")))]
+fn test_timeout_fails_hung_test() {
+    fn hangs() -> Result<(), String> {
+        loop {
+            std::thread::park();
+        }
+    }
+    fn passes() -> Result<(), String> {
+        Ok(())
+    }
+
+    let test = |name, testfn| TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName(name),
+            ignore: false,
+            ignore_message: None,
+            source_file: "",
+            start_line: 0,
+            start_col: 0,
+            end_line: 0,
+            end_col: 0,
+            should_panic: ShouldPanic::No,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+        },
+        testfn: StaticTestFn(testfn),
+    };
+
+    for test_threads in [1, 2] {
+        let mut results = Vec::new();
+        let notify = |event: TestEvent| {
+            if let TestEvent::TeResult(completed_test) = event {
+                results.push((completed_test.desc.name.to_string(), completed_test.result));
+            }
+            Ok(())
+        };
+        let opts = TestOpts {
+            run_tests: true,
+            test_threads: Some(test_threads),
+            timeout: Some(Duration::from_millis(100)),
+            ..TestOpts::new()
+        };
+        let tests = vec![test("hangs", hangs), test("passes", passes)];
+        run_tests(&opts, tests, notify).unwrap();
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            results,
+            [("hangs".to_string(), TrTimedFail), ("passes".to_string(), TrOk)],
+            "with {test_threads} test threads"
+        );
+    }
+}
+
+#[test]
+#[cfg(not(any(target_os = "emscripten", target_family = "wasm")))]
+fn test_timeout_fails_hung_dynamic_test_with_isolate() {
+    let test = |name, testfn: fn() -> Result<(), String>| TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName(name),
+            ignore: false,
+            ignore_message: None,
+            source_file: "",
+            start_line: 0,
+            start_col: 0,
+            end_line: 0,
+            end_col: 0,
+            should_panic: ShouldPanic::No,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+        },
+        testfn: DynTestFn(Box::new(testfn)),
+    };
+
+    // Dynamic tests still run in-process with `--isolate`, so they are timed out in-process too.
+    let mut results = Vec::new();
+    let notify = |event: TestEvent| {
+        if let TestEvent::TeResult(completed_test) = event {
+            results.push((completed_test.desc.name.to_string(), completed_test.result));
+        }
+        Ok(())
+    };
+    let opts = TestOpts {
+        run_tests: true,
+        isolate: true,
+        timeout: Some(Duration::from_millis(100)),
+        ..TestOpts::new()
+    };
+    fn hangs() -> Result<(), String> {
+        loop {
+            std::thread::park();
+        }
+    }
+    let tests = vec![test("hangs", hangs), test("passes", || Ok(()))];
+    run_tests(&opts, tests, notify).unwrap();
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(results, [("hangs".to_string(), TrTimedFail), ("passes".to_string(), TrOk)]);
+}
+
 #[test]
 pub fn test_metricmap_compare() {
     let mut m1 = MetricMap::new();
@@ -843,6 +1107,49 @@ fn f(b: &mut Bencher) -> Result<(), String> {
     rx.recv().unwrap();
 }
 
+fn bench_samples(samples: &[f64]) -> crate::bench::BenchSamples {
+    crate::bench::BenchSamples { ns_iter_summ: crate::stats::Summary::new(samples), mb_s: 0 }
+}
+
+#[test]
+pub fn test_bench_baseline_roundtrip() {
+    use crate::bench::BenchBaseline;
+
+    let mut baseline = BenchBaseline::new();
+    baseline.insert("fast", bench_samples(&[1.0, 2.0, 3.0]));
+    baseline
+        .insert("name with\ttab", crate::bench::BenchSamples { mb_s: 42, ..bench_samples(&[0.1]) });
+
+    let mut saved = Vec::new();
+    baseline.write_to(&mut saved).unwrap();
+    let loaded = BenchBaseline::read_from(&saved[..]).unwrap();
+    assert_eq!(loaded, baseline);
+
+    assert!(BenchBaseline::read_from(&b"not a baseline\n"[..]).is_err());
+}
+
+#[test]
+pub fn test_bench_compare_to_baseline() {
+    use crate::bench::compare_bench_samples;
+
+    let baseline = bench_samples(&[100.0, 101.0, 99.0, 100.0, 102.0, 98.0]);
+    let same = compare_bench_samples(&baseline, &baseline, 5.0);
+    assert_eq!(same.change_pct, 0.0);
+    assert!(!same.regressed);
+
+    let slower = bench_samples(&[120.0, 121.0, 119.0, 120.0, 122.0, 118.0]);
+    let comparison = compare_bench_samples(&baseline, &slower, 5.0);
+    assert!((comparison.change_pct - 20.0).abs() < 1e-9);
+    assert!(comparison.interval_pct.0 < 20.0 && comparison.interval_pct.1 > 20.0);
+    assert!(comparison.regressed);
+
+    // The same slowdown is fine with a more lenient threshold.
+    assert!(!compare_bench_samples(&baseline, &slower, 25.0).regressed);
+
+    // Getting faster is never a regression.
+    assert!(!compare_bench_samples(&slower, &baseline, 5.0).regressed);
+}
+
 #[test]
 fn should_sort_failures_before_printing_them() {
     let test_a = TestDesc {
@@ -882,6 +1189,7 @@ fn should_sort_failures_before_printing_them() {
         total: 0,
         passed: 0,
         failed: 0,
+        flaky: 0,
         ignored: 0,
         filtered_out: 0,
         measured: 0,
@@ -890,8 +1198,12 @@ fn should_sort_failures_before_printing_them() {
         failures: vec![(test_b, Vec::new()), (test_a, Vec::new())],
         options: Options::new(),
         not_failures: Vec::new(),
+        flakies: Vec::new(),
         ignores: Vec::new(),
         time_failures: Vec::new(),
+        bench_results: crate::bench::BenchBaseline::new(),
+        bench_baseline: None,
+        regression_threshold: DEFAULT_REGRESSION_THRESHOLD,
     };
 
     out.write_failures(&st).unwrap();
@@ -905,6 +1217,119 @@ fn should_sort_failures_before_printing_them() {
     assert!(apos < bpos);
 }
 
+#[test]
+fn tap_groups_tests_into_subtests() {
+    use crate::formatters::{OutputFormatter, TapFormatter};
+
+    fn desc(name: &'static str, ignore_message: Option<&'static str>) -> TestDesc {
+        TestDesc {
+            name: StaticTestName(name),
+            ignore: ignore_message.is_some(),
+            ignore_message,
+            source_file: "",
+            start_line: 0,
+            start_col: 0,
+            end_line: 0,
+            end_col: 0,
+            should_panic: ShouldPanic::No,
+            compile_fail: false,
+            no_run: false,
+            test_type: TestType::Unknown,
+        }
+    }
+
+    fn output(out: &TapFormatter<Vec<u8>>) -> String {
+        match out.output_location() {
+            &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+            &OutputLocation::Pretty(_) => unreachable!(),
+        }
+    }
+
+    let mut out = TapFormatter::new(OutputLocation::Raw(Vec::new()));
+    let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    out.write_run_start(4, None, None).unwrap();
+    out.write_result(&desc("b::fails", None), &TrFailedMsg("oh no".into()), None, b"out\n", &st)
+        .unwrap();
+    // Tests in a module are only written with their subtest, at the end of the run.
+    assert_eq!(output(&out), "TAP version 14\n");
+    out.write_result(&desc("top #1", None), &TrOk, None, b"quiet", &st).unwrap();
+    // Tests outside of any module are written as soon as their result is known.
+    assert!(output(&out).ends_with("ok 1 - top \\#1\n"));
+    out.write_result(&desc("a::skipped", Some("slow")), &TrIgnored, None, b"", &st).unwrap();
+    out.write_result(&desc("b::retried", None), &TrFlaky(2), None, b"", &st).unwrap();
+    st.failed = 1;
+    st.flaky = 1;
+    out.write_run_finish(&st).unwrap();
+
+    let expected = "\
+TAP version 14
+ok 1 - top \\#1
+# Subtest: a
+    1..1
+    ok 1 - skipped # SKIP slow
+ok 2 - a
+# Subtest: b
+    1..2
+    not ok 1 - fails
+      ---
+      severity: fail
+      message: \"oh no\"
+      stdout: |
+        out
+      ...
+    ok 2 - retried
+      ---
+      attempts: 2
+      ...
+not ok 3 - b
+1..3
+# passed: 0, failed: 1, flaky: 1, ignored: 0, measured: 0, filtered out: 0
+";
+    assert_eq!(output(&out), expected);
+}
+
+#[test]
+fn json_report_has_output_and_time_limits_of_passing_tests() {
+    use crate::formatters::{JsonFormatter, OutputFormatter};
+
+    let desc = TestDesc {
+        name: StaticTestName("passes"),
+        ignore: false,
+        ignore_message: None,
+        source_file: "",
+        start_line: 0,
+        start_col: 0,
+        end_line: 0,
+        end_col: 0,
+        should_panic: ShouldPanic::No,
+        compile_fail: false,
+        no_run: false,
+        test_type: TestType::UnitTest,
+    };
+    let time_options = TestTimeOptions {
+        unit_threshold: TimeThreshold::new(Duration::from_millis(50), Duration::from_millis(100)),
+        ..TestTimeOptions::default()
+    };
+    let exec_time = time::TestExecTime(Duration::from_millis(20));
+
+    let mut out = JsonFormatter::new_report(OutputLocation::Raw(Vec::new()), time_options);
+    let st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    out.write_run_start(1, None, None).unwrap();
+    out.write_result(&desc, &TrOk, Some(&exec_time), b"hello\n", &st).unwrap();
+
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    let expected = concat!(
+        r#"{ "type": "suite", "event": "started", "test_count": 1, "format_version": 1 }"#,
+        "\n",
+        r#"{ "type": "test", "name": "passes", "event": "ok", "exec_time": 0.02, "stdout": "hello\n", "warn_time": 0.05, "critical_time": 0.1 }"#,
+        "\n",
+    );
+    assert_eq!(s, expected);
+}
+
 #[test]
 #[cfg(not(target_os = "emscripten"))]
 fn test_dyn_bench_returning_err_fails_when_run_as_test() {
@@ -2,6 +2,7 @@
 //! execution.
 //! The purposes of this module:
 //! - Check whether test is timed out.
+//! - Provide the deadline for the `timeout` option.
 //! - Provide helpers for `report-time` and `measure-time` options.
 //! - Provide newtypes for executions times.
 
@@ -61,6 +62,12 @@ pub fn get_default_test_timeout() -> Instant {
     Instant::now() + Duration::from_secs(TEST_WARN_TIMEOUT_S)
 }
 
+/// Returns an `Instant` object denoting when a test run with the hard `--timeout`
+/// limit should be stopped.
+pub fn get_test_deadline(timeout: Duration) -> Instant {
+    Instant::now() + timeout
+}
+
 /// The measured execution time of a unit test.
 #[derive(Debug, Clone, PartialEq)]
 pub struct TestExecTime(pub Duration);
@@ -163,7 +170,7 @@ pub fn is_critical(&self, test: &TestDesc, exec_time: &TestExecTime) -> bool {
         exec_time.0 >= self.critical_time(test)
     }
 
-    fn warn_time(&self, test: &TestDesc) -> Duration {
+    pub(crate) fn warn_time(&self, test: &TestDesc) -> Duration {
         match test.test_type {
             TestType::UnitTest => self.unit_threshold.warn,
             TestType::IntegrationTest => self.integration_threshold.warn,
@@ -172,7 +179,7 @@ fn warn_time(&self, test: &TestDesc) -> Duration {
         }
     }
 
-    fn critical_time(&self, test: &TestDesc) -> Duration {
+    pub(crate) fn critical_time(&self, test: &TestDesc) -> Duration {
         match test.test_type {
             TestType::UnitTest => self.unit_threshold.critical,
             TestType::IntegrationTest => self.integration_threshold.critical,
@@ -103,6 +103,24 @@ pub fn padding(&self) -> NamePadding {
         }
     }
 
+    /// Returns a copy of this test function if it can be run more than once,
+    /// which is only the case for the static variants.
+    pub(crate) fn try_clone(&self) -> Option<TestFn> {
+        match *self {
+            StaticTestFn(f) => Some(StaticTestFn(f)),
+            StaticBenchFn(f) => Some(StaticBenchFn(f)),
+            StaticBenchAsTestFn(f) => Some(StaticBenchAsTestFn(f)),
+            DynTestFn(..) | DynBenchFn(..) | DynBenchAsTestFn(..) => None,
+        }
+    }
+
+    pub(crate) fn is_dynamic(&self) -> bool {
+        match self {
+            StaticTestFn(..) | StaticBenchFn(..) | StaticBenchAsTestFn(..) => false,
+            DynTestFn(..) | DynBenchFn(..) | DynBenchAsTestFn(..) => true,
+        }
+    }
+
     pub(crate) fn into_runnable(self) -> Runnable {
         match self {
             StaticTestFn(f) => Runnable::Test(RunnableTest::Static(f)),
@@ -3,7 +3,7 @@
 use rustc_data_structures::unord::UnordSet;
 use rustc_errors::emitter::{Emitter, EmitterWriter};
 use rustc_errors::json::JsonEmitter;
-use rustc_errors::TerminalUrl;
+use rustc_errors::{ColorConfig, TerminalUrl};
 use rustc_feature::UnstableFeatures;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::{DefId, DefIdMap, DefIdSet, LocalDefId};
@@ -166,6 +166,14 @@ pub(crate) fn new_handler(
                 .ui_testing(unstable_opts.ui_testing),
             )
         }
+        // Only the session's emitter writes a SARIF log, see `rustc_errors::sarif`.
+        ErrorOutputType::Sarif => Box::new(
+            EmitterWriter::stderr(ColorConfig::Auto, fallback_bundle)
+                .sm(source_map.map(|sm| sm as _))
+                .diagnostic_width(diagnostic_width)
+                .track_diagnostics(unstable_opts.track_diagnostics)
+                .ui_testing(unstable_opts.ui_testing),
+        ),
     };
 
     rustc_errors::Handler::with_emitter(emitter)
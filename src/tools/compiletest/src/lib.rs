@@ -524,12 +524,20 @@ pub fn test_opts(config: &Config) -> test::TestOpts {
         color: config.color,
         shuffle: false,
         shuffle_seed: None,
+        shard: None,
+        retries: 0,
+        save_baseline: None,
+        baseline: None,
+        regression_threshold: test::DEFAULT_REGRESSION_THRESHOLD,
         test_threads: None,
         skip: config.skip.clone(),
         list: false,
         options: test::Options::new(),
         time_options: None,
+        timeout: None,
+        report_file: None,
         force_run_in_process: false,
+        isolate: false,
         fail_fast: std::env::var_os("RUSTC_TEST_FAIL_FAST").is_some(),
     }
 }
@@ -0,0 +1,8 @@
+#[inline]
+fn inner(x: u32) -> u32 {
+    x + 1
+}
+
+pub fn outer(x: u32) -> u32 {
+    inner(x)
+}
@@ -0,0 +1,7 @@
+fn wrap<T: Copy>(x: T) -> Option<T> {
+    Some(x)
+}
+
+fn main() {
+    let _ = (wrap(1u8), wrap(2u16), wrap(3u32));
+}
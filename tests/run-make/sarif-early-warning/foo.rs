@@ -0,0 +1,3 @@
+pub fn foo() {
+    let unused = 1;
+}
@@ -0,0 +1,42 @@
+// run-pass
+// only-linux - pidfds are a linux-specific concept
+// ignore-emscripten no processes
+// ignore-sgx no processes
+
+#![feature(linux_pidfd)]
+#![feature(rustc_private)]
+
+extern crate libc;
+
+use std::os::linux::process::PidFd;
+use std::os::unix::process::ExitStatusExt;
+use std::process::Command;
+use std::time::Duration;
+
+fn main() {
+    let child = Command::new("sleep").arg("1000").spawn().unwrap();
+    let pidfd = match PidFd::open(child.id()) {
+        Ok(pidfd) => pidfd,
+        // pidfd_open requires Linux 5.3, and may be blocked inside containers.
+        Err(e) if matches!(e.raw_os_error(), Some(libc::ENOSYS) | Some(libc::EPERM)) => return,
+        Err(e) => panic!("failed to open pidfd: {e}"),
+    };
+
+    assert!(!pidfd.has_exited().unwrap());
+    assert!(pidfd.try_wait().unwrap().is_none());
+    assert!(pidfd.wait_timeout(Duration::from_millis(10)).unwrap().is_none());
+
+    pidfd.kill().unwrap();
+    let status = pidfd.wait_timeout(Duration::from_secs(60)).unwrap().unwrap();
+    assert_eq!(status.signal(), Some(libc::SIGKILL));
+
+    // The process has been reaped, so it can neither be waited for nor signaled again.
+    assert_eq!(pidfd.wait().unwrap_err().raw_os_error(), Some(libc::ECHILD));
+    assert_eq!(pidfd.kill().unwrap_err().raw_os_error(), Some(libc::ESRCH));
+
+    let child = Command::new("sh").args(["-c", "exit 3"]).spawn().unwrap();
+    let pidfd = PidFd::open(child.id()).unwrap();
+    let status = pidfd.wait().unwrap();
+    assert_eq!(status.code(), Some(3));
+    assert!(pidfd.has_exited().unwrap());
+}
@@ -0,0 +1,46 @@
+// no-prefer-dynamic
+// compile-flags: --test
+// run-flags: --test-threads=1 -Zunstable-options --isolate
+// run-fail
+// check-run-results
+// exec-env:RUST_BACKTRACE=0
+// normalize-stdout-test "finished in \d+\.\d+s" -> "finished in $$TIME"
+
+// ignore-wasm no panic or subprocess support
+// ignore-emscripten no panic or subprocess support
+// ignore-sgx no subprocess support
+// ignore-windows aborting doesn't end the process with a signal
+
+// With `--isolate`, a test that crashes its process only fails itself, and tests that catch
+// their own panics are judged by their outcome, as when they run in-process.
+
+#![cfg(test)]
+
+use std::panic;
+use std::thread;
+
+#[test]
+fn aborts() {
+    std::process::abort();
+}
+
+#[test]
+fn catches_its_own_panic() {
+    assert!(panic::catch_unwind(|| panic!("caught")).is_err());
+}
+
+#[test]
+fn it_works() {
+    assert_eq!(1 + 1, 2);
+}
+
+#[test]
+fn joins_a_panicked_thread() {
+    assert!(thread::spawn(|| panic!("joined")).join().is_err());
+}
+
+#[test]
+#[should_panic]
+fn recovers_but_should_panic() {
+    let _ = panic::catch_unwind(|| panic!("recovered"));
+}